@@ -1,4 +1,9 @@
-use std::{fmt,
+//! Lookup tables with interpolation and extrapolation, sized at compile time so they can be `const` on embedded targets.\
+//! Without the default `std` feature the crate is `no_std`: the methods that return a `Vec` or `String`,
+//! the tables sized at runtime, the parsing of method names and the file helpers need `std`.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use core::{fmt,
         ops::{
             Add,
             Sub,
@@ -6,8 +11,14 @@ use std::{fmt,
             Div,
             Neg,
         },
-        cmp::PartialOrd,
-    convert::Infallible};
+        cmp::{PartialOrd, Ordering},
+    convert::Infallible,
+    num::Wrapping,
+    marker::PhantomData};
+#[cfg(feature = "std")]
+use std::{io, path::Path, str::FromStr};
+#[cfg(feature = "serde")]
+use serde::{Serialize, Deserialize};
 
 #[derive(Debug, Clone)]
 /// Something went wrong with extrapolating, either NoneError was set or the lookuptable is not set up correctly
@@ -19,240 +30,5330 @@ impl fmt::Display for ExtrapolationError {
     }
 }
 
-/// Extrapolation methods for lookup tables
-pub enum Extrapolation {
-    /// Error if the entered breakpoint exceeds the values in the lookup tables' breakpoints vector.
-    NoneError,
-    /// Hold the value at the first or last breakpoint in the lookup table if the entered breakpoint is not in the range of the breakpoints vector.
-    NoneHoldExtreme,
-    /// Extrapolate the result using the slope of the last or first 2 breakpoint-value pairs.
-    Linear,
+/// Logs the error over `defmt` without the formatting machinery of `fmt::Display`, enabled with the `defmt` feature.
+#[cfg(feature = "defmt")]
+impl defmt::Format for ExtrapolationError {
+    fn format(&self, f: defmt::Formatter) {
+        defmt::write!(f, "ExtrapolationError")
+    }
 }
 
-/// Interpolation methods for lookup tables
-pub enum Interpolation {
-    /// Interpolate the result using the slope of the 2 breakpoint-value pairs that the entered breakpoint falls between. Worst for speed but best precision.
-    Linear,
-    /// Don't interpolate, always rounds down to previous value. Good for speed bad for precision.
-    NoneFloor,
-    /// Don't interpolate, always rounds up to the next value. Good for speed bad for precision.
-    NoneCeiling,
-    /// Don't interpolate, rounds to the nearest value. Kind of bad for speed better for precision.
-    NoneClosest,
+#[derive(Debug, Clone, PartialEq)]
+/// Something went wrong during a checked lookup
+pub enum LookupError {
+    /// The entered breakpoint was out of bounds with the NoneError extrapolation method
+    OutOfRange,
+    /// A subtraction, multiplication or division overflowed, underflowed or divided by zero
+    ArithmeticOverflow,
 }
 
-/// A struct representing a 1-D lookup table, breakpoints must be an ascending vector! 1,2,3,4 and not 4,3,2,1 or 1,2,3,2
-pub struct OneDLookup <
-T: PartialOrd + Sub + Add + Div + Copy + Clone,
-U: Add + Sub + Div + Mul + Copy + Clone,
-const C: usize>{
-    /// The breakpoints that act as the index for the values.
-    breakpoints: [T;C],
-    /// The values that represent the result from the lookup.
-    values: [U;C],
-    /// constant value for the lookup table so it only has to be calculated at initialisation, instead of every function call.\
-    /// represents the delta between the last two breakpoints
-    last_diff_bp: T,
-    /// constant value for the lookup table so it only has to be calculated at initialisation, instead of every function call.\
-    /// represents the delta between the last two values
-    last_diff_values: U,
-    /// constant value for the lookup table so it only has to be calculated at initialisation, instead of every function call.\
-    /// represents the delta between the first two breakpoints
-    first_diff_bp: T,
-    /// constant value for the lookup table so it only has to be calculated at initialisation, instead of every function call.\
-    /// represents the delta between the first two values
-    first_diff_values: U,
+impl fmt::Display for LookupError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LookupError::OutOfRange => write!(f, "Index was out of bounds with the NoneError extrapolation method"),
+            LookupError::ArithmeticOverflow => write!(f, "An arithmetic operation overflowed during the lookup"),
+        }
+    }
 }
 
+/// Logs the error over `defmt` without the formatting machinery of `fmt::Display`, enabled with the `defmt` feature.
+#[cfg(feature = "defmt")]
+impl defmt::Format for LookupError {
+    fn format(&self, f: defmt::Formatter) {
+        match self {
+            LookupError::OutOfRange => defmt::write!(f, "LookupError::OutOfRange"),
+            LookupError::ArithmeticOverflow => defmt::write!(f, "LookupError::ArithmeticOverflow"),
+        }
+    }
+}
 
-impl<
-T: PartialOrd + Add + Copy + Clone + Sub<Output = T> + Div<Output = T>, 
-U: Sub<Output = U>  + Add<Output = U> + Copy + Clone + From<T> + Mul<Output = U> + Div<Output = U> + Neg<Output = U>,
-const C: usize
->
-OneDLookup<T,U,C>{
-    /// Returns a (interpolated) value from the lookup table that matches the entered breakpoint.
-    /// 
-    /// # Arguments
-    /// 
-    /// * `breakpoint` - A reference to the breakpoint for which a value must be found by the lookup table
-    /// * `extrapolation` - The extrapolation method to use for this lookup operation
-    /// * `interpolation` - The interpolation method to use for this lookup operation
-    /// 
-    /// # Examples
-    /// 
-    /// ```
-    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
-    /// use::go_lookup_tables::{OneDLookup, Interpolation, Extrapolation};
-    /// let measured_voltage = 2000i16;
-    /// const LOOKUP_TABLE: OneDLookup<i16,f32,4> = create_1d_lookup!((0,500,4500,5000), (0.0,0.0,500.0,500.0));//simple 0.5V to 4.5V pressure sensor
-    /// let pressure = LOOKUP_TABLE.lookup(&measured_voltage, Extrapolation::NoneHoldExtreme, Interpolation::Linear).unwrap();
-    /// assert_eq!(pressure, 187.5f32)
-    /// # }
-    /// ```
-    pub fn lookup<Y: Copy>(&self, breakpoint: &Y, extrapolation: Extrapolation, interpolation: Interpolation) -> Result<U, ExtrapolationError>
-    where T: From<Y> + From<i8>{
-        let calc_breakpoint = T::from(*breakpoint);
-        match self.breakpoints.iter().position(|bp| bp >= &calc_breakpoint){ 
-            Some(index) => {
-                if self.breakpoints[index] == calc_breakpoint {
-                    return Ok(self.values[index]) 
-                }
-                else if index != 0 {
-                    // handle interpolation
-                    return match interpolation {
-                        Interpolation::Linear => {
-                            let interpolated_diff_bp = calc_breakpoint - self.breakpoints[index -1];
-                            let diff_actual_bp = self.breakpoints[index] - self.breakpoints[index-1];
-                            let diff_values = self.values[index] - self.values[index-1];
-                            Ok((U::from(interpolated_diff_bp) * diff_values) / U::from(diff_actual_bp) + self.values[index-1])
-                        },
-                        Interpolation::NoneCeiling => {Ok(*self.values.get(index).unwrap())},
-                        Interpolation::NoneFloor => {Ok(*self.values.get(index-1).unwrap())},
-                        Interpolation::NoneClosest => {
-                            let interpolated_diff_bp = calc_breakpoint - self.breakpoints[index -1];
-                            let diff_actual_bp = self.breakpoints[index] - self.breakpoints[index-1];
-                            let diff_factor = diff_actual_bp - interpolated_diff_bp;
-                            let round: usize = if diff_factor > (diff_actual_bp/T::from(2))
-                                {
-                                0
-                            } else {
-                                1
-                            };
-                            Ok(self.values[index-1 + round])
-                        },
-                    }
-                }
-                // handle extrapolation at the low end
-                match extrapolation {
-                    Extrapolation::NoneError => Err(ExtrapolationError),
-                    Extrapolation::NoneHoldExtreme => Ok(self.values[0]),
-                    Extrapolation::Linear => {
-                        let extrapolated_diff_bp = self.breakpoints[1] - calc_breakpoint;
-                        Ok((U::from(extrapolated_diff_bp) * -self.first_diff_values) / U::from(self.first_diff_bp) + self.values[1])
-                    }
+impl From<ExtrapolationError> for LookupError {
+    fn from(_: ExtrapolationError) -> Self {
+        LookupError::OutOfRange
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Something went wrong while building a lookup table from existing data
+pub enum LookupBuildError {
+    /// The dimensions of the data don't match the dimensions of the lookup table
+    ShapeMismatch {
+        /// The expected length
+        expected: usize,
+        /// The length that was found
+        found: usize,
+    },
+    /// The breakpoints aren't in ascending order
+    NotAscending {
+        /// The index of the first breakpoint that is smaller than the breakpoint before it
+        index: usize,
+    },
+    /// A breakpoint occurs more than once
+    DuplicateBreakpoint {
+        /// The index of the second occurrence of the breakpoint after sorting
+        index: usize,
+    },
+    /// The values aren't strictly monotone, so they can't be used as breakpoints of an inverse
+    NotMonotone {
+        /// The index of the first value that doesn't continue the direction of the first two values
+        index: usize,
+    },
+    /// The first byte of an encoded lookup table isn't a known endianness marker
+    InvalidEndiannessMarker {
+        /// The byte that was found
+        found: u8,
+    },
+    /// The horizontal breakpoints of a row differ from those of the first row
+    BreakpointMismatch {
+        /// The index of the first row whose breakpoints differ
+        row: usize,
+    },
+    /// The delta between two adjacent breakpoints or values overflows their type, only returned with the `checked_construction` feature
+    DiffOverflow {
+        /// The index of the breakpoint or value whose delta with the one before it overflows
+        index: usize,
+    },
+    /// An axis of a lookup table has no breakpoints
+    EmptyAxis,
+}
+
+impl fmt::Display for LookupBuildError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LookupBuildError::ShapeMismatch { expected, found } => write!(f, "Shape mismatch, expected a length of {} but found {}", expected, found),
+            LookupBuildError::NotAscending { index } => write!(f, "Breakpoints aren't in ascending order, breakpoint {} is smaller than the one before it", index),
+            LookupBuildError::DuplicateBreakpoint { index } => write!(f, "Breakpoint {} is equal to the one before it", index),
+            LookupBuildError::NotMonotone { index } => write!(f, "Values aren't strictly monotone, value {} doesn't continue the direction of the first two values", index),
+            LookupBuildError::InvalidEndiannessMarker { found } => write!(f, "Unknown endianness marker {}", found),
+            LookupBuildError::BreakpointMismatch { row } => write!(f, "The breakpoints of row {} differ from those of the first row", row),
+            LookupBuildError::DiffOverflow { index } => write!(f, "The delta between breakpoint or value {} and the one before it overflows its type", index),
+            LookupBuildError::EmptyAxis => write!(f, "An axis has no breakpoints"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// The values of a lookup table aren't monotone in the expected direction
+pub struct NonMonotoneAt {
+    /// The index of the first value that breaks the expected order with the value before it
+    pub index: usize,
+}
+
+impl fmt::Display for NonMonotoneAt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Values aren't monotone, value {} breaks the expected order with the one before it", self.index)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// The values of a lookup table contain a NaN while the analysis was asked to fail on it, see `NanPolicy::Error`
+pub struct NanAt {
+    /// The index of the first NaN value
+    pub index: usize,
+}
+
+impl fmt::Display for NanAt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Value {} is NaN", self.index)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// Errors returned by `OneDLookup::assert_monotone`
+pub enum MonotoneError {
+    /// The values aren't monotone in the expected direction.
+    NonMonotone(NonMonotoneAt),
+    /// The values contain a NaN and the NaN policy is `NanPolicy::Error`.
+    Nan(NanAt),
+}
+
+impl fmt::Display for MonotoneError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MonotoneError::NonMonotone(error) => error.fmt(f),
+            MonotoneError::Nan(error) => error.fmt(f),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// A breakpoint or value of a lookup table is NaN or infinite, which has no Rust literal, see `OneDLookup::to_const_source`
+pub struct NonFiniteAt {
+    /// The index of the first breakpoint-value pair with a NaN or infinite breakpoint or value
+    pub index: usize,
+}
+
+impl fmt::Display for NonFiniteAt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Breakpoint or value {} is NaN or infinite, so it can't be written as a literal", self.index)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// The values of a lookup table sum to zero, so they can't be used as weights, see `OneDLookup::weighted_centroid`
+pub struct ZeroWeight;
+
+impl fmt::Display for ZeroWeight {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "The values sum to zero, so they have no weighted centroid")
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq)]
+/// A string didn't name a known interpolation or extrapolation method
+pub struct ParseModeError {
+    /// The string that couldn't be parsed
+    pub input: String,
+}
+
+#[cfg(feature = "std")]
+impl fmt::Display for ParseModeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Unknown interpolation or extrapolation method \"{}\"", self.input)
+    }
+}
+
+/// Checks that the breakpoints are in ascending order, equal neighbours are allowed just like in the create macros.
+fn check_ascending<T: PartialOrd>(breakpoints: &[T]) -> Result<(), LookupBuildError> {
+    match breakpoints.windows(2).position(|pair| pair[0] > pair[1]) {
+        Some(index) => Err(LookupBuildError::NotAscending { index: index + 1 }),
+        None => Ok(()),
+    }
+}
+
+/// Numeric types with a smallest and largest value, used to saturate conversions into the breakpoint type.
+pub trait Bounded {
+    /// The smallest value of the type.
+    const MIN: Self;
+    /// The largest value of the type.
+    const MAX: Self;
+}
+
+macro_rules! impl_bounded {
+    ($($t:ty),*) => {
+        $(impl Bounded for $t {
+            const MIN: Self = <$t>::MIN;
+            const MAX: Self = <$t>::MAX;
+        })*
+    };
+}
+
+impl_bounded!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+/// Primitive types that `OneDLookup::to_const_source` can write as Rust source, with the name of the type as it is written in source.
+pub trait SourceLiteral: fmt::Debug {
+    /// The name of the type in Rust source, like `i16`.
+    const TYPE_NAME: &'static str;
+    /// Returns `false` if the value has no literal, like a NaN or infinite float.
+    fn has_literal(&self) -> bool;
+    /// Returns `false` if `create_1d_lookup!` can't calculate `high - low` at compile time, like a falling edge of an unsigned type.
+    fn const_delta_fits(low: &Self, high: &Self) -> bool;
+}
+
+macro_rules! impl_source_literal_int {
+    ($($t:ident),*) => {
+        $(impl SourceLiteral for $t {
+            const TYPE_NAME: &'static str = stringify!($t);
+            fn has_literal(&self) -> bool { true }
+            fn const_delta_fits(low: &Self, high: &Self) -> bool { high.checked_sub(*low).is_some() }
+        })*
+    };
+}
+
+macro_rules! impl_source_literal_float {
+    ($($t:ident),*) => {
+        $(impl SourceLiteral for $t {
+            const TYPE_NAME: &'static str = stringify!($t);
+            fn has_literal(&self) -> bool { self.is_finite() }
+            fn const_delta_fits(_low: &Self, _high: &Self) -> bool { true }
+        })*
+    };
+}
+
+impl_source_literal_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_source_literal_float!(f32, f64);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// The byte order used to encode a lookup table, so a table flashed by one target can be read by a target with another native byte order.
+pub enum Endianness {
+    /// Least significant byte first, encoded with marker byte `0`.
+    Little,
+    /// Most significant byte first, encoded with marker byte `1`.
+    Big,
+}
+
+impl Endianness {
+    /// Returns the marker byte that is stored in front of an encoded lookup table.
+    #[cfg(feature = "std")]
+    fn marker(self) -> u8 {
+        match self {
+            Endianness::Little => 0,
+            Endianness::Big => 1,
+        }
+    }
+
+    /// Returns the endianness that belongs to a marker byte.
+    fn from_marker(marker: u8) -> Result<Endianness, LookupBuildError> {
+        match marker {
+            0 => Ok(Endianness::Little),
+            1 => Ok(Endianness::Big),
+            found => Err(LookupBuildError::InvalidEndiannessMarker { found }),
+        }
+    }
+}
+
+/// Numeric types with a fixed size byte encoding, used by `to_bytes` and `from_bytes`.
+pub trait ByteEncoding: Sized {
+    /// The number of bytes of an encoded value.
+    const SIZE: usize;
+    /// Appends the value to `out` in the entered byte order.
+    #[cfg(feature = "std")]
+    fn write_bytes(self, out: &mut Vec<u8>, endianness: Endianness);
+    /// Returns the value decoded from the first `SIZE` bytes in the entered byte order.
+    fn read_bytes(bytes: &[u8], endianness: Endianness) -> Self;
+}
+
+macro_rules! impl_byte_encoding {
+    ($($t:ty),*) => {
+        $(impl ByteEncoding for $t {
+            const SIZE: usize = core::mem::size_of::<$t>();
+            #[cfg(feature = "std")]
+            fn write_bytes(self, out: &mut Vec<u8>, endianness: Endianness) {
+                match endianness {
+                    Endianness::Little => out.extend_from_slice(&self.to_le_bytes()),
+                    Endianness::Big => out.extend_from_slice(&self.to_be_bytes()),
                 }
             }
-            None => match extrapolation {
-            // handle extrapolation at the high end
-                Extrapolation::NoneError => Err(ExtrapolationError),
-                Extrapolation::NoneHoldExtreme => Ok(self.values[self.values.len()-1]),
-                Extrapolation::Linear => {
-                    let extrapolated_diff_bp: T = calc_breakpoint - self.breakpoints[self.breakpoints.len()-2];
-                    Ok((U::from(extrapolated_diff_bp) * self.last_diff_values) / U::from(self.last_diff_bp) + self.values[self.values.len()-2])
+            fn read_bytes(bytes: &[u8], endianness: Endianness) -> Self {
+                let mut buffer = [0u8; core::mem::size_of::<$t>()];
+                buffer.copy_from_slice(&bytes[..Self::SIZE]);
+                match endianness {
+                    Endianness::Little => <$t>::from_le_bytes(buffer),
+                    Endianness::Big => <$t>::from_be_bytes(buffer),
                 }
             }
+        })*
+    };
+}
+
+impl_byte_encoding!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128, f32, f64);
+
+/// Numeric types that can be created from an `f64`, used by methods that calculate in `f64` and return the value type.\
+/// The conversion behaves like an `as` cast, integers are rounded towards zero and saturate at their bounds.
+pub trait FromF64 {
+    /// Returns the `f64` converted to this type.
+    fn from_f64(value: f64) -> Self;
+}
+
+macro_rules! impl_from_f64 {
+    ($($t:ty),*) => {
+        $(impl FromF64 for $t {
+            fn from_f64(value: f64) -> Self { value as $t }
+        })*
+    };
+}
+
+impl_from_f64!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+impl<X: FromF64> FromF64 for Wrapping<X> {
+    fn from_f64(value: f64) -> Self { Wrapping(X::from_f64(value)) }
+}
+
+/// Numeric types that can be converted to an `f64`, also the 64-bit and wider integers that don't implement `Into<f64>`.\
+/// The conversion behaves like an `as` cast, integers that don't fit in the 53 bit mantissa are rounded to the nearest `f64`.
+pub trait AsF64: Copy {
+    /// Returns the value converted to an `f64`.
+    fn as_f64(self) -> f64;
+}
+
+macro_rules! impl_as_f64 {
+    ($($t:ty),*) => {
+        $(impl AsF64 for $t {
+            fn as_f64(self) -> f64 { self as f64 }
+        })*
+    };
+}
+
+impl_as_f64!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+impl<X: AsF64> AsF64 for Wrapping<X> {
+    fn as_f64(self) -> f64 { self.0.as_f64() }
+}
+
+/// Numeric types that can be converted to `V` saturating at its bounds, used by `lookup_as` to convert a result to a smaller type.\
+/// Integers are narrowed with `TryFrom`, so they keep their exact value when it fits, even the 64-bit and wider integers an `f64` can't hold.
+/// Floats are converted like an `as` cast, integers are rounded towards zero and NaN becomes 0.
+pub trait SaturatingInto<V> {
+    /// Returns the value converted to `V`, or the bound of `V` it lies beyond.
+    fn saturating_into(self) -> V;
+}
+
+macro_rules! impl_saturating_into_int {
+    ($($from:ty),*) => {
+        $(impl_saturating_into_int!(@to $from; i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+        impl_saturating_into_int!(@as $from; f32, f64);)*
+    };
+    (@to $from:ty; $($to:ty),*) => {
+        $(impl SaturatingInto<$to> for $from {
+            fn saturating_into(self) -> $to {
+                <$to>::try_from(self).unwrap_or(if self > 0 { <$to>::MAX } else { <$to>::MIN })
+            }
+        })*
+    };
+    (@as $from:ty; $($to:ty),*) => {
+        $(impl SaturatingInto<$to> for $from {
+            fn saturating_into(self) -> $to { self as $to }
+        })*
+    };
+}
+
+macro_rules! impl_saturating_into_float {
+    ($($from:ty),*) => {
+        $(impl_saturating_into_int!(@as $from; i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);)*
+    };
+}
+
+impl_saturating_into_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_saturating_into_float!(f32, f64);
+
+/// Value types that can be calculated on in `f64` one component at a time, used by the interpolation methods that aren't linear.\
+/// Every type that converts to and from an `f64` has itself as its only component,
+/// with the `num-complex` feature `num_complex::Complex` maps its real and imaginary parts separately.
+pub trait F64Components: Copy {
+    /// Returns the result of `f` for every component of the entered values, for example the real and imaginary parts of complex numbers.
+    fn map_components<const K: usize>(values: [Self; K], f: impl Fn([f64; K]) -> f64) -> Self;
+}
+
+impl<X: AsF64 + FromF64> F64Components for X {
+    fn map_components<const K: usize>(values: [Self; K], f: impl Fn([f64; K]) -> f64) -> Self {
+        X::from_f64(f(values.map(AsF64::as_f64)))
+    }
+}
+
+#[cfg(feature = "num-complex")]
+impl<X: AsF64 + FromF64> F64Components for num_complex::Complex<X> {
+    fn map_components<const K: usize>(values: [Self; K], f: impl Fn([f64; K]) -> f64) -> Self {
+        num_complex::Complex::new(X::from_f64(f(values.map(|value| value.re.as_f64()))), X::from_f64(f(values.map(|value| value.im.as_f64()))))
+    }
+}
+
+/// The knots of a lookup table converted to `f64`, with the breakpoints as their distance to the first breakpoint.
+/// The distances are calculated in the breakpoint type before converting, so large breakpoints like timestamps keep the precision of their differences.
+struct OffsetKnots<'a, T, U> {
+    breakpoints: &'a [T],
+    values: &'a [U],
+}
+
+impl<T: PartialOrd + Sub<Output = T> + AsF64, U> OffsetKnots<'_, T, U> {
+    /// Returns the distance of the entered breakpoint to the first breakpoint, negative below it.
+    fn offset(&self, breakpoint: T) -> f64 {
+        //subtract the positive way around, so unsigned breakpoint types don't wrap
+        if breakpoint >= self.breakpoints[0] {
+            (breakpoint - self.breakpoints[0]).as_f64()
+        } else {
+            -(self.breakpoints[0] - breakpoint).as_f64()
         }
     }
-    /// This method is unsafe, consider using the create_1d_lookup!() macro instead.
-    /// Returns a lookup table. Only use an ascending breakpoints vector! for example  1,2,3,4 and not 4,3,2,1 or 1,2,3,2 \
-    /// breakpoints and values must have the same length!
-    /// 
-    /// # Arguments
-    /// 
-    /// * `breakpoints` - The breakpoints that act as the index for the values
-    /// * `values` - The values that represent the result from the lookup
-    /// 
-    /// # Examples
-    /// 
-    /// ```
-    /// use::go_lookup_tables::{OneDLookup};
-    /// const LOOKUP_TABLE: OneDLookup<i16,f32,4> = OneDLookup::new([0,500,4500,5000], [0.0,0.0,500.0,500.0], 500, 0.0, -500, 0.0); //simple 0.5V to 4.5V pressure sensor
-    /// ```
-    pub const fn new(breakpoints: [T;C], values: [U;C], last_diff_bp: T, last_diff_values: U, first_diff_bp: T, first_diff_values: U) -> OneDLookup<T,U,C> where [T;C]: Sized, [U;C]: Sized {
-        OneDLookup {
-            last_diff_bp,
-            last_diff_values,
-            first_diff_bp,
-            first_diff_values,
-            breakpoints,
-            values,
-        }        
+}
+
+impl<T: PartialOrd + Sub<Output = T> + AsF64, U: AsF64> Knots<f64, f64> for OffsetKnots<'_, T, U> {
+    fn count(&self) -> usize {
+        self.breakpoints.len()
+    }
+    fn breakpoint(&self, index: usize) -> f64 {
+        self.offset(self.breakpoints[index])
+    }
+    fn value(&self, index: usize) -> f64 {
+        self.values[index].as_f64()
     }
 }
 
-/// Returns a lookup table. Only use an ascending breakpoints vector! for example  1,2,3,4 and not 4,3,2,1 or 1,2,3,2 \
-/// breakpoints and values must have the same length!
-/// 
-/// # Arguments
-/// 
-/// * `breakpoints` - The breakpoints that act as the index for the values
-/// * `values` - The values that represent the result from the lookup
-/// 
-/// # Panics
-///
-/// `create_1d_lookup!` panics if breakpoints is not in ascending order or if breakpoints.len() != values.len().
-/// This panic is generated at compile time.
-/// 
-/// # Examples
-/// 
-/// ```
-/// # #[macro_use] extern crate go_lookup_tables; fn main() {
-/// use::go_lookup_tables::*;
-/// const LOOKUP_TABLE: OneDLookup<i16,f32,4> = create_1d_lookup!((0,500,4500,5000), (0.0,0.0,500.0,500.0)); //simple 0.5V to 4.5V pressure sensor
-/// # }
-/// ```
-#[macro_export]
-macro_rules! create_1d_lookup {
-    (($($bps:expr),*), ($($vals:expr),*)) => {{
-        const _: () = {
-            let breakpoints = [ $($bps,)* ];
-            let values = [ $($vals,)* ];
-            if breakpoints.len() != values.len() {
-                panic!("lengths of breakpoints and values don't match");
-            }
+/// The knots of a lookup table converted to `f64` with `Into<f64>`, for lookups that don't need any arithmetic of the table types.
+struct F64Knots<'a, T, U> {
+    breakpoints: &'a [T],
+    values: &'a [U],
+}
+
+impl<T: Into<f64> + Copy, U: Into<f64> + Copy> Knots<f64, f64> for F64Knots<'_, T, U> {
+    fn count(&self) -> usize {
+        self.breakpoints.len()
+    }
+    fn breakpoint(&self, index: usize) -> f64 {
+        self.breakpoints[index].into()
+    }
+    fn value(&self, index: usize) -> f64 {
+        self.values[index].into()
+    }
+}
+
+/// Arithmetic that reports failure instead of wrapping, used by the checked lookups.\
+/// For floats an infinite or NaN result counts as a failure.
+pub trait CheckedArithmetic: Sized {
+    /// Returns `self + rhs`, or `None` if it overflows.
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    /// Returns `self - rhs`, or `None` if it overflows.
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+    /// Returns `self * rhs`, or `None` if it overflows.
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    /// Returns `self / rhs`, or `None` if it overflows or `rhs` is zero.
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! impl_checked_arithmetic_int {
+    ($($t:ty),*) => {
+        $(impl CheckedArithmetic for $t {
+            fn checked_add(self, rhs: Self) -> Option<Self> { <$t>::checked_add(self, rhs) }
+            fn checked_sub(self, rhs: Self) -> Option<Self> { <$t>::checked_sub(self, rhs) }
+            fn checked_mul(self, rhs: Self) -> Option<Self> { <$t>::checked_mul(self, rhs) }
+            fn checked_div(self, rhs: Self) -> Option<Self> { <$t>::checked_div(self, rhs) }
+        })*
+    };
+}
+
+macro_rules! impl_checked_arithmetic_float {
+    ($($t:ty),*) => {
+        $(impl CheckedArithmetic for $t {
+            fn checked_add(self, rhs: Self) -> Option<Self> { Some(self + rhs).filter(|result| result.is_finite()) }
+            fn checked_sub(self, rhs: Self) -> Option<Self> { Some(self - rhs).filter(|result| result.is_finite()) }
+            fn checked_mul(self, rhs: Self) -> Option<Self> { Some(self * rhs).filter(|result| result.is_finite()) }
+            fn checked_div(self, rhs: Self) -> Option<Self> { Some(self / rhs).filter(|result| result.is_finite()) }
+        })*
+    };
+}
+
+impl_checked_arithmetic_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+impl_checked_arithmetic_float!(f32, f64);
+
+/// The delta between the two breakpoints or values of an edge segment, which `OneDLookup` caches.\
+/// The default methods subtract and accept every delta, so other types only need an empty impl.
+/// The primitive types wrap the delta of a falling unsigned edge and check signed and float deltas in the direction of their edge.
+pub trait EdgeDelta: Sub<Output = Self> + Copy {
+    /// Returns `high - low`, for unsigned types a falling edge wraps around.
+    fn edge_delta(low: Self, high: Self) -> Self {
+        high - low
+    }
+    /// Returns false if `high - low` doesn't fit in the type, checked by `OneDLookup::try_new` with the `checked_construction` feature.
+    fn edge_delta_fits(_low: Self, _high: Self) -> bool {
+        true
+    }
+    /// Returns `from + (to - from) * offset / span`, the value at `offset` along a segment of width `span` that runs from `from` to `to`,
+    /// which the lookups use to interpolate and extrapolate.\
+    /// Unsigned types calculate a falling segment as `from - (from - to) * offset / span`, so its delta doesn't wrap around.
+    fn lerp(from: Self, to: Self, offset: Self, span: Self) -> Self
+    where Self: Add<Output = Self> + Mul<Output = Self> + Div<Output = Self>{
+        (offset * (to - from)) / span + from
+    }
+}
+
+macro_rules! impl_edge_delta_signed {
+    ($($t:ty),*) => {
+        $(impl EdgeDelta for $t {
+            fn edge_delta_fits(low: Self, high: Self) -> bool { high.checked_sub(low).is_some() }
+        })*
+    };
+}
+
+macro_rules! impl_edge_delta_unsigned {
+    ($($t:ty),*) => {
+        $(impl EdgeDelta for $t {
+            fn edge_delta(low: Self, high: Self) -> Self { high.wrapping_sub(low) }
+            fn lerp(from: Self, to: Self, offset: Self, span: Self) -> Self {
+                if to < from { from - (offset * (from - to)) / span } else { (offset * (to - from)) / span + from }
+            }
+        })*
+    };
+}
+
+macro_rules! impl_edge_delta_float {
+    ($($t:ty),*) => {
+        $(impl EdgeDelta for $t {
+            fn edge_delta_fits(low: Self, high: Self) -> bool { (high - low).is_finite() }
+        })*
+    };
+}
+
+impl_edge_delta_signed!(i8, i16, i32, i64, i128, isize);
+impl_edge_delta_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_edge_delta_float!(f32, f64);
+
+impl<X> EdgeDelta for Wrapping<X> where Wrapping<X>: Sub<Output = Wrapping<X>> + Copy {}
+
+#[cfg(feature = "num-complex")]
+impl<X> EdgeDelta for num_complex::Complex<X> where num_complex::Complex<X>: Sub<Output = num_complex::Complex<X>> + Copy {}
+
+/// Division that rounds to the nearest integer instead of towards zero, used by `OneDLookup::lookup_rounded`.\
+/// Halfway results are rounded away from zero, the rounding is decided on the remainder so it can't overflow, floats divide exactly.
+pub trait DivRound: Sized {
+    /// Returns `self / divisor` rounded to the nearest integer.
+    fn div_round(self, divisor: Self) -> Self;
+}
+
+/// Anything that maps an input to an output, so generic code can accept any interpolator instead of only the lookup tables of this crate.\
+/// `OneDLookup` implements it with `Extrapolation::NoneError` and `Interpolation::Linear`,
+/// wrap it with `OneDLookup::interpolator` to evaluate with other methods.
+pub trait Interpolator<X, Y> {
+    /// Returns the output that matches the entered input.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns an `ExtrapolationError` if the input is out of range and the interpolator doesn't extrapolate.
+    fn evaluate(&self, x: X) -> Result<Y, ExtrapolationError>;
+}
+
+/// The shape and knots of a lookup table of any dimension, so tooling like exporters and validators can treat 1-D and 2-D tables uniformly.
+pub trait LookupTable {
+    /// The breakpoints that make up a query, `T` for a 1-D table and `(S, T)` for a 2-D table.
+    type Query;
+    /// The values that a query returns.
+    type Output;
+    /// The breakpoints of every axis, `[T;C]` for a 1-D table and `([S;N], [T;M])` for a 2-D table.
+    type Axes;
+    /// Returns the number of axes.
+    fn dimensions(&self) -> usize;
+    /// Returns the number of breakpoints of every axis, horizontal first.
+    #[cfg(feature = "std")]
+    fn axis_lengths(&self) -> Vec<usize>;
+    /// Returns the breakpoints of every axis.
+    fn axes(&self) -> Self::Axes;
+    /// Returns every knot as its query and value, for 2-D tables row by row.
+    #[cfg(feature = "std")]
+    fn knots(&self) -> Vec<(Self::Query, Self::Output)>;
+}
+
+impl<
+T: PartialOrd + Sub + Add + Div + Copy + Clone,
+U: Add + Sub + Div + Mul + Copy + Clone,
+const C: usize
+>
+LookupTable for OneDLookup<T,U,C>{
+    type Query = T;
+    type Output = U;
+    type Axes = [T;C];
+    fn dimensions(&self) -> usize {
+        1
+    }
+    #[cfg(feature = "std")]
+    fn axis_lengths(&self) -> Vec<usize> {
+        vec![C]
+    }
+    fn axes(&self) -> [T;C] {
+        self.breakpoints
+    }
+    #[cfg(feature = "std")]
+    fn knots(&self) -> Vec<(T, U)> {
+        self.breakpoints.iter().copied().zip(self.values.iter().copied()).collect()
+    }
+}
+
+impl<
+S: PartialOrd + Sub + Add + Div + Copy + Clone,
+T: PartialOrd + Sub + Add + Div + Copy + Clone,
+U: Add + Sub + Div + Mul + Copy + Clone,
+const N: usize,
+const M: usize,
+>
+LookupTable for TwoDLookup<S,T,U,N,M>{
+    type Query = (S, T);
+    type Output = U;
+    type Axes = ([S;N], [T;M]);
+    fn dimensions(&self) -> usize {
+        2
+    }
+    #[cfg(feature = "std")]
+    fn axis_lengths(&self) -> Vec<usize> {
+        vec![N, M]
+    }
+    fn axes(&self) -> ([S;N], [T;M]) {
+        (self.breakpoints_h, self.breakpoints_v)
+    }
+    #[cfg(feature = "std")]
+    fn knots(&self) -> Vec<((S, T), U)> {
+        self.breakpoints_v.iter().zip(self.values.iter())
+            .flat_map(|(bp_v, row)| self.breakpoints_h.iter().zip(row.iter()).map(move |(bp_h, value)| ((*bp_h, *bp_v), *value)))
+            .collect()
+    }
+}
+
+macro_rules! impl_div_round_signed {
+    ($($t:ty),*) => {
+        $(impl DivRound for $t {
+            fn div_round(self, divisor: Self) -> Self {
+                //round on the remainder, adding half the divisor first could overflow near the bounds
+                let (quotient, remainder) = (self / divisor, self % divisor);
+                if remainder.unsigned_abs() < divisor.unsigned_abs() - divisor.unsigned_abs() / 2 {
+                    quotient
+                } else if (self < 0) != (divisor < 0) {
+                    quotient - 1
+                } else {
+                    quotient + 1
+                }
+            }
+        })*
+    };
+}
+
+macro_rules! impl_div_round_unsigned {
+    ($($t:ty),*) => {
+        $(impl DivRound for $t {
+            fn div_round(self, divisor: Self) -> Self { self / divisor + (self % divisor >= divisor - divisor / 2) as Self }
+        })*
+    };
+}
+
+macro_rules! impl_div_round_float {
+    ($($t:ty),*) => {
+        $(impl DivRound for $t {
+            fn div_round(self, divisor: Self) -> Self { self / divisor }
+        })*
+    };
+}
+
+impl_div_round_signed!(i8, i16, i32, i64, i128, isize);
+impl_div_round_unsigned!(u8, u16, u32, u64, u128, usize);
+impl_div_round_float!(f32, f64);
+
+/// Returns the offset into a segment remapped by the easing curve of the interpolation method, as `span * ease(offset / span)` in the value type.\
+/// Linear interpolation returns the offset unchanged, so it can be used in place of the offset in the linear blend formulas.
+/// The fraction and the curves are calculated in `f64`, so integer tables ease between their values instead of holding the lower one.
+fn ease<B, U>(offset: B, span: B, interpolation: &Interpolation) -> U
+where B: AsF64, U: From<B> + F64Components{
+    let fraction = || offset.as_f64() / span.as_f64();
+    let eased = match interpolation {
+        Interpolation::Smoothstep => {
+            let t = fraction();
+            t * t * (3.0 - 2.0 * t)
+        },
+        Interpolation::Smootherstep => {
+            let t = fraction();
+            t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+        },
+        _ => return U::from(offset),
+    };
+    U::map_components([U::from(span)], |[span]| span * eased)
+}
+
+/// Returns `from + (to - from) * offset / span`, the value at `offset` into a segment of width `span` that runs from `from` to `to`,
+/// the offset is eased first for the smooth interpolation methods. See `EdgeDelta::lerp` for falling segments of unsigned types.
+fn blend<B, U>(from: U, to: U, offset: B, span: B, interpolation: &Interpolation) -> U
+where B: AsF64, U: From<B> + F64Components + EdgeDelta + Add<Output = U> + Mul<Output = U> + Div<Output = U>{
+    if let Interpolation::AngularLinear { period } = *interpolation {
+        return angular_blend(from, to, offset, span, period);
+    }
+    U::lerp(from, to, ease(offset, span, interpolation), U::from(span))
+}
+
+/// Returns the angle at `offset` into a segment of width `span` along the shortest arc from `from` to `to`, wrapped into `[0, period)`.\
+/// Calculated in `f64`, so the sum of two arcs or a value plus the period can't overflow the value type.
+fn angular_blend<B: AsF64, U: F64Components>(from: U, to: U, offset: B, span: B, period: f64) -> U {
+    let fraction = offset.as_f64() / span.as_f64();
+    let wrap = |angle: f64| {
+        let angle = angle % period;
+        if angle < 0.0 { angle + period } else { angle }
+    };
+    U::map_components([from, to], |[from, to]| {
+        let upward = wrap(to - from);
+        let arc = if upward + upward > period { upward - period } else { upward };
+        wrap(from + arc * fraction)
+    })
+}
+
+/// Returns `from + (to - from) * offset / span` with checked arithmetic, the offset is eased first for the smooth interpolation methods.
+/// If `to - from` doesn't fit, like a falling segment of an unsigned type, it is calculated as `from - (from - to) * offset / span`,
+/// so unsigned types only fail if the result itself is negative.
+fn checked_blend<B, U>(from: U, to: U, offset: B, span: B, interpolation: &Interpolation) -> Option<U>
+where B: AsF64, U: From<B> + F64Components + CheckedArithmetic + Copy{
+    //the arc is calculated in f64 and wrapped into the period, it can't overflow
+    if let Interpolation::AngularLinear { period } = *interpolation {
+        return Some(angular_blend(from, to, offset, span, period));
+    }
+    let offset: U = ease(offset, span, interpolation);
+    let span = U::from(span);
+    match to.checked_sub(from) {
+        Some(diff_values) => offset.checked_mul(diff_values)?.checked_div(span)?.checked_add(from),
+        None => from.checked_sub(offset.checked_mul(from.checked_sub(to)?)?.checked_div(span)?),
+    }
+}
+
+/// Returns the index of the breakpoint to use and the index of the breakpoint below it if the result must be interpolated,
+/// with checked arithmetic for `NoneClosest`. Out of range breakpoints hold the extreme index.
+fn checked_indexes<B>(breakpoints: &[B], calc_breakpoint: B, interpolation: &Interpolation) -> Option<(usize,Option<usize>)>
+where B: PartialOrd + CheckedArithmetic + Copy{
+    Some(match breakpoints.iter().position(|bp| bp >= &calc_breakpoint) {
+        Some(index) if index == 0 || breakpoints[index] == calc_breakpoint => (index,None),
+        Some(index) => match interpolation {
+            Interpolation::Linear | Interpolation::Smoothstep | Interpolation::Smootherstep | Interpolation::Cubic | Interpolation::AngularLinear { .. } => (index,Some(index-1)),
+            Interpolation::NoneCeiling => (index,None),
+            Interpolation::NoneFloor => (index-1,None),
+            Interpolation::NoneClosest => {
+                let diff_low = calc_breakpoint.checked_sub(breakpoints[index-1])?;
+                let diff_high = breakpoints[index].checked_sub(calc_breakpoint)?;
+                if diff_high > diff_low {(index-1,None)} else {(index,None)}
+            }
+        },
+        None => (breakpoints.len()-1,None),
+    })
+}
+
+/// Returns the two indexes of an axis that are blended and their linear weights, from the indexes found for linear interpolation.\
+/// If there is nothing to blend the index is listed twice, with a weight of 1.0 and 0.0.
+fn axis_weights<B: Copy + Into<f64>>(breakpoints: &[B], indexes: (usize,Option<usize>), breakpoint: B) -> [(usize, f64); 2] {
+    match indexes.1 {
+        Some(index_low) => {
+            let breakpoint_low: f64 = breakpoints[index_low].into();
+            let breakpoint_high: f64 = breakpoints[indexes.0].into();
+            let fraction = (breakpoint.into() - breakpoint_low) / (breakpoint_high - breakpoint_low);
+            [(index_low, 1.0 - fraction), (indexes.0, fraction)]
+        },
+        None => [(indexes.0, 1.0), (indexes.0, 0.0)],
+    }
+}
+
+/// Returns the index of the breakpoint of a 2-D axis to use and the index of the breakpoint below it if the result must be interpolated.\
+/// Out of range breakpoints hold the extreme index.
+#[cfg(feature = "std")]
+fn axis_indexes<B: PartialOrd + Sub<Output = B> + Copy>(breakpoints: &[B], calc_breakpoint: B, interpolation: &Interpolation) -> (usize,Option<usize>) {
+    axis_indexes_at(breakpoints, breakpoints.iter().position(|bp| bp >= &calc_breakpoint), calc_breakpoint, interpolation)
+}
+
+/// Calculates the position of a breakpoint on an evenly spaced axis from its start and step, see `uniform_position`.\
+/// It is stored with the axis, so only the constructors of uniform tables require the breakpoints to convert to `f64`.
+type UniformPosition<B> = fn(&[B], (B, B), B) -> Option<usize>;
+
+/// Returns the index of the first breakpoint of an evenly spaced axis that isn't below the entered breakpoint, like searching the axis would.\
+/// The index is calculated from the start and step of the axis, the breakpoints next to it are only compared to correct the rounding of the division.
+fn uniform_position<B: PartialOrd + Sub<Output = B> + AsF64>(breakpoints: &[B], (start, step): (B, B), calc_breakpoint: B) -> Option<usize> {
+    let last = breakpoints.len() - 1;
+    if calc_breakpoint <= start {
+        return Some(0);
+    }
+    //also catches NaN, which no breakpoint is greater than or equal to
+    if !matches!(calc_breakpoint.partial_cmp(&breakpoints[last]), Some(Ordering::Less | Ordering::Equal)) {
+        return None;
+    }
+    let mut index = (libm::ceil((calc_breakpoint - start).as_f64() / step.as_f64()) as usize).clamp(1, last);
+    while index > 1 && breakpoints[index - 1] >= calc_breakpoint {
+        index -= 1;
+    }
+    while breakpoints[index] < calc_breakpoint {
+        index += 1;
+    }
+    Some(index)
+}
+
+/// Returns the indexes like `axis_indexes`, from the position of the first breakpoint that isn't below the entered breakpoint.
+fn axis_indexes_at<B: PartialOrd + Sub<Output = B> + Copy>(breakpoints: &[B], position: Option<usize>, calc_breakpoint: B, interpolation: &Interpolation) -> (usize,Option<usize>) {
+    match position {
+        Some(index) => {
+            //easy exit if bp matches existing bp
+            if breakpoints[index] == calc_breakpoint {
+                (index,None)
+            //interpolation zone
+            } else if index != 0 {
+                match interpolation {
+                    Interpolation::Linear | Interpolation::Smoothstep | Interpolation::Smootherstep | Interpolation::Cubic | Interpolation::AngularLinear { .. } => {
+                        (index, Some(index -1))
+                    },
+                    Interpolation::NoneCeiling => (index,None),
+                    Interpolation::NoneFloor => (index-1,None),
+                    Interpolation::NoneClosest => {
+                        let interpolated_diff_bp = calc_breakpoint - breakpoints[index -1];
+                        let diff_actual_bp = breakpoints[index] - breakpoints[index-1];
+                        let diff_factor = diff_actual_bp - interpolated_diff_bp;
+                        //compares both distances instead of halving the span, which needs no division
+                        let round: usize = if diff_factor > interpolated_diff_bp
+                            {
+                            0
+                        } else {
+                            1
+                        };
+                        (index-1+round,None)
+                    }
+                }
+            } else {
+                //low end out of bounds
+                (0,None)
+            }
+        },
+        //high end out of bounds
+        None => (breakpoints.len()-1,None)
+    }
+}
+
+/// Returns the value of a 2-D lookup table at the entered breakpoints, from the indexes found by `axis_indexes` for both axes.\
+/// The breakpoints, indexes and entered breakpoints are passed as horizontal and vertical pairs,
+/// `value` returns the stored value at a vertical and a horizontal index.
+/// `blend_h` and `blend_v` interpolate between two values from an offset and a span on their axis, so the bounds of the interpolation method stay with the caller.
+fn interpolate_2d<S, T, U>(breakpoints: (&[S], &[T]), value: impl Fn(usize, usize) -> U, indexes: ((usize,Option<usize>), (usize,Option<usize>)), breakpoint: (S, T), blend_h: impl Fn(U, U, S, S) -> U, blend_v: impl Fn(U, U, T, T) -> U) -> U
+where S: Sub<Output = S> + Copy, T: Sub<Output = T> + Copy, U: Copy{
+    let ((breakpoints_h, breakpoints_v), (indexes_h, indexes_v), (breakpoint_h, breakpoint_v)) = (breakpoints, indexes, breakpoint);
+    let (intermediary_values, index_v_low) = match (indexes_h.1, indexes_v.1) {
+        (Some(index_h_low), Some(index_v_low)) => {
+            let offset_h = breakpoint_h - breakpoints_h[index_h_low];
+            let diff_actual_bp_h = breakpoints_h[indexes_h.0] - breakpoints_h[index_h_low];
+            ([
+                blend_h(value(index_v_low, index_h_low), value(index_v_low, indexes_h.0), offset_h, diff_actual_bp_h),
+                blend_h(value(indexes_v.0, index_h_low), value(indexes_v.0, indexes_h.0), offset_h, diff_actual_bp_h)
+            ], index_v_low)
+        },
+        (None, None) => return value(indexes_v.0, indexes_h.0),
+        (None, Some(index_v_low)) => {
+            ([
+                value(index_v_low, indexes_h.0),
+                value(indexes_v.0, indexes_h.0)
+            ], index_v_low)
+        },
+        (Some(index_h_low), None) => {
+            let offset_h = breakpoint_h - breakpoints_h[index_h_low];
+            let diff_actual_bp_h = breakpoints_h[indexes_h.0] - breakpoints_h[index_h_low];
+            return blend_h(value(indexes_v.0, index_h_low), value(indexes_v.0, indexes_h.0), offset_h, diff_actual_bp_h)
+        }
+    };
+
+    let offset_v = breakpoint_v - breakpoints_v[index_v_low];
+    let diff_actual_bp_v = breakpoints_v[indexes_v.0] - breakpoints_v[index_v_low];
+    blend_v(intermediary_values[0], intermediary_values[1], offset_v, diff_actual_bp_v)
+}
+
+/// Returns the indexes found for linear interpolation together with the weight of the upper index, 1.0 if there is nothing to blend.
+fn axis_bracket<B: Copy + Into<f64>>(breakpoints: &[B], indexes: (usize,Option<usize>), breakpoint: B) -> (usize, Option<usize>, f64) {
+    match indexes.1 {
+        Some(_) => (indexes.0, indexes.1, axis_weights(breakpoints, indexes, breakpoint)[1].1),
+        None => (indexes.0, None, 1.0),
+    }
+}
+
+/// Returns whether the entered value is zero, for types without a constructor for 0.
+fn is_zero<X: Sub<Output = X> + PartialEq + Copy>(value: X) -> bool {
+    //the type has no constructor for 0, so compare with the difference of the value with itself
+    #[allow(clippy::eq_op)]
+    let zero = value - value;
+    value == zero
+}
+
+/// Formats the items as a comma separated list, `Debug` keeps the decimal point of floats so the list is valid Rust source.
+#[cfg(feature = "std")]
+fn join_debug<T: fmt::Debug>(items: &[T]) -> String {
+    items.iter().map(|item| format!("{:?}", item)).collect::<Vec<String>>().join(", ")
+}
+
+/// Formats the items as a comma separated list of literals suffixed with their type, like `5000000000u64`,
+/// so the source has the same types wherever it is included.
+#[cfg(feature = "std")]
+fn join_literals<T: SourceLiteral>(items: &[T]) -> String {
+    items.iter().map(|item| format!("{:?}{}", item, T::TYPE_NAME)).collect::<Vec<String>>().join(", ")
+}
+
+/// Random access to the breakpoints and values of a 1-D lookup table, so tables with different storage share the lookup logic.
+trait Knots<T, U> {
+    /// The number of breakpoint-value pairs.
+    fn count(&self) -> usize;
+    /// Returns the breakpoint at the entered index.
+    fn breakpoint(&self, index: usize) -> T;
+    /// Returns the value at the entered index.
+    fn value(&self, index: usize) -> U;
+}
+
+impl<T: Copy, U: Copy> Knots<T, U> for (&[T], &[U]) {
+    fn count(&self) -> usize {
+        self.0.len()
+    }
+    fn breakpoint(&self, index: usize) -> T {
+        self.0[index]
+    }
+    fn value(&self, index: usize) -> U {
+        self.1[index]
+    }
+}
+
+/// A view of the knots of a 1-D lookup table together with the deltas at its edges, which evaluates lookups.
+struct KnotsView<K, T> {
+    knots: K,
+    last_diff_bp: T,
+    first_diff_bp: T,
+}
+
+impl<
+T: PartialOrd + Add + Copy + Clone + Sub<Output = T> + Div<Output = T>, 
+K,
+>
+KnotsView<K,T>{
+    /// Calculates the result for a breakpoint that is already converted to `T`, see `OneDLookup::evaluate`.\
+    /// The interpolation methods that are calculated in `f64` interpolate linearly here, see `nonlinear_segment`.
+    fn evaluate<U>(&self, calc_breakpoint: T, position: Option<usize>, extrapolation: Extrapolation<U>, interpolation: Interpolation) -> Result<U, ExtrapolationError>
+    where K: Knots<T, U>, U: Sub<Output = U> + Add<Output = U> + Copy + Clone + From<T> + Mul<Output = U> + Div<Output = U> + EdgeDelta{
+        match position {
+            Some(index) => {
+                if self.knots.breakpoint(index) == calc_breakpoint {
+                    return Ok(self.knots.value(index)) 
+                }
+                else if index != 0 {
+                    // handle interpolation
+                    return match interpolation {
+                        Interpolation::Linear | Interpolation::Smoothstep | Interpolation::Smootherstep | Interpolation::Cubic | Interpolation::AngularLinear { .. } => {
+                            let interpolated_diff_bp = calc_breakpoint - self.knots.breakpoint(index -1);
+                            let diff_actual_bp = self.knots.breakpoint(index) - self.knots.breakpoint(index-1);
+                            Ok(U::lerp(self.knots.value(index-1), self.knots.value(index), U::from(interpolated_diff_bp), U::from(diff_actual_bp)))
+                        },
+                        Interpolation::NoneCeiling => {Ok(self.knots.value(index))},
+                        Interpolation::NoneFloor => {Ok(self.knots.value(index-1))},
+                        Interpolation::NoneClosest => {
+                            let interpolated_diff_bp = calc_breakpoint - self.knots.breakpoint(index -1);
+                            let diff_actual_bp = self.knots.breakpoint(index) - self.knots.breakpoint(index-1);
+                            let diff_factor = diff_actual_bp - interpolated_diff_bp;
+                            let round: usize = if diff_factor > interpolated_diff_bp
+                                {
+                                0
+                            } else {
+                                1
+                            };
+                            Ok(self.knots.value(index-1 + round))
+                        },
+                    }
+                }
+                // handle extrapolation at the low end
+                match extrapolation {
+                    Extrapolation::NoneError => Err(ExtrapolationError),
+                    Extrapolation::NoneHoldExtreme => Ok(self.knots.value(0)),
+                    //equal first breakpoints have no slope, dividing by their zero delta would panic or give infinity
+                    Extrapolation::Linear if is_zero(self.first_diff_bp) => Ok(self.knots.value(0)),
+                    Extrapolation::Linear => {
+                        //continue the first segment from the second value, so a falling edge of an unsigned type doesn't use a wrapped delta
+                        let extrapolated_diff_bp = self.knots.breakpoint(1) - calc_breakpoint;
+                        Ok(U::lerp(self.knots.value(1), self.knots.value(0), U::from(extrapolated_diff_bp), U::from(self.first_diff_bp)))
+                    },
+                    Extrapolation::LinearSlope { low, .. } => {
+                        let extrapolated_diff_bp = self.knots.breakpoint(0) - calc_breakpoint;
+                        Ok(self.knots.value(0) - U::from(extrapolated_diff_bp) * low)
+                    }
+                }
+            }
+            None => {
+                // handle extrapolation at the high end
+                let last = self.knots.count() - 1;
+                match extrapolation {
+                    Extrapolation::NoneError => Err(ExtrapolationError),
+                    Extrapolation::NoneHoldExtreme => Ok(self.knots.value(last)),
+                    Extrapolation::Linear if is_zero(self.last_diff_bp) => Ok(self.knots.value(last)),
+                    Extrapolation::Linear => {
+                        let extrapolated_diff_bp = calc_breakpoint - self.knots.breakpoint(last-1);
+                        Ok(U::lerp(self.knots.value(last-1), self.knots.value(last), U::from(extrapolated_diff_bp), U::from(self.last_diff_bp)))
+                    },
+                    Extrapolation::LinearSlope { high, .. } => {
+                        let extrapolated_diff_bp = calc_breakpoint - self.knots.breakpoint(last);
+                        Ok(U::from(extrapolated_diff_bp) * high + self.knots.value(last))
+                    }
+                }
+            }
+        }
+    }
+
+}
+
+impl<
+T: PartialOrd + Add + Copy + Clone + Sub<Output = T> + Div<Output = T> + AsF64, 
+K,
+>
+KnotsView<K,T>{
+    /// Returns the result of the interpolation methods that are calculated in `f64`, for a breakpoint that falls between two breakpoints of the table.
+    /// `None` for every other breakpoint and method, those are calculated by `evaluate`.
+    fn nonlinear_segment<U>(&self, calc_breakpoint: T, position: Option<usize>, interpolation: &Interpolation) -> Option<U>
+    where K: Knots<T, U>, U: Sub<Output = U> + Add<Output = U> + Copy + Clone + From<T> + Mul<Output = U> + Div<Output = U> + F64Components + EdgeDelta{
+        let index = position.filter(|&index| index != 0 && self.knots.breakpoint(index) != calc_breakpoint)?;
+        let interpolated_diff_bp = calc_breakpoint - self.knots.breakpoint(index -1);
+        let diff_actual_bp = self.knots.breakpoint(index) - self.knots.breakpoint(index-1);
+        match interpolation {
+            Interpolation::Cubic if self.knots.count() >= CUBIC_MIN_BREAKPOINTS => Some(self.cubic(calc_breakpoint, index)),
+            Interpolation::Smoothstep | Interpolation::Smootherstep | Interpolation::AngularLinear { .. } =>
+                Some(blend(self.knots.value(index-1), self.knots.value(index), interpolated_diff_bp, diff_actual_bp, interpolation)),
+            _ => None,
+        }
+    }
+
+    /// Calculates the Catmull-Rom cubic between the breakpoints `index - 1` and `index` for a breakpoint that falls between them.\
+    /// The slope at a breakpoint is the slope between its neighbours, the first and last breakpoint use the slope of their segment.
+    /// The cubic is calculated in `f64`, its terms go negative for falling data which unsigned value types can't hold.
+    fn cubic<U>(&self, calc_breakpoint: T, index: usize) -> U
+    where K: Knots<T, U>, U: F64Components{
+        let last = self.knots.count() - 1;
+        let (low, high) = (index - 1, index);
+        let (before, after) = (low.saturating_sub(1), (high + 1).min(last));
+        //the distances are calculated in the breakpoint type first, the later breakpoint is subtracted from so unsigned types don't wrap
+        let distance = |from: usize, to: usize| (self.knots.breakpoint(to) - self.knots.breakpoint(from)).as_f64();
+        let (span, offset) = (distance(low, high), (calc_breakpoint - self.knots.breakpoint(low)).as_f64());
+        let (span_low, span_high) = (distance(before, high), distance(low, after));
+        U::map_components([before, low, high, after].map(|knot| self.knots.value(knot)), |[value_before, value_low, value_high, value_after]| {
+            let (slope_low, slope_high) = ((value_high - value_before) / span_low, (value_after - value_low) / span_high);
+            let slope = (value_high - value_low) / span;
+            //the cubic Hermite polynomial in power form around the lower breakpoint
+            value_low
+                + slope_low * offset
+                + (3.0 * slope - 2.0 * slope_low - slope_high) * offset * offset / span
+                + (slope_low + slope_high - 2.0 * slope) * offset * offset * offset / (span * span)
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Extrapolation methods for lookup tables, `U` is the value type of the lookup table.
+pub enum Extrapolation<U> {
+    /// Error if the entered breakpoint exceeds the values in the lookup tables' breakpoints vector.
+    NoneError,
+    /// Hold the value at the first or last breakpoint in the lookup table if the entered breakpoint is not in the range of the breakpoints vector.
+    NoneHoldExtreme,
+    /// Extrapolate the result using the slope of the last or first 2 breakpoint-value pairs.
+    /// If those 2 breakpoints are equal there is no slope, and the value at the first or last breakpoint is held instead.
+    Linear,
+    /// Extrapolate the result from the first or last breakpoint-value pair using a known slope (value change per breakpoint unit)
+    /// instead of the slope of the edge segments.
+    LinearSlope {
+        /// The slope used below the first breakpoint
+        low: U,
+        /// The slope used above the last breakpoint
+        high: U,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// The side of the lookup table on which an entered breakpoint falls out of range
+pub enum Side {
+    /// The entered breakpoint is below the first breakpoint.
+    Low,
+    /// The entered breakpoint is above the last breakpoint.
+    High,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// Interpolation methods for lookup tables
+pub enum Interpolation {
+    /// Interpolate the result using the slope of the 2 breakpoint-value pairs that the entered breakpoint falls between. Worst for speed but best precision.
+    Linear,
+    /// Don't interpolate, always rounds down to previous value. Good for speed bad for precision.
+    NoneFloor,
+    /// Don't interpolate, always rounds up to the next value. Good for speed bad for precision.
+    NoneCeiling,
+    /// Blend the 2 breakpoint-value pairs with the smoothstep curve `3t²-2t³` of the fraction `t` of the segment,
+    /// so the result eases in and out of every breakpoint. The curve is calculated in `f64` by `lookup_nonlinear`, integer value types round the eased offset towards zero,
+    /// `lookup` interpolates linearly.
+    Smoothstep,
+    /// Blend the 2 breakpoint-value pairs with the smootherstep curve `6t⁵-15t⁴+10t³` of the fraction `t` of the segment,
+    /// which also eases the rate of change. The curve is calculated in `f64` by `lookup_nonlinear`, integer value types round the eased offset towards zero,
+    /// `lookup` interpolates linearly.
+    Smootherstep,
+    /// Don't interpolate, rounds to the nearest value. Kind of bad for speed better for precision.\
+    /// 2-D lookup tables round each axis independently, which also selects the knot that is nearest by Euclidean distance,
+    /// both in breakpoint units and with each axis normalized by its local spacing, because the squared distance to a knot is the sum of one term per axis.
+    NoneClosest,
+    /// Interpolate with a Catmull-Rom cubic through the 2 breakpoint-value pairs, with the slope at each breakpoint taken from its neighbours,
+    /// so the result and its rate of change are continuous at every breakpoint. The cubic is calculated in `f64`, integer value types round it towards zero.\
+    /// Only `OneDLookup::lookup_nonlinear` with at least 4 breakpoints uses the cubic, `lookup`, smaller tables, 2-D tables and `lookup_checked` interpolate linearly,
+    /// `OneDLookup::lookup_reporting` returns the method that was actually used.
+    Cubic,
+    /// Interpolate linearly along the shortest arc between angles, like headings or phases, with `period` the value of a full turn, for example `360.0` for degrees.\
+    /// Between 350° and 10° the result passes through 0° instead of going back through 180°, when both arcs are equally long the arc upward is used.
+    /// The values must be within `[0, period)`, the arc is calculated in `f64` by `lookup_nonlinear` and the result is wrapped into the same range,
+    /// `lookup` interpolates linearly.
+    /// Out of range breakpoints are extrapolated like `Linear`, without wrapping.
+    AngularLinear {
+        /// The value of a full turn
+        period: f64,
+    },
+}
+
+/// The number of breakpoints a 1-D lookup table needs for `Interpolation::Cubic`, smaller tables fall back to linear interpolation.
+const CUBIC_MIN_BREAKPOINTS: usize = 4;
+
+impl<U: AsF64> Extrapolation<U> {
+    /// Returns the same extrapolation method with its slopes converted to `f64`, for the lookups that are calculated in `f64`.
+    fn as_f64(self) -> Extrapolation<f64> {
+        match self {
+            Extrapolation::NoneError => Extrapolation::NoneError,
+            Extrapolation::NoneHoldExtreme => Extrapolation::NoneHoldExtreme,
+            Extrapolation::Linear => Extrapolation::Linear,
+            Extrapolation::LinearSlope { low, high } => Extrapolation::LinearSlope { low: low.as_f64(), high: high.as_f64() },
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<U> FromStr for Extrapolation<U> {
+    type Err = ParseModeError;
+
+    /// Parses an extrapolation method from its name, ignoring case, so it can be selected from a configuration file.\
+    /// Accepts `"error"`, `"hold_extreme"` and `"linear"`, with or without the `none_` prefix of the variant names.
+    /// `LinearSlope` needs slopes so it can't be parsed from a name.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use::go_lookup_tables::Extrapolation;
+    /// assert_eq!("Hold_Extreme".parse::<Extrapolation<f32>>(), Ok(Extrapolation::NoneHoldExtreme));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "error" | "none_error" => Ok(Extrapolation::NoneError),
+            "hold_extreme" | "none_hold_extreme" => Ok(Extrapolation::NoneHoldExtreme),
+            "linear" => Ok(Extrapolation::Linear),
+            _ => Err(ParseModeError { input: s.to_string() }),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl FromStr for Interpolation {
+    type Err = ParseModeError;
+
+    /// Parses an interpolation method from its name, ignoring case, so it can be selected from a configuration file.\
+    /// Accepts `"linear"`, `"floor"`, `"ceiling"`, `"closest"`, `"smoothstep"`, `"smootherstep"` and `"cubic"`,
+    /// with or without the `none_` prefix of the variant names, and `"angular_linear:"` followed by the period, like `"angular_linear:360"`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use::go_lookup_tables::Interpolation;
+    /// assert_eq!("Floor".parse::<Interpolation>(), Ok(Interpolation::NoneFloor));
+    /// assert!("quadratic".parse::<Interpolation>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "linear" => Ok(Interpolation::Linear),
+            "floor" | "none_floor" => Ok(Interpolation::NoneFloor),
+            "ceiling" | "none_ceiling" => Ok(Interpolation::NoneCeiling),
+            "closest" | "none_closest" => Ok(Interpolation::NoneClosest),
+            "smoothstep" => Ok(Interpolation::Smoothstep),
+            "smootherstep" => Ok(Interpolation::Smootherstep),
+            "cubic" => Ok(Interpolation::Cubic),
+            name => match name.strip_prefix("angular_linear:").map(|period| period.trim().parse::<f64>()) {
+                Some(Ok(period)) => Ok(Interpolation::AngularLinear { period }),
+                _ => Err(ParseModeError { input: s.to_string() }),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// The kind of a local extremum in the values of a lookup table
+pub enum Extremum {
+    /// The value is larger than its neighbours.
+    Maximum,
+    /// The value is smaller than its neighbours.
+    Minimum,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// How the analysis methods of a lookup table treat NaN values, a value is NaN if it doesn't compare equal to itself.
+pub enum NanPolicy {
+    /// A NaN poisons the result, `value_range` returns NaN and `assert_monotone` reports the NaN as the break in the order.
+    Propagate,
+    /// NaN values are skipped as if they weren't in the table.
+    Ignore,
+    /// A NaN returns a `NanAt` error with its index.
+    Error,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// A segment between two adjacent breakpoints of a 1-D lookup table, returned by `OneDLookup::segments`
+pub struct Segment<T, U> {
+    /// The breakpoint at the start of the segment.
+    pub lower: T,
+    /// The breakpoint at the end of the segment.
+    pub upper: T,
+    /// The value at the start of the segment.
+    pub lower_value: U,
+    /// The value at the end of the segment.
+    pub upper_value: U,
+    /// The change of the value per breakpoint unit, `(upper_value - lower_value) / (upper - lower)`.\
+    /// `None` if the slope can't be represented by `U`: for equal breakpoints, falling segments of unsigned value types, or on overflow.
+    pub slope: Option<U>,
+}
+
+/// Interpolation methods for step lookup tables, these never blend two values so they work for any value type.
+pub enum StepInterpolation {
+    /// Always rounds down to previous value.
+    NoneFloor,
+    /// Always rounds up to the next value.
+    NoneCeiling,
+    /// Rounds to the nearest value.
+    NoneClosest,
+}
+
+/// A struct representing a 1-D lookup table, breakpoints must be an ascending vector! 1,2,3,4 and not 4,3,2,1 or 1,2,3,2
+/// 
+/// Interpolating converts breakpoint deltas into the value type, so `lookup` requires `U: From<T>`.
+/// `From` is only implemented for lossless conversions, which guarantees that every breakpoint delta can be represented by `U`.
+/// For example `i32` breakpoints can't be paired with `f32` values, because an `f32` can't hold every `i32`, use `f64` values or `i16` breakpoints instead.
+/// 
+/// `lookup` interpolates and extrapolates as `value + (next value - value) * offset / span` with `EdgeDelta::lerp`,
+/// which calculates falling segments of unsigned value types the other way around so they don't wrap.
+/// `U` needs no order, value types without a natural order like complex numbers can be looked up as well.
+pub struct OneDLookup<T, U, const C: usize>{
+    /// The breakpoints that act as the index for the values.
+    breakpoints: [T;C],
+    /// The values that represent the result from the lookup.
+    values: [U;C],
+    /// constant value for the lookup table so it only has to be calculated at initialisation, instead of every function call.\
+    /// represents the delta between the last two breakpoints
+    last_diff_bp: T,
+    /// constant value for the lookup table so it only has to be calculated at initialisation, instead of every function call.\
+    /// represents the delta between the last two values
+    last_diff_values: U,
+    /// constant value for the lookup table so it only has to be calculated at initialisation, instead of every function call.\
+    /// represents the delta between the first two breakpoints
+    first_diff_bp: T,
+    /// constant value for the lookup table so it only has to be calculated at initialisation, instead of every function call.\
+    /// represents the delta between the first two values
+    first_diff_values: U,
+}
+
+impl<T, U, const C: usize> OneDLookup<T,U,C>{
+    /// This method is unsafe, consider using the create_1d_lookup!() macro instead.
+    /// Returns a lookup table. Only use an ascending breakpoints vector! for example  1,2,3,4 and not 4,3,2,1 or 1,2,3,2 \
+    /// breakpoints and values must have the same length!\
+    /// The cached value deltas aren't used by the lookups, so for unsigned value types a wrapped delta of a falling edge is harmless.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `breakpoints` - The breakpoints that act as the index for the values
+    /// * `values` - The values that represent the result from the lookup
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use::go_lookup_tables::{OneDLookup};
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,4> = OneDLookup::new([0,500,4500,5000], [0.0,0.0,500.0,500.0], 500, 0.0, -500, 0.0); //simple 0.5V to 4.5V pressure sensor
+    /// ```
+    pub const fn new(breakpoints: [T;C], values: [U;C], last_diff_bp: T, last_diff_values: U, first_diff_bp: T, first_diff_values: U) -> OneDLookup<T,U,C> where [T;C]: Sized, [U;C]: Sized {
+        OneDLookup {
+            last_diff_bp,
+            last_diff_values,
+            first_diff_bp,
+            first_diff_values,
+            breakpoints,
+            values,
+        }        
+    }
+
+    /// Returns a (interpolated) value from the lookup table that matches the entered breakpoint, calculated and returned in `f64`.\
+    /// Unlike `lookup` this only needs the breakpoints and values to convert into `f64`,
+    /// so it works for value types without `From` for the breakpoint type or `Neg`, like `u32` values with `i16` breakpoints.
+    /// The slopes of `Extrapolation::LinearSlope` are given in `f64` as well.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `breakpoint` - A reference to the breakpoint for which a value must be found by the lookup table
+    /// * `extrapolation` - The extrapolation method to use for this lookup operation
+    /// * `interpolation` - The interpolation method to use for this lookup operation
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use::go_lookup_tables::{OneDLookup, Interpolation, Extrapolation};
+    /// //u32 doesn't implement From<i16>, so lookup isn't available
+    /// let lookup_table: OneDLookup<i16,u32,2> = OneDLookup::try_new([0,100], [0,1000]).unwrap();
+    /// assert_eq!(lookup_table.lookup_f64(&25i16, Extrapolation::NoneError, Interpolation::Linear).unwrap(), 250.0);
+    /// ```
+    pub fn lookup_f64<Y: Copy>(&self, breakpoint: &Y, extrapolation: Extrapolation<f64>, interpolation: Interpolation) -> Result<f64, ExtrapolationError>
+    where T: From<Y> + Into<f64> + Copy, U: Into<f64> + Copy{
+        let calc_breakpoint: f64 = T::from(*breakpoint).into();
+        let knots = F64Knots { breakpoints: &self.breakpoints, values: &self.values };
+        let position = (0..C).find(|index| knots.breakpoint(*index) >= calc_breakpoint);
+        let view = KnotsView {
+            last_diff_bp: knots.breakpoint(C-1) - knots.breakpoint(C-2),
+            first_diff_bp: knots.breakpoint(1) - knots.breakpoint(0),
+            knots,
+        };
+        match view.nonlinear_segment(calc_breakpoint, position, &interpolation) {
+            Some(value) => Ok(value),
+            None => view.evaluate(calc_breakpoint, position, extrapolation, interpolation),
+        }
+    }
+}
+
+
+impl<
+T: PartialOrd + Add + Copy + Clone + Sub<Output = T> + Div<Output = T>, 
+U: Sub<Output = U>  + Add<Output = U> + Copy + Clone + From<T> + Mul<Output = U> + Div<Output = U> + EdgeDelta,
+const C: usize
+>
+OneDLookup<T,U,C>{
+    /// Returns a (interpolated) value from the lookup table that matches the entered breakpoint.\
+    /// `Interpolation::Smoothstep`, `Smootherstep`, `Cubic` and `AngularLinear` are calculated in `f64`, which `lookup` doesn't require of the table types,
+    /// so it interpolates linearly for them. Use `lookup_nonlinear` for those methods.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `breakpoint` - A reference to the breakpoint for which a value must be found by the lookup table
+    /// * `extrapolation` - The extrapolation method to use for this lookup operation
+    /// * `interpolation` - The interpolation method to use for this lookup operation
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{OneDLookup, Interpolation, Extrapolation};
+    /// let measured_voltage = 2000i16;
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,4> = create_1d_lookup!((0,500,4500,5000), (0.0,0.0,500.0,500.0));//simple 0.5V to 4.5V pressure sensor
+    /// let pressure = LOOKUP_TABLE.lookup(&measured_voltage, Extrapolation::NoneHoldExtreme, Interpolation::Linear).unwrap();
+    /// assert_eq!(pressure, 187.5f32)
+    /// # }
+    /// ```
+    pub fn lookup<Y: Copy>(&self, breakpoint: &Y, extrapolation: Extrapolation<U>, interpolation: Interpolation) -> Result<U, ExtrapolationError>
+    where T: From<Y>{
+        let calc_breakpoint = T::from(*breakpoint);
+        let position = self.breakpoints.iter().position(|bp| bp >= &calc_breakpoint);
+        self.evaluate(calc_breakpoint, position, extrapolation, interpolation)
+    }
+
+    /// Returns a (interpolated) value from the lookup table that matches the entered breakpoint, like `lookup`,
+    /// but also calculates the interpolation methods that aren't linear: `Interpolation::Smoothstep`, `Smootherstep`, `Cubic` and `AngularLinear`.\
+    /// Those are calculated in `f64`, so the breakpoints must convert to `f64` and the values must be calculated on in `f64` one component at a time.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `breakpoint` - A reference to the breakpoint for which a value must be found by the lookup table
+    /// * `extrapolation` - The extrapolation method to use for this lookup operation
+    /// * `interpolation` - The interpolation method to use for this lookup operation
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{OneDLookup, Interpolation, Extrapolation};
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,2> = create_1d_lookup!((0,400), (0.0,100.0));
+    /// assert_eq!(LOOKUP_TABLE.lookup_nonlinear(&100i16, Extrapolation::NoneError, Interpolation::Smoothstep).unwrap(), 15.625);
+    /// assert_eq!(LOOKUP_TABLE.lookup(&100i16, Extrapolation::NoneError, Interpolation::Smoothstep).unwrap(), 25.0);
+    /// # }
+    /// ```
+    pub fn lookup_nonlinear<Y: Copy>(&self, breakpoint: &Y, extrapolation: Extrapolation<U>, interpolation: Interpolation) -> Result<U, ExtrapolationError>
+    where T: From<Y> + AsF64, U: F64Components{
+        let calc_breakpoint = T::from(*breakpoint);
+        let position = self.breakpoints.iter().position(|bp| bp >= &calc_breakpoint);
+        match self.knots_view().nonlinear_segment(calc_breakpoint, position, &interpolation) {
+            Some(value) => Ok(value),
+            None => self.evaluate(calc_breakpoint, position, extrapolation, interpolation),
+        }
+    }
+
+    /// Returns a linearly interpolated angle from a lookup table of angles, like headings or phases, interpolating along the shortest arc.\
+    /// A shorthand for `lookup_nonlinear` with `Interpolation::AngularLinear`, with the period in the value type.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `breakpoint` - A reference to the breakpoint for which a value must be found by the lookup table
+    /// * `extrapolation` - The extrapolation method to use for this lookup operation
+    /// * `period` - The value of a full turn, for example `360.0` for degrees
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{OneDLookup, Extrapolation};
+    /// const HEADING: OneDLookup<i16,f32,3> = create_1d_lookup!((0,10,20), (350.0,10.0,40.0));
+    /// assert_eq!(HEADING.lookup_angular(&5i16, Extrapolation::NoneError, 360.0).unwrap(), 0.0);
+    /// assert_eq!(HEADING.lookup_angular(&15i16, Extrapolation::NoneError, 360.0).unwrap(), 25.0);
+    /// # }
+    /// ```
+    pub fn lookup_angular<Y: Copy>(&self, breakpoint: &Y, extrapolation: Extrapolation<U>, period: U) -> Result<U, ExtrapolationError>
+    where T: From<Y> + AsF64, U: AsF64 + F64Components{
+        self.lookup_nonlinear(breakpoint, extrapolation, Interpolation::AngularLinear { period: period.as_f64() })
+    }
+
+    /// Looks up every breakpoint in `breakpoints` like `lookup`, for offline resampling of query arrays.\
+    /// The first failing lookup is returned as the error.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `breakpoints` - The breakpoints for which values must be found by the lookup table
+    /// * `extrapolation` - The extrapolation method to use for these lookup operations
+    /// * `interpolation` - The interpolation method to use for these lookup operations
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{OneDLookup, Interpolation, Extrapolation};
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,4> = create_1d_lookup!((0,500,4500,5000), (0.0,0.0,500.0,500.0));
+    /// let pressures = LOOKUP_TABLE.lookup_many(&[0i16, 2000, 5000], Extrapolation::NoneError, Interpolation::Linear).unwrap();
+    /// assert_eq!(pressures, vec![0.0, 187.5, 500.0]);
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn lookup_many<Y: Copy>(&self, breakpoints: &[Y], extrapolation: Extrapolation<U>, interpolation: Interpolation) -> Result<Vec<U>, ExtrapolationError>
+    where T: From<Y>{
+        breakpoints.iter().map(|breakpoint| self.lookup(breakpoint, extrapolation, interpolation)).collect()
+    }
+
+    /// Looks up every breakpoint in `breakpoints` like `lookup_many`, splitting the breakpoints across the rayon thread pool.
+    /// Enabled with the `rayon` feature.\
+    /// The results are in the same order as `breakpoints`, and match those of `lookup_many`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `breakpoints` - The breakpoints for which values must be found by the lookup table
+    /// * `extrapolation` - The extrapolation method to use for these lookup operations
+    /// * `interpolation` - The interpolation method to use for these lookup operations
+    #[cfg(feature = "rayon")]
+    pub fn lookup_many_par<Y: Copy + Sync>(&self, breakpoints: &[Y], extrapolation: Extrapolation<U>, interpolation: Interpolation) -> Result<Vec<U>, ExtrapolationError>
+    where T: From<Y> + Sync, U: Send + Sync{
+        use rayon::prelude::*;
+        breakpoints.par_iter().map(|breakpoint| self.lookup(breakpoint, extrapolation, interpolation)).collect()
+    }
+
+    /// Calculates the result for a breakpoint that is already converted to `T`.\
+    /// `position` is the index of the first breakpoint that is greater than or equal to `calc_breakpoint`, `None` if there is none.
+    /// With the `log` feature a breakpoint outside of the table is logged as a warning, so deployed systems report running outside of their calibration.
+    /// The breakpoint type has no way to be formatted, so the warning names the side of the table instead of the breakpoint.
+    fn evaluate(&self, calc_breakpoint: T, position: Option<usize>, extrapolation: Extrapolation<U>, interpolation: Interpolation) -> Result<U, ExtrapolationError>
+    {
+        let result = self.knots_view().evaluate(calc_breakpoint, position, extrapolation, interpolation);
+        #[cfg(feature = "log")]
+        if position.is_none_or(|index| index == 0 && self.breakpoints[0] != calc_breakpoint) {
+            let side = if position.is_some() { "below the first" } else { "above the last" };
+            match result {
+                Ok(_) => log::warn!("lookup {} of the {} breakpoints of the table, the result is extrapolated", side, C),
+                Err(_) => log::warn!("lookup {} of the {} breakpoints of the table, NoneError returned an error", side, C),
+            }
+        }
+        result
+    }
+
+    /// Returns a view of the knots and cached breakpoint deltas of the lookup table, which evaluates lookups.
+    fn knots_view(&self) -> KnotsView<(&[T], &[U]), T> {
+        KnotsView {
+            knots: (&self.breakpoints[..], &self.values[..]),
+            last_diff_bp: self.last_diff_bp,
+            first_diff_bp: self.first_diff_bp,
+        }
+    }
+
+    /// Returns a (interpolated) value from the lookup table like `lookup`, together with the interpolation method that was actually used.\
+    /// `Interpolation::Cubic` falls back to `Interpolation::Linear` for tables with less than 4 breakpoints, every other method is used as entered.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{OneDLookup, Interpolation, Extrapolation};
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,3> = create_1d_lookup!((0,500,1000), (0.0,100.0,400.0));
+    /// let (value, applied) = LOOKUP_TABLE.lookup_reporting(&250i16, Extrapolation::NoneError, Interpolation::Cubic).unwrap();
+    /// assert_eq!((value, applied), (50.0, Interpolation::Linear));
+    /// # }
+    /// ```
+    pub fn lookup_reporting<Y: Copy>(&self, breakpoint: &Y, extrapolation: Extrapolation<U>, interpolation: Interpolation) -> Result<(U, Interpolation), ExtrapolationError>
+    where T: From<Y> + AsF64, U: F64Components{
+        let applied = match interpolation {
+            Interpolation::Cubic if C < CUBIC_MIN_BREAKPOINTS => Interpolation::Linear,
+            requested => requested,
+        };
+        Ok((self.lookup_nonlinear(breakpoint, extrapolation, interpolation)?, applied))
+    }
+    /// Returns a (interpolated) value from the lookup table that matches the entered breakpoint, like `lookup`,
+    /// but every subtraction, multiplication and division is checked. Instead of wrapping or panicking this returns
+    /// `LookupError::ArithmeticOverflow`, so the result is either correct or an error.
+    /// `Interpolation::Cubic` falls back to linear interpolation.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `breakpoint` - A reference to the breakpoint for which a value must be found by the lookup table
+    /// * `extrapolation` - The extrapolation method to use for this lookup operation
+    /// * `interpolation` - The interpolation method to use for this lookup operation
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{OneDLookup, Interpolation, Extrapolation, LookupError};
+    /// const LOOKUP_TABLE: OneDLookup<i16,i16,2> = create_1d_lookup!((0,1000), (0,1000));
+    /// let result = LOOKUP_TABLE.lookup_checked(&500i16, Extrapolation::NoneHoldExtreme, Interpolation::Linear);
+    /// assert_eq!(result, Err(LookupError::ArithmeticOverflow)); //500 * 1000 doesn't fit in an i16
+    /// # }
+    /// ```
+    pub fn lookup_checked<Y: Copy>(&self, breakpoint: &Y, extrapolation: Extrapolation<U>, interpolation: Interpolation) -> Result<U, LookupError>
+    where T: From<Y> + CheckedArithmetic + AsF64, U: CheckedArithmetic + F64Components{
+        let calc_breakpoint = T::from(*breakpoint);
+        let result = match self.breakpoints.iter().position(|bp| bp >= &calc_breakpoint) {
+            Some(index) if self.breakpoints[index] == calc_breakpoint => Some(self.values[index]),
+            // handle extrapolation at the low end
+            Some(0) => match extrapolation {
+                Extrapolation::NoneError => return Err(LookupError::OutOfRange),
+                Extrapolation::NoneHoldExtreme => Some(self.values[0]),
+                Extrapolation::Linear => self.breakpoints[1].checked_sub(calc_breakpoint)
+                    .and_then(|offset| checked_blend(self.values[1], self.values[0], offset, self.first_diff_bp, &Interpolation::Linear)),
+                Extrapolation::LinearSlope { low, .. } => self.breakpoints[0].checked_sub(calc_breakpoint)
+                    .and_then(|offset| U::from(offset).checked_mul(low))
+                    .and_then(|delta| self.values[0].checked_sub(delta)),
+            },
+            // handle interpolation
+            Some(index) => match interpolation {
+                Interpolation::Linear | Interpolation::Smoothstep | Interpolation::Smootherstep | Interpolation::Cubic | Interpolation::AngularLinear { .. } => calc_breakpoint.checked_sub(self.breakpoints[index-1])
+                    .zip(self.breakpoints[index].checked_sub(self.breakpoints[index-1]))
+                    .and_then(|(offset, span)| checked_blend(self.values[index-1], self.values[index], offset, span, &interpolation)),
+                Interpolation::NoneCeiling => Some(self.values[index]),
+                Interpolation::NoneFloor => Some(self.values[index-1]),
+                Interpolation::NoneClosest => calc_breakpoint.checked_sub(self.breakpoints[index-1])
+                    .zip(self.breakpoints[index].checked_sub(calc_breakpoint))
+                    .map(|(diff_low, diff_high)| if diff_high > diff_low {self.values[index-1]} else {self.values[index]}),
+            },
+            // handle extrapolation at the high end
+            None => match extrapolation {
+                Extrapolation::NoneError => return Err(LookupError::OutOfRange),
+                Extrapolation::NoneHoldExtreme => Some(self.values[C-1]),
+                Extrapolation::Linear => calc_breakpoint.checked_sub(self.breakpoints[C-2])
+                    .and_then(|offset| checked_blend(self.values[C-2], self.values[C-1], offset, self.last_diff_bp, &Interpolation::Linear)),
+                Extrapolation::LinearSlope { high, .. } => calc_breakpoint.checked_sub(self.breakpoints[C-1])
+                    .and_then(|offset| U::from(offset).checked_mul(high))
+                    .and_then(|delta| self.values[C-1].checked_add(delta)),
+            },
+        };
+        result.ok_or(LookupError::ArithmeticOverflow)
+    }
+
+    /// Returns a (interpolated) value from the lookup table that matches the entered breakpoint, for breakpoint types that don't fit in `T`.\
+    /// A breakpoint that can't be converted saturates to `T::MAX` if it is positive or `T::MIN` if it is negative, which then goes through the extrapolation.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `breakpoint` - A reference to the breakpoint for which a value must be found by the lookup table
+    /// * `extrapolation` - The extrapolation method to use for this lookup operation
+    /// * `interpolation` - The interpolation method to use for this lookup operation
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{OneDLookup, Interpolation, Extrapolation};
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,4> = create_1d_lookup!((0,500,4500,5000), (0.0,0.0,500.0,500.0));
+    /// let pressure = LOOKUP_TABLE.lookup_saturating_convert(&100_000u32, Extrapolation::NoneHoldExtreme, Interpolation::Linear).unwrap();
+    /// assert_eq!(pressure, 500.0f32)
+    /// # }
+    /// ```
+    pub fn lookup_saturating_convert<Y: Copy + PartialOrd + Default>(&self, breakpoint: &Y, extrapolation: Extrapolation<U>, interpolation: Interpolation) -> Result<U, ExtrapolationError>
+    where T: TryFrom<Y> + Bounded{
+        let calc_breakpoint = match T::try_from(*breakpoint) {
+            Ok(calc_breakpoint) => calc_breakpoint,
+            Err(_) if *breakpoint > Y::default() => T::MAX,
+            Err(_) => T::MIN,
+        };
+        let position = self.breakpoints.iter().position(|bp| bp >= &calc_breakpoint);
+        self.evaluate(calc_breakpoint, position, extrapolation, interpolation)
+    }
+
+    /// Returns a (interpolated) value from the lookup table that matches the entered breakpoint, converted to a smaller type like the `u8` of a DAC.\
+    /// The result saturates at the bounds of `V` instead of failing, see `SaturatingInto`:
+    /// integer results are narrowed exactly, float results are converted like an `as` cast, rounded towards zero with NaN becoming 0.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `breakpoint` - A reference to the breakpoint for which a value must be found by the lookup table
+    /// * `extrapolation` - The extrapolation method to use for this lookup operation
+    /// * `interpolation` - The interpolation method to use for this lookup operation
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{OneDLookup, Interpolation, Extrapolation};
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,2> = create_1d_lookup!((0,1000), (0.0,400.0));
+    /// let level: u8 = LOOKUP_TABLE.lookup_as(&500i16, Extrapolation::NoneHoldExtreme, Interpolation::Linear).unwrap();
+    /// assert_eq!(level, 200);
+    /// let level: u8 = LOOKUP_TABLE.lookup_as(&800i16, Extrapolation::NoneHoldExtreme, Interpolation::Linear).unwrap();
+    /// assert_eq!(level, 255);
+    /// # }
+    /// ```
+    pub fn lookup_as<V, Y: Copy>(&self, breakpoint: &Y, extrapolation: Extrapolation<U>, interpolation: Interpolation) -> Result<V, ExtrapolationError>
+    where T: From<Y>, U: SaturatingInto<V>{
+        self.lookup(breakpoint, extrapolation, interpolation).map(SaturatingInto::saturating_into)
+    }
+
+    /// Returns a (interpolated) value from the lookup table that matches the entered breakpoint, scaled by `gain` and offset by `bias`
+    /// after interpolating, as `result * gain + bias`. This trims a calibration without rebuilding the lookup table.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `breakpoint` - A reference to the breakpoint for which a value must be found by the lookup table
+    /// * `extrapolation` - The extrapolation method to use for this lookup operation
+    /// * `interpolation` - The interpolation method to use for this lookup operation
+    /// * `gain` - The factor the result is multiplied by
+    /// * `bias` - The offset that is added to the result after the gain
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{OneDLookup, Interpolation, Extrapolation};
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,4> = create_1d_lookup!((0,500,4500,5000), (0.0,0.0,500.0,500.0));
+    /// let pressure = LOOKUP_TABLE.lookup_adjusted(&2000i16, Extrapolation::NoneHoldExtreme, Interpolation::Linear, 1.02, -3.0).unwrap();
+    /// assert_eq!(pressure, 188.25f32);
+    /// # }
+    /// ```
+    pub fn lookup_adjusted<Y: Copy>(&self, breakpoint: &Y, extrapolation: Extrapolation<U>, interpolation: Interpolation, gain: U, bias: U) -> Result<U, ExtrapolationError>
+    where T: From<Y>{
+        Ok(self.lookup(breakpoint, extrapolation, interpolation)? * gain + bias)
+    }
+
+    /// Returns the smallest and largest result over the breakpoint interval `[low, high]`, for propagating the uncertainty of a measurement.\
+    /// The results at both ends are compared with the values of the knots inside the interval, since the values don't have to be monotone.
+    /// `Interpolation::Cubic` can overshoot the knots between them, those interior extrema are not searched.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `low` - A reference to the lower end of the breakpoint interval
+    /// * `high` - A reference to the upper end of the breakpoint interval
+    /// * `extrapolation` - The extrapolation method to use for the ends of the interval
+    /// * `interpolation` - The interpolation method to use for the ends of the interval
+    /// 
+    /// # Errors
+    /// 
+    /// Returns an `ExtrapolationError` if an end of the interval is out of range and the extrapolation method is `NoneError`.
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `low` is greater than `high`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{OneDLookup, Interpolation, Extrapolation};
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,3> = create_1d_lookup!((0,100,200), (0.0,10.0,0.0));
+    /// let (min, max) = LOOKUP_TABLE.lookup_interval(&90i16, &120i16, Extrapolation::NoneError, Interpolation::Linear).unwrap();
+    /// assert_eq!((min, max), (8.0, 10.0));
+    /// # }
+    /// ```
+    pub fn lookup_interval<Y: Copy>(&self, low: &Y, high: &Y, extrapolation: Extrapolation<U>, interpolation: Interpolation) -> Result<(U, U), ExtrapolationError>
+    where T: From<Y>, U: PartialOrd{
+        let (calc_low, calc_high) = (T::from(*low), T::from(*high));
+        assert!(calc_low <= calc_high, "the low end of an interval can't be greater than the high end");
+        let at_low = self.lookup(low, extrapolation, interpolation)?;
+        let at_high = self.lookup(high, extrapolation, interpolation)?;
+        let (mut min, mut max) = if at_low < at_high { (at_low, at_high) } else { (at_high, at_low) };
+        for (bp, value) in self.breakpoints.iter().zip(self.values.iter()) {
+            if *bp > calc_low && *bp < calc_high {
+                if *value < min {
+                    min = *value;
+                }
+                if *value > max {
+                    max = *value;
+                }
+            }
+        }
+        Ok((min, max))
+    }
+
+    /// Returns a (interpolated) value from the lookup table that matches the entered breakpoint, clamped into `[min, max]` after interpolating.\
+    /// This limits the result to what an actuator accepts per call, without changing the stored values,
+    /// so extrapolated results can't command the actuator beyond its limits either.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `breakpoint` - A reference to the breakpoint for which a value must be found by the lookup table
+    /// * `extrapolation` - The extrapolation method to use for this lookup operation
+    /// * `interpolation` - The interpolation method to use for this lookup operation
+    /// * `min` - The smallest result that is returned
+    /// * `max` - The largest result that is returned
+    /// 
+    /// # Panics
+    /// 
+    /// Panics if `min` is larger than `max`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{OneDLookup, Interpolation, Extrapolation};
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,2> = create_1d_lookup!((0,100), (0.0,80.0));
+    /// let duty_cycle = LOOKUP_TABLE.lookup_clamped_output(&150i16, Extrapolation::Linear, Interpolation::Linear, 5.0, 95.0).unwrap();
+    /// assert_eq!(duty_cycle, 95.0f32);
+    /// # }
+    /// ```
+    pub fn lookup_clamped_output<Y: Copy>(&self, breakpoint: &Y, extrapolation: Extrapolation<U>, interpolation: Interpolation, min: U, max: U) -> Result<U, ExtrapolationError>
+    where T: From<Y>, U: PartialOrd{
+        assert!(min <= max, "min must not be larger than max");
+        let result = self.lookup(breakpoint, extrapolation, interpolation)?;
+        Ok(if result < min { min } else if result > max { max } else { result })
+    }
+
+    /// Looks up every breakpoint from an iterator and pushes the results into any `Extend` sink, without an intermediate allocation.\
+    /// This suits unbounded streams, for example writing into a ring buffer for real-time signal processing.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `breakpoints` - The breakpoints for which values must be found by the lookup table
+    /// * `out` - The sink that receives the results, in the order of the breakpoints
+    /// * `extrapolation` - The extrapolation method to use for these lookup operations
+    /// * `interpolation` - The interpolation method to use for these lookup operations
+    /// 
+    /// # Errors
+    /// 
+    /// Returns an `ExtrapolationError` at the first breakpoint that is out of range with `Extrapolation::NoneError`,
+    /// the results before it have already been pushed into the sink.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{OneDLookup, Interpolation, Extrapolation};
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,4> = create_1d_lookup!((0,500,4500,5000), (0.0,0.0,500.0,500.0));
+    /// let mut pressures = Vec::new();
+    /// LOOKUP_TABLE.resample_into([500i16, 2000, 4500].into_iter(), &mut pressures, Extrapolation::NoneError, Interpolation::Linear).unwrap();
+    /// assert_eq!(pressures, vec![0.0, 187.5, 500.0]);
+    /// # }
+    /// ```
+    pub fn resample_into<Y: Copy>(&self, breakpoints: impl Iterator<Item = Y>, out: &mut impl Extend<U>, extrapolation: Extrapolation<U>, interpolation: Interpolation) -> Result<(), ExtrapolationError>
+    where T: From<Y>{
+        let mut error = None;
+        out.extend(breakpoints.map_while(|breakpoint| match self.lookup(&breakpoint, extrapolation, interpolation) {
+            Ok(value) => Some(value),
+            Err(extrapolation_error) => {
+                error = Some(extrapolation_error);
+                None
+            },
+        }));
+        match error {
+            Some(extrapolation_error) => Err(extrapolation_error),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns a (interpolated) value from the lookup table that matches the entered breakpoint, extrapolating linearly when it is out of range.\
+    /// This is the same as `lookup` with `Extrapolation::Linear`, but the table size is checked at compile time and the result can't fail.
+    /// 
+    /// # Panics
+    /// 
+    /// `lookup_linear_extrapolation` fails to compile if the lookup table has less than 2 breakpoints.
+    /// 
+    /// ```compile_fail
+    /// use::go_lookup_tables::{OneDLookup, Interpolation};
+    /// let lookup_table: OneDLookup<i16,f32,1> = OneDLookup::new([0], [1.0], 0, 0.0, 0, 0.0);
+    /// lookup_table.lookup_linear_extrapolation(&5i16, Interpolation::Linear);
+    /// ```
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{OneDLookup, Interpolation};
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,2> = create_1d_lookup!((0,500), (0.0,50.0));
+    /// assert_eq!(LOOKUP_TABLE.lookup_linear_extrapolation(&1000i16, Interpolation::Linear), 100.0);
+    /// # }
+    /// ```
+    pub fn lookup_linear_extrapolation<Y: Copy>(&self, breakpoint: &Y, interpolation: Interpolation) -> U
+    where T: From<Y>{
+        let () = Self::HAS_OUTER_SEGMENTS;
+        self.lookup(breakpoint, Extrapolation::Linear, interpolation).expect("linear extrapolation doesn't fail")
+    }
+
+    /// Returns a (interpolated) value from the lookup table that matches the entered breakpoint, holding the extreme values when it is out of range.\
+    /// An out of range breakpoint also returns an `ExtrapolationError`, so the value can be used while the excursion is still logged.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `breakpoint` - A reference to the breakpoint for which a value must be found by the lookup table
+    /// * `interpolation` - The interpolation method to use for this lookup operation
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{OneDLookup, Interpolation};
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,4> = create_1d_lookup!((0,500,4500,5000), (0.0,0.0,500.0,500.0));
+    /// let (pressure, excursion) = LOOKUP_TABLE.lookup_or_clamped(&6000i16, Interpolation::Linear);
+    /// assert_eq!(pressure, 500.0f32);
+    /// assert!(excursion.is_some());
+    /// # }
+    /// ```
+    pub fn lookup_or_clamped<Y: Copy>(&self, breakpoint: &Y, interpolation: Interpolation) -> (U, Option<ExtrapolationError>)
+    where T: From<Y>{
+        let calc_breakpoint = T::from(*breakpoint);
+        let position = self.breakpoints.iter().position(|bp| bp >= &calc_breakpoint);
+        let excursion = if calc_breakpoint < self.breakpoints[0] || position.is_none() {
+            Some(ExtrapolationError)
+        } else {
+            None
+        };
+        let value = self.evaluate(calc_breakpoint, position, Extrapolation::NoneHoldExtreme, interpolation)
+            .expect("holding the extreme values doesn't fail");
+        (value, excursion)
+    }
+
+    /// Returns a (interpolated) value from the lookup table that matches the entered breakpoint, using a custom extrapolation function.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `breakpoint` - A reference to the breakpoint for which a value must be found by the lookup table
+    /// * `interpolation` - The interpolation method to use for this lookup operation
+    /// * `extrapolate` - Only called when the breakpoint is out of range, with the side it is on, the breakpoint and the lookup table itself
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{OneDLookup, Interpolation, Side};
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,2> = create_1d_lookup!((0,10), (0.0,10.0));
+    /// //quadratic growth beyond the last breakpoint
+    /// let quadratic = |side: Side, breakpoint: i16, _: &OneDLookup<i16,f32,2>| match side {
+    ///     Side::Low => 0.0,
+    ///     Side::High => 10.0 + ((breakpoint - 10) * (breakpoint - 10)) as f32,
+    /// };
+    /// assert_eq!(LOOKUP_TABLE.lookup_with_extrapolator(&12i16, Interpolation::Linear, quadratic).unwrap(), 14.0);
+    /// # }
+    /// ```
+    pub fn lookup_with_extrapolator<Y: Copy>(&self, breakpoint: &Y, interpolation: Interpolation, extrapolate: impl Fn(Side, T, &Self) -> U) -> Result<U, ExtrapolationError>
+    where T: From<Y>{
+        let calc_breakpoint = T::from(*breakpoint);
+        if calc_breakpoint < self.breakpoints[0] {
+            Ok(extrapolate(Side::Low, calc_breakpoint, self))
+        } else if calc_breakpoint > self.breakpoints[C-1] {
+            Ok(extrapolate(Side::High, calc_breakpoint, self))
+        } else {
+            self.lookup(breakpoint, Extrapolation::NoneError, interpolation)
+        }
+    }
+
+    /// Returns a linearly interpolated value from the lookup table that matches the entered breakpoint,
+    /// rounded to the nearest integer instead of truncated towards zero like `lookup` does for integer value types.\
+    /// Halfway results are rounded away from the lower breakpoint's value, extrapolated results aren't rounded.
+    /// For float value types the result is the same as `lookup`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `breakpoint` - A reference to the breakpoint for which a value must be found by the lookup table
+    /// * `extrapolation` - The extrapolation method to use for this lookup operation
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{OneDLookup, Interpolation, Extrapolation};
+    /// const LOOKUP_TABLE: OneDLookup<i16,i32,2> = create_1d_lookup!((0,3), (0,2));
+    /// assert_eq!(LOOKUP_TABLE.lookup(&1i16, Extrapolation::NoneError, Interpolation::Linear).unwrap(), 0);
+    /// assert_eq!(LOOKUP_TABLE.lookup_rounded(&1i16, Extrapolation::NoneError).unwrap(), 1);
+    /// # }
+    /// ```
+    pub fn lookup_rounded<Y: Copy>(&self, breakpoint: &Y, extrapolation: Extrapolation<U>) -> Result<U, ExtrapolationError>
+    where T: From<Y>, U: PartialOrd + DivRound{
+        let calc_breakpoint = T::from(*breakpoint);
+        match self.breakpoints.iter().position(|bp| bp >= &calc_breakpoint) {
+            Some(index) if index != 0 && self.breakpoints[index] != calc_breakpoint => {
+                let offset = U::from(calc_breakpoint - self.breakpoints[index-1]);
+                let span = U::from(self.breakpoints[index] - self.breakpoints[index-1]);
+                let (low, high) = (self.values[index-1], self.values[index]);
+                if high < low {
+                    //falling segment, subtract the other way around so unsigned value types don't wrap
+                    Ok(low - (offset * (low - high)).div_round(span))
+                } else {
+                    Ok(low + (offset * (high - low)).div_round(span))
+                }
+            },
+            position => self.evaluate(calc_breakpoint, position, extrapolation, Interpolation::Linear),
+        }
+    }
+
+    /// Returns the difference between an `f64` precise linear interpolation and the result of `lookup` for the entered breakpoint.\
+    /// This quantifies the truncation error of integer value types. Only `Interpolation::Linear` is compared,
+    /// the other interpolation methods return 0.0 because they deliberately differ from a linear interpolation. Out of range breakpoints hold the extreme values.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `breakpoint` - A reference to the breakpoint for which the residual must be calculated
+    /// * `interpolation` - The interpolation method to use for this lookup operation
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{OneDLookup, Interpolation};
+    /// const LOOKUP_TABLE: OneDLookup<i16,i32,2> = create_1d_lookup!((0,4), (0,10));
+    /// assert_eq!(LOOKUP_TABLE.interpolation_residual(&1i16, Interpolation::Linear), 0.5);
+    /// # }
+    /// ```
+    pub fn interpolation_residual<Y: Copy>(&self, breakpoint: &Y, interpolation: Interpolation) -> f64
+    where T: From<Y> + Into<f64>, U: Into<f64> + PartialOrd{
+        if !matches!(interpolation, Interpolation::Linear) {
+            return 0.0;
+        }
+        let calc_breakpoint = T::from(*breakpoint);
+        let result: f64 = match self.lookup(breakpoint, Extrapolation::NoneHoldExtreme, interpolation) {
+            Ok(result) => result.into(),
+            Err(_) => return 0.0,
+        };
+        let precise = match self.breakpoints.iter().position(|bp| bp >= &calc_breakpoint) {
+            Some(index) if index != 0 && self.breakpoints[index] != calc_breakpoint => {
+                let breakpoint_low: f64 = self.breakpoints[index-1].into();
+                let breakpoint_high: f64 = self.breakpoints[index].into();
+                let value_low: f64 = self.values[index-1].into();
+                let value_high: f64 = self.values[index].into();
+                (calc_breakpoint.into() - breakpoint_low) * (value_high - value_low) / (breakpoint_high - breakpoint_low) + value_low
+            },
+            _ => return 0.0,
+        };
+        precise - result
+    }
+
+    /// Returns the lookup table evaluated at evenly spaced breakpoints from the first to the last breakpoint, for example to plot or resample it.\
+    /// For integer breakpoint types the samples are truncated to whole breakpoints. The samples are kept within the breakpoints,
+    /// so `extrapolation` can't make a sample fail, it is only passed on to `lookup`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `count` - The number of samples, a single sample is taken at the first breakpoint
+    /// * `extrapolation` - The extrapolation method to use for the lookups
+    /// * `interpolation` - The interpolation method to use for the lookups
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{OneDLookup, Interpolation, Extrapolation};
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,4> = create_1d_lookup!((0,500,4500,5000), (0.0,0.0,500.0,500.0));
+    /// let curve = LOOKUP_TABLE.sample_uniform(3, Extrapolation::NoneError, Interpolation::Linear);
+    /// assert_eq!(curve, vec![(0, 0.0), (2500, 250.0), (5000, 500.0)]);
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn sample_uniform(&self, count: usize, extrapolation: Extrapolation<U>, interpolation: Interpolation) -> Vec<(T, U)>
+    where T: Into<f64> + FromF64{
+        let (first, last) = (self.breakpoints[0], self.breakpoints[C-1]);
+        let (low, high): (f64, f64) = (first.into(), last.into());
+        (0..count).map(|sample| {
+            let breakpoint = match sample {
+                0 => first,
+                _ if sample == count - 1 => last,
+                _ => {
+                    let breakpoint = T::from_f64(low + (high - low) * sample as f64 / (count - 1) as f64);
+                    if breakpoint > last { last } else if breakpoint < first { first } else { breakpoint }
+                },
+            };
+            (breakpoint, self.lookup(&breakpoint, extrapolation, interpolation).expect("the samples are within the breakpoints"))
+        }).collect()
+    }
+
+    /// Returns a (interpolated) value from the lookup table at a fraction of the span of the breakpoints, for example the position of a UI slider.\
+    /// `0.0` is the first breakpoint and `1.0` the last, fractions outside `[0,1]` are clamped and NaN is treated as `0.0`.
+    /// For integer breakpoint types the breakpoint is truncated to a whole breakpoint.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `percent` - The fraction of the span of the breakpoints, in `[0,1]`
+    /// * `interpolation` - The interpolation method to use for this lookup operation
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{OneDLookup, Interpolation};
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,4> = create_1d_lookup!((0,500,4500,5000), (0.0,0.0,500.0,500.0));
+    /// assert_eq!(LOOKUP_TABLE.lookup_percent(0.5, Interpolation::Linear), 250.0);
+    /// # }
+    /// ```
+    pub fn lookup_percent(&self, percent: f64, interpolation: Interpolation) -> U
+    where T: Into<f64> + FromF64{
+        let (first, last) = (self.breakpoints[0], self.breakpoints[C-1]);
+        let breakpoint = if percent >= 1.0 {
+            last
+        } else if percent > 0.0 {
+            let (low, high): (f64, f64) = (first.into(), last.into());
+            let breakpoint = T::from_f64(low + (high - low) * percent);
+            if breakpoint > last { last } else if breakpoint < first { first } else { breakpoint }
+        } else {
+            first
+        };
+        self.lookup(&breakpoint, Extrapolation::NoneHoldExtreme, interpolation).expect("holding the extreme values never fails")
+    }
+
+    /// Returns the largest absolute error of the linear interpolation of each segment against a reference function, to show where knots must be added.\
+    /// Each segment is sampled at `samples_per_segment` equal steps between its two breakpoints, both breakpoints included.
+    /// For integer breakpoint types the samples are truncated to whole breakpoints.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `f` - The function the lookup table approximates
+    /// * `samples_per_segment` - The number of steps each segment is divided in, `0` only compares the knots
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::OneDLookup;
+    /// const SQUARE: OneDLookup<f64,f64,3> = create_1d_lookup!((0.0,1.0,2.0), (0.0,1.0,4.0));
+    /// assert_eq!(SQUARE.approximation_error(|x| x * x, 2), vec![0.25, 0.25]);
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn approximation_error(&self, f: impl Fn(T) -> U, samples_per_segment: usize) -> Vec<f64>
+    where T: Into<f64> + FromF64, U: Into<f64>{
+        let steps = samples_per_segment.max(1);
+        self.breakpoints.windows(2).map(|pair| {
+            let (low, high): (f64, f64) = (pair[0].into(), pair[1].into());
+            (0..=steps).map(|step| {
+                let breakpoint = if step == steps { pair[1] } else { T::from_f64(low + (high - low) * step as f64 / steps as f64) };
+                let value: f64 = self.lookup(&breakpoint, Extrapolation::NoneHoldExtreme, Interpolation::Linear).expect("holding the extreme values never fails").into();
+                (value - f(breakpoint).into()).abs()
+            }).fold(0.0, f64::max)
+        }).collect()
+    }
+
+    /// Returns the largest absolute difference between the results of this and another lookup table, for example to quantify how much a retuned map changed.\
+    /// Both tables are sampled at evenly spaced breakpoints across the range that both tables cover, including its ends.
+    /// For integer breakpoint types the samples are truncated to whole breakpoints.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The lookup table to compare with
+    /// * `samples` - The number of breakpoints to compare at, at least 2 are used
+    /// * `interpolation` - The interpolation method to use for both tables
+    /// 
+    /// Returns NaN if the ranges of the breakpoints don't overlap.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{OneDLookup, Interpolation};
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,4> = create_1d_lookup!((0,500,4500,5000), (0.0,0.0,500.0,500.0));
+    /// const RETUNED: OneDLookup<i16,f32,4> = create_1d_lookup!((0,500,4500,5000), (0.0,0.0,520.0,500.0));
+    /// assert_eq!(LOOKUP_TABLE.max_abs_diff(&RETUNED, 11, Interpolation::Linear), 20.0);
+    /// # }
+    /// ```
+    pub fn max_abs_diff(&self, other: &Self, samples: usize, interpolation: Interpolation) -> f64
+    where T: Into<f64> + FromF64, U: Into<f64> + PartialOrd{
+        let low = if self.breakpoints[0] > other.breakpoints[0] { self.breakpoints[0] } else { other.breakpoints[0] }.into();
+        let high = if self.breakpoints[C-1] < other.breakpoints[C-1] { self.breakpoints[C-1] } else { other.breakpoints[C-1] }.into();
+        if low > high {
+            return f64::NAN;
+        }
+        let samples = samples.max(2);
+        (0..samples).map(|sample| {
+            let breakpoint = T::from_f64(low + (high - low) * sample as f64 / (samples - 1) as f64);
+            let own: f64 = self.lookup(&breakpoint, Extrapolation::NoneHoldExtreme, interpolation).unwrap().into();
+            let others: f64 = other.lookup(&breakpoint, Extrapolation::NoneHoldExtreme, interpolation).unwrap().into();
+            (own - others).abs()
+        }).fold(0.0, f64::max)
+    }
+
+    /// Returns the centroid of the breakpoints weighted by their values, `sum(breakpoint * value) / sum(value)`,
+    /// for center-of-mass style metrics over a tabulated distribution.\
+    /// Only the knots are weighted, the interpolation between them is not integrated.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns `ZeroWeight` if the values sum to zero.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::OneDLookup;
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,4> = create_1d_lookup!((0,500,4500,5000), (0.0,0.0,500.0,500.0));
+    /// assert_eq!(LOOKUP_TABLE.weighted_centroid(), Ok(4750.0));
+    /// # }
+    /// ```
+    pub fn weighted_centroid(&self) -> Result<f64, ZeroWeight>
+    where T: Into<f64>, U: Into<f64>{
+        let (moment, total) = self.breakpoints.iter().zip(self.values.iter()).fold((0.0, 0.0), |(moment, total), (bp, value)| {
+            let (bp, value): (f64, f64) = ((*bp).into(), (*value).into());
+            (moment + bp * value, total + value)
+        });
+        if total == 0.0 {
+            return Err(ZeroWeight);
+        }
+        Ok(moment / total)
+    }
+
+    /// Returns the indexes of the interior knots that lie on the line between their neighbours within `eps`,
+    /// to help prune oversized lookup tables.\
+    /// Removing one of these knots on its own changes the linearly interpolated result by less than `eps` everywhere.
+    /// The first and last knots are never listed, and each knot is checked against its original neighbours,
+    /// so removing several adjacent listed knots at once can change the result by more.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `eps` - The largest change in the result that is allowed, exclusive
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::OneDLookup;
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,5> = create_1d_lookup!((0,500,2500,4500,5000), (0.0,0.0,250.0,500.0,500.0));
+    /// assert_eq!(LOOKUP_TABLE.redundant_knots(0.01), vec![2]);
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn redundant_knots(&self, eps: f64) -> Vec<usize>
+    where T: Into<f64>, U: Into<f64>{
+        (1..C.saturating_sub(1)).filter(|&index| {
+            let (low, mid, high): (f64, f64, f64) = (self.breakpoints[index-1].into(), self.breakpoints[index].into(), self.breakpoints[index+1].into());
+            let (low_value, mid_value, high_value): (f64, f64, f64) = (self.values[index-1].into(), self.values[index].into(), self.values[index+1].into());
+            let line = if high == low { low_value } else { low_value + (high_value - low_value) * (mid - low) / (high - low) };
+            (mid_value - line).abs() < eps
+        }).collect()
+    }
+
+    /// Returns the linear interpolation of every segment as `(lower breakpoint, slope, intercept)`,
+    /// so that a breakpoint `x` in the segment maps to `slope * (x - lower breakpoint) + intercept`.\
+    /// This allows evaluating the lookup table without this crate, for example from generated C code.
+    /// For integer value types the slope is truncated, so the coefficients only reproduce `lookup` exactly for float value types,
+    /// and unsigned value types can't represent the negative slope of a falling segment.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::OneDLookup;
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,4> = create_1d_lookup!((0,500,4500,5000), (0.0,0.0,500.0,500.0));
+    /// assert_eq!(LOOKUP_TABLE.segment_coefficients()[1], (500, 0.125, 0.0));
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn segment_coefficients(&self) -> Vec<(T, U, U)> {
+        self.breakpoints.windows(2).zip(self.values.windows(2)).map(|(breakpoints, values)| {
+            (breakpoints[0], (values[1] - values[0]) / U::from(breakpoints[1] - breakpoints[0]), values[0])
+        }).collect()
+    }
+
+    /// Returns an iterator over the segments between adjacent breakpoints with their values and slope,
+    /// for example to build a custom interpolator or to draw the lookup table.\
+    /// Like `segment_coefficients`, the slope is truncated for integer value types.
+    /// The slope is calculated with checked arithmetic, so a slope that `U` can't represent is `None` instead of wrapping or dividing by zero.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{OneDLookup, Segment};
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,4> = create_1d_lookup!((0,500,4500,5000), (0.0,0.0,500.0,500.0));
+    /// let rising = LOOKUP_TABLE.segments().nth(1).unwrap();
+    /// assert_eq!(rising, Segment { lower: 500, upper: 4500, lower_value: 0.0, upper_value: 500.0, slope: Some(0.125) });
+    /// # }
+    /// ```
+    pub fn segments(&self) -> impl Iterator<Item = Segment<T, U>> + '_
+    where U: CheckedArithmetic{
+        self.breakpoints.windows(2).zip(self.values.windows(2)).map(|(breakpoints, values)| Segment {
+            lower: breakpoints[0],
+            upper: breakpoints[1],
+            lower_value: values[0],
+            upper_value: values[1],
+            slope: values[1].checked_sub(values[0]).and_then(|rise| rise.checked_div(U::from(breakpoints[1] - breakpoints[0]))),
+        })
+    }
+
+    /// Returns true if a linear lookup at each breakpoint of the lookup table returns exactly the stored value.\
+    /// This always holds for a table with unique breakpoints, so it's a cheap self-test for a corrupted table.
+    /// For a repeated breakpoint the lookup returns the value of the first one, so tables with repeated breakpoints only pass if their values are repeated as well.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::OneDLookup;
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,4> = create_1d_lookup!((0,500,4500,5000), (0.0,0.0,500.0,500.0));
+    /// assert!(LOOKUP_TABLE.self_consistency_check());
+    /// # }
+    /// ```
+    pub fn self_consistency_check(&self) -> bool
+    where U: PartialOrd{
+        self.breakpoints.iter().zip(self.values.iter()).all(|(breakpoint, value)| {
+            matches!(self.lookup(breakpoint, Extrapolation::NoneError, Interpolation::Linear), Ok(result) if result == *value)
+        })
+    }
+
+    /// Returns true if the lookup table passes `self_consistency_check`, its breakpoints are ascending
+    /// and the cached breakpoint deltas match the breakpoints.
+    /// The cached value deltas are only compared for rising edge segments, a falling edge of an unsigned value type wraps around.\
+    /// This catches tables built with `new` from inconsistent data, which the lookups on the breakpoints themselves can't detect.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use::go_lookup_tables::OneDLookup;
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,3> = OneDLookup::new([0,500,1000], [0.0,5.0,20.0], 500, 10.0, 500, 5.0);
+    /// assert!(LOOKUP_TABLE.self_consistency_check());
+    /// assert!(!LOOKUP_TABLE.self_consistency_check_strict());
+    /// ```
+    pub fn self_consistency_check_strict(&self) -> bool
+    where U: PartialOrd{
+        let rising_edge_matches = |low: U, high: U, cached: U| high < low || high - low == cached;
+        self.self_consistency_check()
+            && check_ascending(&self.breakpoints).is_ok()
+            && self.breakpoints[C-1] - self.breakpoints[C-2] == self.last_diff_bp
+            && self.breakpoints[1] - self.breakpoints[0] == self.first_diff_bp
+            && rising_edge_matches(self.values[C-2], self.values[C-1], self.last_diff_values)
+            && rising_edge_matches(self.values[0], self.values[1], self.first_diff_values)
+    }
+
+    /// Returns a smaller table covering only `[low, high]`, for example to zoom into a calibration window.\
+    /// The knots strictly inside the range are copied and knots are added at exactly `low` and `high`,
+    /// their values are looked up with `interpolation`. A range that reaches past the table holds the extreme values there.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `low` - The first breakpoint of the sub-table
+    /// * `high` - The last breakpoint of the sub-table
+    /// * `interpolation` - The interpolation method used for the values at `low` and `high`
+    /// 
+    /// # Panics
+    /// 
+    /// `subrange` panics if `low` isn't below `high`, a range of a single breakpoint would give two knots at the same breakpoint.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{OneDLookup, Interpolation};
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,4> = create_1d_lookup!((0,500,4500,5000), (0.0,0.0,500.0,500.0));
+    /// let window = LOOKUP_TABLE.subrange(250, 4750, Interpolation::Linear);
+    /// assert_eq!(window.breakpoints(), &[250, 500, 4500, 4750]);
+    /// assert_eq!(window.values(), &[0.0, 0.0, 500.0, 500.0]);
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn subrange(&self, low: T, high: T, interpolation: Interpolation) -> DynamicOneDLookup<T,U>
+    {
+        assert!(low < high, "the low end of a subrange must be below the high end");
+        let value_at = |breakpoint: T| {
+            let position = self.breakpoints.iter().position(|bp| bp >= &breakpoint);
+            self.evaluate(breakpoint, position, Extrapolation::NoneHoldExtreme, interpolation).unwrap()
+        };
+        let mut breakpoints = vec![low];
+        let mut values = vec![value_at(low)];
+        for (bp, value) in self.breakpoints.iter().zip(self.values.iter()) {
+            if *bp > low && *bp < high {
+                breakpoints.push(*bp);
+                values.push(*value);
+            }
+        }
+        breakpoints.push(high);
+        values.push(value_at(high));
+        DynamicOneDLookup { breakpoints, values }
+    }
+
+    /// Returns two tables that split this one at `breakpoint`, the lower one covers `[first breakpoint, breakpoint]` and the upper one `[breakpoint, last breakpoint]`.\
+    /// Both tables get a knot at exactly `breakpoint` whose value is looked up with `interpolation`, see `subrange`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `breakpoint` - The breakpoint at which the table is split
+    /// * `interpolation` - The interpolation method used for the value at `breakpoint`
+    /// 
+    /// # Panics
+    /// 
+    /// `split_at` panics if `breakpoint` isn't strictly between the first and the last breakpoint,
+    /// splitting at either end would leave a half of a single breakpoint.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{OneDLookup, Interpolation};
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,4> = create_1d_lookup!((0,500,4500,5000), (0.0,0.0,500.0,500.0));
+    /// let (lower, upper) = LOOKUP_TABLE.split_at(2500, Interpolation::Linear);
+    /// assert_eq!(lower.breakpoints(), &[0, 500, 2500]);
+    /// assert_eq!(upper.values(), &[250.0, 500.0, 500.0]);
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn split_at(&self, breakpoint: T, interpolation: Interpolation) -> (DynamicOneDLookup<T,U>, DynamicOneDLookup<T,U>)
+    {
+        assert!(breakpoint > self.breakpoints[0] && breakpoint < self.breakpoints[C-1], "a lookup table can only be split strictly between its first and last breakpoint");
+        (self.subrange(self.breakpoints[0], breakpoint, interpolation), self.subrange(breakpoint, self.breakpoints[C-1], interpolation))
+    }
+
+}
+
+impl<
+T: PartialOrd + Add + Copy + Clone + Sub<Output = T> + Div<Output = T> + AsF64,
+const C: usize
+>
+OneDLookup<T,f32,C>
+where f32: From<T>{
+    /// Returns a (interpolated) value from the lookup table that matches the entered breakpoint, like `lookup`,
+    /// but linear interpolation and extrapolation use a fused multiply-add in a fixed order so the result is bit-identical on every target.\
+    /// The fraction of the segment is calculated first and then blended as `fraction * diff_values + base` with a single rounding,
+    /// so the result can differ in the last bit from `lookup`, which multiplies before dividing.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `breakpoint` - A reference to the breakpoint for which a value must be found by the lookup table
+    /// * `extrapolation` - The extrapolation method to use for this lookup operation
+    /// * `interpolation` - The interpolation method to use for this lookup operation
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{OneDLookup, Interpolation, Extrapolation};
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,4> = create_1d_lookup!((0,500,4500,5000), (0.0,0.0,500.0,500.0));
+    /// let pressure = LOOKUP_TABLE.lookup_fma(&2000i16, Extrapolation::NoneHoldExtreme, Interpolation::Linear).unwrap();
+    /// assert_eq!(pressure, 187.5f32);
+    /// # }
+    /// ```
+    pub fn lookup_fma<Y: Copy>(&self, breakpoint: &Y, extrapolation: Extrapolation<f32>, interpolation: Interpolation) -> Result<f32, ExtrapolationError>
+    where T: From<Y>{
+        let calc_breakpoint = T::from(*breakpoint);
+        let position = self.breakpoints.iter().position(|bp| bp >= &calc_breakpoint);
+        match (position, extrapolation, interpolation) {
+            (Some(index), _, Interpolation::Linear) if index != 0 && self.breakpoints[index] != calc_breakpoint => {
+                let fraction = f32::from(calc_breakpoint - self.breakpoints[index-1]) / f32::from(self.breakpoints[index] - self.breakpoints[index-1]);
+                Ok(libm::fmaf(fraction, self.values[index] - self.values[index-1], self.values[index-1]))
+            },
+            //equal edge breakpoints have no slope, like lookup the extreme value is held instead of dividing by their zero delta
+            (Some(0), Extrapolation::Linear, _) if self.breakpoints[0] != calc_breakpoint && is_zero(self.first_diff_bp) => Ok(self.values[0]),
+            (Some(0), Extrapolation::Linear, _) if self.breakpoints[0] != calc_breakpoint => {
+                let fraction = f32::from(self.breakpoints[1] - calc_breakpoint) / f32::from(self.first_diff_bp);
+                Ok(libm::fmaf(-fraction, self.values[1] - self.values[0], self.values[1]))
+            },
+            (Some(0), Extrapolation::LinearSlope { low, .. }, _) if self.breakpoints[0] != calc_breakpoint => {
+                Ok(libm::fmaf(-f32::from(self.breakpoints[0] - calc_breakpoint), low, self.values[0]))
+            },
+            (None, Extrapolation::Linear, _) if is_zero(self.last_diff_bp) => Ok(self.values[C-1]),
+            (None, Extrapolation::Linear, _) => {
+                let fraction = f32::from(calc_breakpoint - self.breakpoints[C-2]) / f32::from(self.last_diff_bp);
+                Ok(libm::fmaf(fraction, self.values[C-1] - self.values[C-2], self.values[C-2]))
+            },
+            (None, Extrapolation::LinearSlope { high, .. }, _) => {
+                Ok(libm::fmaf(f32::from(calc_breakpoint - self.breakpoints[C-1]), high, self.values[C-1]))
+            },
+            //knots, holding, NoneError and the rounding methods return a stored value without arithmetic,
+            //the smoothing, cubic and angular methods ease or wrap in f64 and aren't blended with a fused multiply-add
+            _ => self.lookup_nonlinear(breakpoint, extrapolation, interpolation),
+        }
+    }
+}
+
+impl<
+T: PartialOrd + Sub + Add + Div + Copy + Clone,
+U: Add + Sub + Div + Mul + Copy + Clone,
+const C: usize
+>
+OneDLookup<T,U,C>{
+    /// Returns the breakpoint-value pairs of the lookup table as `f64` points, ready to be handed to a plotting library.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::OneDLookup;
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,4> = create_1d_lookup!((0,500,4500,5000), (0.0,0.0,500.0,500.0));
+    /// assert_eq!(LOOKUP_TABLE.to_points()[2], (4500.0, 500.0));
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn to_points(&self) -> Vec<(f64, f64)>
+    where T: Into<f64>, U: Into<f64>{
+        self.breakpoints.iter().zip(self.values.iter()).map(|(bp, value)| ((*bp).into(), (*value).into())).collect()
+    }
+
+    /// Returns the lookup table with its breakpoints linearly remapped to `[0,1]` and the same values,
+    /// for example to blend lookup tables with different breakpoint ranges on a common axis.\
+    /// The first breakpoint maps to exactly 0.0 and the last to exactly 1.0, a lookup table whose breakpoints are all equal maps them all to 0.0.
+    /// The distances to the first breakpoint are calculated in the breakpoint type, so wide breakpoint types keep their precision.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{OneDLookup, Interpolation, Extrapolation};
+    /// const LOOKUP_TABLE: OneDLookup<i16,f64,4> = create_1d_lookup!((1000,1500,5500,6000), (0.0,0.0,500.0,500.0));
+    /// let unit_table = LOOKUP_TABLE.normalized_axis();
+    /// assert_eq!(unit_table.lookup(&0.5f64, Extrapolation::NoneError, Interpolation::Linear).unwrap(), 250.0);
+    /// # }
+    /// ```
+    pub fn normalized_axis(&self) -> OneDLookup<f64,U,C>
+    where T: Sub<Output = T> + AsF64, U: EdgeDelta{
+        let knots = OffsetKnots { breakpoints: &self.breakpoints, values: &self.values };
+        let range = knots.offset(self.breakpoints[C-1]);
+        let breakpoints: [f64;C] = core::array::from_fn(|i| if range > 0.0 { knots.offset(self.breakpoints[i]) / range } else { 0.0 });
+        OneDLookup::with_cached_diffs(breakpoints, self.values)
+    }
+
+    /// Converts the values of the lookup table into another type, the cached value deltas are recalculated in the new type.
+    /// 
+    /// # Panics
+    /// 
+    /// `cast_values` fails to compile if the lookup table has less than 2 breakpoints.
+    /// 
+    /// ```compile_fail
+    /// use::go_lookup_tables::OneDLookup;
+    /// let lookup_table: OneDLookup<i16,i32,1> = OneDLookup::new([0], [1], 0, 0, 0, 0);
+    /// let float_table: OneDLookup<i16,f64,1> = lookup_table.cast_values();
+    /// ```
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{OneDLookup, Interpolation, Extrapolation};
+    /// const LOOKUP_TABLE: OneDLookup<i16,i32,2> = create_1d_lookup!((0,100), (0,5));
+    /// let float_table: OneDLookup<i16,f64,2> = LOOKUP_TABLE.cast_values();
+    /// assert_eq!(float_table.lookup(&50i16, Extrapolation::NoneError, Interpolation::Linear).unwrap(), 2.5f64);
+    /// # }
+    /// ```
+    pub fn cast_values<V>(self) -> OneDLookup<T,V,C>
+    where T: Sub<Output = T>, V: Add + EdgeDelta + Div + Mul + Copy + Clone + From<U>{
+        OneDLookup::with_cached_diffs(self.breakpoints, self.values.map(V::from))
+    }
+
+    /// Returns a (interpolated) value from the lookup table that matches the entered breakpoint, calculated in `f64`.\
+    /// This allows breakpoint types that the value type can't be created from, like `i64` timestamps with `f32` values.
+    /// The breakpoint differences are calculated in the breakpoint type and only then converted to `f64`,
+    /// so the interpolation keeps its precision even when the breakpoints themselves don't fit in an `f64` exactly.
+    /// The result is converted to the value type like an `as` cast, so integer value types are rounded towards zero.
+    /// `create_1d_lookup!` requires `U: From<T>`, so build such a lookup table with `try_new` instead.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `breakpoint` - A reference to the breakpoint for which a value must be found by the lookup table
+    /// * `extrapolation` - The extrapolation method to use for this lookup operation
+    /// * `interpolation` - The interpolation method to use for this lookup operation
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use::go_lookup_tables::{OneDLookup, Interpolation, Extrapolation};
+    /// let lookup_table: OneDLookup<i64,f32,2> = OneDLookup::try_new([1_700_000_000_000_000_000, 1_700_000_000_000_001_000], [0.0, 1.0]).unwrap();
+    /// let value = lookup_table.lookup_via_f64(&1_700_000_000_000_000_250i64, Extrapolation::NoneError, Interpolation::Linear).unwrap();
+    /// assert_eq!(value, 0.25);
+    /// ```
+    pub fn lookup_via_f64<Y: Copy>(&self, breakpoint: &Y, extrapolation: Extrapolation<U>, interpolation: Interpolation) -> Result<U, ExtrapolationError>
+    where T: From<Y> + Sub<Output = T> + AsF64, U: AsF64 + FromF64{
+        let calc_breakpoint = T::from(*breakpoint);
+        let position = self.breakpoints.iter().position(|bp| bp >= &calc_breakpoint);
+        let knots = OffsetKnots { breakpoints: &self.breakpoints, values: &self.values };
+        let calc_offset = knots.offset(calc_breakpoint);
+        KnotsView {
+            last_diff_bp: knots.breakpoint(C-1) - knots.breakpoint(C-2),
+            first_diff_bp: knots.breakpoint(1) - knots.breakpoint(0),
+            knots,
+        }.evaluate(calc_offset, position, extrapolation.as_f64(), interpolation).map(U::from_f64)
+    }
+
+    /// Returns a (interpolated) value from the lookup table that matches the entered `f64` breakpoint, calculated and returned in `f64`.\
+    /// `lookup` converts the entered breakpoint to the breakpoint type first, so for integer breakpoints the fractional part of the breakpoint is lost,
+    /// here the breakpoint keeps its fraction, a breakpoint of `2.7` interpolates at `2.7` instead of at `2`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `breakpoint` - The breakpoint for which a value must be found by the lookup table
+    /// * `extrapolation` - The extrapolation method to use for this lookup operation
+    /// * `interpolation` - The interpolation method to use for this lookup operation
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{OneDLookup, Interpolation, Extrapolation};
+    /// const LOOKUP_TABLE: OneDLookup<i16,i32,2> = create_1d_lookup!((0,10), (0,100));
+    /// let value = LOOKUP_TABLE.lookup_high_res(2.7, Extrapolation::NoneError, Interpolation::Linear).unwrap();
+    /// assert_eq!(value, 27.0);
+    /// # }
+    /// ```
+    pub fn lookup_high_res(&self, breakpoint: f64, extrapolation: Extrapolation<U>, interpolation: Interpolation) -> Result<f64, ExtrapolationError>
+    where T: Sub<Output = T> + AsF64, U: AsF64{
+        let knots = OffsetKnots { breakpoints: &self.breakpoints, values: &self.values };
+        let calc_offset = breakpoint - self.breakpoints[0].as_f64();
+        let position = (0..C).find(|index| knots.breakpoint(*index) >= calc_offset);
+        KnotsView {
+            last_diff_bp: knots.breakpoint(C-1) - knots.breakpoint(C-2),
+            first_diff_bp: knots.breakpoint(1) - knots.breakpoint(0),
+            knots,
+        }.evaluate(calc_offset, position, extrapolation.as_f64(), interpolation)
+    }
+
+    /// Returns a linearly interpolated value from the lookup table as a Q16.16 fixed-point number, for integer lookup tables on targets without an FPU.\
+    /// The interpolation is calculated in integers and rounded down, so the fractional bits are those of the exact interpolation.
+    /// Results outside the Q16.16 range saturate to `i32::MIN` or `i32::MAX`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `breakpoint` - A reference to the breakpoint for which a value must be found by the lookup table
+    /// 
+    /// # Errors
+    /// 
+    /// Returns an `ExtrapolationError` if the breakpoint is out of range.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::OneDLookup;
+    /// const LOOKUP_TABLE: OneDLookup<i16,i16,2> = create_1d_lookup!((0,4), (0,1));
+    /// assert_eq!(LOOKUP_TABLE.lookup_q16(&1i16).unwrap(), 0x4000); //0.25
+    /// # }
+    /// ```
+    pub fn lookup_q16<Y: Copy>(&self, breakpoint: &Y) -> Result<i32, ExtrapolationError>
+    where T: From<Y> + Into<i64>, U: Into<i64>{
+        let calc_breakpoint = T::from(*breakpoint);
+        let q16 = match self.breakpoints.iter().position(|bp| bp >= &calc_breakpoint) {
+            //shifting a value beyond 2^47 would overflow the i64, multiplying saturates instead
+            Some(index) if self.breakpoints[index] == calc_breakpoint => self.values[index].into().saturating_mul(1 << 16),
+            Some(index) if index != 0 => {
+                let breakpoint_low: i64 = self.breakpoints[index-1].into();
+                let offset = calc_breakpoint.into() - breakpoint_low;
+                let span = self.breakpoints[index].into() - breakpoint_low;
+                let value_low: i64 = self.values[index-1].into();
+                let diff_values = self.values[index].into() - value_low;
+                //the product of two 32 bit deltas shifted by 16 doesn't fit in an i64
+                let fraction = ((i128::from(diff_values) * i128::from(offset)) << 16).div_euclid(i128::from(span));
+                value_low.saturating_mul(1 << 16).saturating_add(fraction.clamp(i128::from(i64::MIN), i128::from(i64::MAX)) as i64)
+            },
+            _ => return Err(ExtrapolationError),
+        };
+        Ok(q16.clamp(i64::from(i32::MIN), i64::from(i32::MAX)) as i32)
+    }
+
+    /// Returns the smallest query delta that can move the interpolated output by one step of the value type, over all segments whose values differ.\
+    /// In a segment the query has to move `width / |value delta|` for integer value types, float value types are only limited by the breakpoint type.
+    /// For integer breakpoints the step is rounded up to whole units, a segment of width 1 has no query inside it at all
+    /// and its midpoint used by `NoneClosest` can't be represented. Repeated breakpoints and flat segments are skipped,
+    /// a table without any rising or falling segment returns its whole range.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::OneDLookup;
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,4> = create_1d_lookup!((0,500,4500,5000), (0.0,0.0,500.0,500.0));
+    /// assert_eq!(LOOKUP_TABLE.min_resolvable_query_step(), 1);
+    /// const COARSE_TABLE: OneDLookup<i16,i16,4> = create_1d_lookup!((0,500,4500,5000), (0,0,50,50));
+    /// assert_eq!(COARSE_TABLE.min_resolvable_query_step(), 80); //4000 breakpoint units for 50 value steps
+    /// # }
+    /// ```
+    pub fn min_resolvable_query_step(&self) -> T
+    where T: Sub<Output = T> + AsF64 + FromF64, U: AsF64 + FromF64{
+        //integer types truncate a half to zero, their steps are whole units
+        let quantum = |half: f64| if half == 0.0 { 1.0 } else { 0.0 };
+        let breakpoint_quantum = quantum(T::from_f64(0.5).as_f64());
+        let value_quantum = quantum(U::from_f64(0.5).as_f64());
+        self.breakpoints.windows(2).zip(self.values.windows(2))
+            .filter(|(pair, values)| pair[1] > pair[0] && values[1].as_f64() != values[0].as_f64())
+            .map(|(pair, values)| {
+                let width = (pair[1] - pair[0]).as_f64();
+                let rise = (values[1].as_f64() - values[0].as_f64()).abs();
+                (width * value_quantum / rise).max(breakpoint_quantum)
+            })
+            .reduce(f64::min)
+            .map(|step| T::from_f64(if breakpoint_quantum > 0.0 { libm::ceil(step) } else { step }))
+            .unwrap_or(self.breakpoints[C-1] - self.breakpoints[0])
+    }
+
+    /// Returns the index and breakpoint of the knot whose value is closest to the entered value, the first one if several are equally close.\
+    /// Unlike `invert` the values don't have to be monotone, only the stored values are compared so nothing is interpolated.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::OneDLookup;
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,4> = create_1d_lookup!((0,500,4500,5000), (0.0,0.0,500.0,500.0));
+    /// assert_eq!(LOOKUP_TABLE.breakpoint_of_nearest_value(&300.0), (2, 4500));
+    /// # }
+    /// ```
+    pub fn breakpoint_of_nearest_value(&self, value: &U) -> (usize, T)
+    where U: PartialOrd + Sub<Output = U>{
+        //the distance is calculated the positive way around, so unsigned value types don't wrap
+        let distance = |stored: U| if stored > *value { stored - *value } else { *value - stored };
+        let mut nearest = 0;
+        for index in 1..C {
+            if distance(self.values[index]) < distance(self.values[nearest]) {
+                nearest = index;
+            }
+        }
+        (nearest, self.breakpoints[nearest])
+    }
+
+    /// Returns the breakpoint and value of the knot with the largest value, the first one if several are equally large.\
+    /// With linear, smoothstep or no interpolation the curve never leaves the range of the two knots around it, so this is also the maximum of the curve.
+    /// `Interpolation::Cubic` can overshoot the knots between them, those interior extrema are not searched.
+    /// NaN values are never chosen unless the first value is NaN.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::OneDLookup;
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,4> = create_1d_lookup!((0,500,4500,5000), (0.0,0.0,500.0,500.0));
+    /// assert_eq!(LOOKUP_TABLE.argmax(), (4500, 500.0));
+    /// # }
+    /// ```
+    pub fn argmax(&self) -> (T, U)
+    where U: PartialOrd{
+        let mut max = 0;
+        for index in 1..C {
+            if self.values[index] > self.values[max] {
+                max = index;
+            }
+        }
+        (self.breakpoints[max], self.values[max])
+    }
+
+    /// Returns the breakpoint and value of the knot with the smallest value, the first one if several are equally small.\
+    /// Like `argmax` only the knots are compared, the interior extrema of `Interpolation::Cubic` are not searched.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::OneDLookup;
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,4> = create_1d_lookup!((0,500,4500,5000), (0.0,0.0,500.0,500.0));
+    /// assert_eq!(LOOKUP_TABLE.argmin(), (0, 0.0));
+    /// # }
+    /// ```
+    pub fn argmin(&self) -> (T, U)
+    where U: PartialOrd{
+        let mut min = 0;
+        for index in 1..C {
+            if self.values[index] < self.values[min] {
+                min = index;
+            }
+        }
+        (self.breakpoints[min], self.values[min])
+    }
+
+    /// Returns true if the entered breakpoint lies within `[first breakpoint, last breakpoint]`, so it can be looked up without extrapolating.\
+    /// NaN breakpoints are never contained.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::OneDLookup;
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,4> = create_1d_lookup!((0,500,4500,5000), (0.0,0.0,500.0,500.0));
+    /// assert!(LOOKUP_TABLE.contains(&5000i16));
+    /// assert!(!LOOKUP_TABLE.contains(&5001i16));
+    /// # }
+    /// ```
+    pub fn contains<Y: Copy>(&self, breakpoint: &Y) -> bool
+    where T: From<Y>{
+        let calc_breakpoint = T::from(*breakpoint);
+        calc_breakpoint >= self.breakpoints[0] && calc_breakpoint <= self.breakpoints[C-1]
+    }
+
+    /// Returns true if the entered breakpoint is exactly one of the breakpoints of the lookup table,
+    /// in which case `lookup` returns the stored value without interpolating, whatever the interpolation method.\
+    /// Float breakpoints are compared with `==`, so `-0.0` matches a `0.0` breakpoint and NaN never matches.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::OneDLookup;
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,4> = create_1d_lookup!((0,500,4500,5000), (0.0,0.0,500.0,500.0));
+    /// assert!(LOOKUP_TABLE.is_exact_knot(&500i16));
+    /// assert!(!LOOKUP_TABLE.is_exact_knot(&501i16));
+    /// # }
+    /// ```
+    pub fn is_exact_knot<Y: Copy>(&self, breakpoint: &Y) -> bool
+    where T: From<Y>{
+        let calc_breakpoint = T::from(*breakpoint);
+        self.breakpoints.contains(&calc_breakpoint)
+    }
+
+    /// Returns the indexes of the two breakpoints the entered breakpoint falls between and their linear blend weights, which sum to 1.0.\
+    /// This lets other data that is indexed by the same breakpoints be blended without storing it in the lookup table.
+    /// A breakpoint that matches a breakpoint of the lookup table gets a weight of 1.0 for that index, listed twice.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns an `ExtrapolationError` if the breakpoint is out of range.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::OneDLookup;
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,4> = create_1d_lookup!((0,500,4500,5000), (0.0,0.0,500.0,500.0));
+    /// let calibration_offsets = [0.0, 1.0, 3.0, 0.0];
+    /// let [(index_a, weight_a), (index_b, weight_b)] = LOOKUP_TABLE.weights(&1500i16).unwrap();
+    /// assert_eq!(calibration_offsets[index_a] * weight_a + calibration_offsets[index_b] * weight_b, 1.5);
+    /// # }
+    /// ```
+    pub fn weights<Y: Copy>(&self, breakpoint: &Y) -> Result<[(usize, f64); 2], ExtrapolationError>
+    where T: From<Y> + Into<f64>{
+        let calc_breakpoint = T::from(*breakpoint);
+        match self.breakpoints.iter().position(|bp| bp >= &calc_breakpoint) {
+            Some(index) if self.breakpoints[index] == calc_breakpoint => Ok([(index, 1.0), (index, 0.0)]),
+            Some(index) if index != 0 => {
+                let breakpoint_low: f64 = self.breakpoints[index-1].into();
+                let breakpoint_high: f64 = self.breakpoints[index].into();
+                let fraction = (calc_breakpoint.into() - breakpoint_low) / (breakpoint_high - breakpoint_low);
+                Ok([(index-1, 1.0 - fraction), (index, fraction)])
+            },
+            _ => Err(ExtrapolationError),
+        }
+    }
+
+    /// Returns the smallest and the largest value of the lookup table.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `nan_policy` - How NaN values are treated, with `NanPolicy::Ignore` a table of only NaN values still returns NaN
+    /// 
+    /// # Errors
+    /// 
+    /// Returns `NanAt` with the index of the first NaN value if `nan_policy` is `NanPolicy::Error`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{OneDLookup, NanPolicy, NanAt};
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,4> = create_1d_lookup!((0,500,4500,5000), (100.0,f32::NAN,500.0,-20.0));
+    /// assert_eq!(LOOKUP_TABLE.value_range(NanPolicy::Ignore), Ok((-20.0, 500.0)));
+    /// assert_eq!(LOOKUP_TABLE.value_range(NanPolicy::Error), Err(NanAt { index: 1 }));
+    /// # }
+    /// ```
+    pub fn value_range(&self, nan_policy: NanPolicy) -> Result<(U, U), NanAt>
+    where U: PartialOrd{
+        let is_nan = |value: &U| value.partial_cmp(value).is_none();
+        if let Some(index) = self.values.iter().position(is_nan) {
+            match nan_policy {
+                NanPolicy::Propagate => return Ok((self.values[index], self.values[index])),
+                NanPolicy::Error => return Err(NanAt { index }),
+                NanPolicy::Ignore => {},
+            }
+        }
+        let mut numbers = self.values.iter().filter(|value| !is_nan(value));
+        let first = match numbers.next() {
+            Some(value) => *value,
+            None => return Ok((self.values[0], self.values[0])),
+        };
+        Ok(numbers.fold((first, first), |(min, max), value| {
+            (if *value < min { *value } else { min }, if *value > max { *value } else { max })
+        }))
+    }
+
+    /// Checks that the values of the lookup table are monotone in the entered direction, equal neighbours are allowed for a rising or falling table.\
+    /// `Ordering::Less` expects rising values, `Ordering::Greater` falling values and `Ordering::Equal` a constant table.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `direction` - The expected order of each value with the value after it
+    /// * `nan_policy` - How NaN values are treated, with `NanPolicy::Ignore` each value is compared with the closest number before it
+    /// 
+    /// # Errors
+    /// 
+    /// Returns `MonotoneError::NonMonotone` with the index of the first value that breaks the expected order with the value before it
+    /// and `MonotoneError::Nan` with the index of the first NaN value if `nan_policy` is `NanPolicy::Error`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{OneDLookup, NonMonotoneAt, MonotoneError, NanPolicy};
+    /// use std::cmp::Ordering;
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,4> = create_1d_lookup!((0,500,4500,5000), (0.0,0.0,500.0,500.0));
+    /// assert!(LOOKUP_TABLE.assert_monotone(Ordering::Less, NanPolicy::Error).is_ok());
+    /// assert_eq!(LOOKUP_TABLE.assert_monotone(Ordering::Greater, NanPolicy::Error), Err(MonotoneError::NonMonotone(NonMonotoneAt { index: 2 })));
+    /// # }
+    /// ```
+    pub fn assert_monotone(&self, direction: Ordering, nan_policy: NanPolicy) -> Result<(), MonotoneError>
+    where U: PartialOrd{
+        let mut previous: Option<U> = None;
+        for (index, value) in self.values.iter().enumerate() {
+            if value.partial_cmp(value).is_none() {
+                match nan_policy {
+                    NanPolicy::Propagate => {},
+                    NanPolicy::Ignore => continue,
+                    NanPolicy::Error => return Err(MonotoneError::Nan(NanAt { index })),
+                }
+            }
+            if let Some(previous) = previous {
+                let order = previous.partial_cmp(value);
+                if order != Some(direction) && order != Some(Ordering::Equal) {
+                    return Err(MonotoneError::NonMonotone(NonMonotoneAt { index }));
+                }
+            }
+            previous = Some(*value);
+        }
+        Ok(())
+    }
+
+    /// Returns the size of the largest step of the values against the direction of the lookup table, zero for a monotone table.\
+    /// The table is treated as rising if the last value isn't below the first value and as falling otherwise.
+    /// Steps involving a NaN value are skipped.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::OneDLookup;
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,5> = create_1d_lookup!((0,500,1000,1500,2000), (0.0,100.0,98.5,200.0,300.0));
+    /// assert_eq!(LOOKUP_TABLE.monotonicity_defect(), 1.5);
+    /// # }
+    /// ```
+    pub fn monotonicity_defect(&self) -> U
+    where U: Sub<Output = U> + PartialOrd{
+        let rising = self.values[C-1].partial_cmp(&self.values[0]) != Some(Ordering::Less);
+        //the type has no constructor for 0, so start from the difference of a value with itself
+        #[allow(clippy::eq_op)]
+        let zero = self.values[0] - self.values[0];
+        //only subtract for backward steps, so unsigned types never go below 0
+        self.values.windows(2).fold(zero, |defect, pair| {
+            let step = match (rising, pair[1] < pair[0]) {
+                (true, true) => pair[0] - pair[1],
+                (false, false) if pair[1] > pair[0] => pair[1] - pair[0],
+                _ => return defect,
+            };
+            if step > defect { step } else { defect }
+        })
+    }
+
+    /// Returns the slopes of the first and the last segment in value change per breakpoint unit, as `(low, high)`.\
+    /// These are the slopes `Extrapolation::Linear` uses below the first and above the last breakpoint.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::OneDLookup;
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,3> = create_1d_lookup!((0,500,1000), (0.0,100.0,50.0));
+    /// assert_eq!(LOOKUP_TABLE.edge_slopes(), (0.2, -0.1));
+    /// # }
+    /// ```
+    pub fn edge_slopes(&self) -> (f64, f64)
+    where T: Into<f64>, U: Into<f64>{
+        let slope = |low: usize, high: usize| {
+            (self.values[high].into() - self.values[low].into()) / (self.breakpoints[high].into() - self.breakpoints[low].into())
+        };
+        (slope(0, 1), slope(C-2, C-1))
+    }
+
+    /// Returns the value at a fractional index, interpolated linearly between the two neighbouring values, without searching the breakpoints.\
+    /// An index of `2.5` lies halfway between the values at index 2 and 3, the index is clamped to `[0, C-1]`.
+    /// This is useful when the position is already known, for example from an earlier search.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::OneDLookup;
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,4> = create_1d_lookup!((0,500,4500,5000), (0.0,0.0,500.0,500.0));
+    /// assert_eq!(LOOKUP_TABLE.at_fractional_index(1.25), 125.0f32);
+    /// assert_eq!(LOOKUP_TABLE.at_fractional_index(7.0), 500.0f32);
+    /// # }
+    /// ```
+    pub fn at_fractional_index(&self, index: f64) -> U
+    where U: Into<f64> + FromF64{
+        let index = index.max(0.0).min((C-1) as f64);
+        let index_low = libm::floor(index) as usize;
+        let fraction = index - index_low as f64;
+        if fraction == 0.0 {
+            return self.values[index_low];
+        }
+        let value_low: f64 = self.values[index_low].into();
+        let value_high: f64 = self.values[index_low+1].into();
+        U::from_f64((value_high - value_low) * fraction + value_low)
+    }
+
+    /// Returns the indexes of the local maxima and minima in the values, for finding intended peaks like a torque peak or unintended spikes.\
+    /// Only values with a neighbour on both sides count, for a plateau of equal values the index of its first value is reported.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{OneDLookup, Extremum};
+    /// const TORQUE: OneDLookup<i16,f32,5> = create_1d_lookup!((1000,2000,3000,4000,5000), (180.0,240.0,260.0,250.0,210.0));
+    /// assert_eq!(TORQUE.local_extrema(), vec![(2, Extremum::Maximum)]);
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn local_extrema(&self) -> Vec<(usize, Extremum)>
+    where U: PartialOrd{
+        let mut extrema = Vec::new();
+        let mut start = 1;
+        while start + 1 < C {
+            let mut end = start;
+            while end + 1 < C && self.values[end + 1] == self.values[start] {
+                end += 1;
+            }
+            if end + 1 == C {
+                break;
+            }
+            let (before, value, after) = (self.values[start - 1], self.values[start], self.values[end + 1]);
+            if value > before && value > after {
+                extrema.push((start, Extremum::Maximum));
+            } else if value < before && value < after {
+                extrema.push((start, Extremum::Minimum));
+            }
+            start = end + 1;
+        }
+        extrema
+    }
+
+    /// Returns the lookup table wrapped with fixed extrapolation and interpolation methods, to pass it on as an `Interpolator`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{OneDLookup, Interpolator, Interpolation, Extrapolation};
+    /// fn evaluate_below_range<I: Interpolator<i16, f32>>(interpolator: &I) -> f32 {
+    ///     interpolator.evaluate(-100).unwrap()
+    /// }
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,4> = create_1d_lookup!((0,500,4500,5000), (0.0,0.0,500.0,500.0));
+    /// assert_eq!(evaluate_below_range(&LOOKUP_TABLE.interpolator(Extrapolation::NoneHoldExtreme, Interpolation::Linear)), 0.0);
+    /// # }
+    /// ```
+    pub fn interpolator(&self, extrapolation: Extrapolation<U>, interpolation: Interpolation) -> ConfiguredOneDLookup<'_,T,U,C> {
+        ConfiguredOneDLookup { table: self, extrapolation, interpolation }
+    }
+
+    /// Attaches a name and units to the lookup table, for calibration tooling.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{OneDLookup, TableMeta};
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,2> = create_1d_lookup!((500,4500), (0.0,500.0));
+    /// let annotated = LOOKUP_TABLE.with_meta(TableMeta::new("intake pressure", "mV", "kPa"));
+    /// assert_eq!(annotated.to_string(), "intake pressure: 500 mV -> 0 kPa, 4500 mV -> 500 kPa");
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn with_meta(self, meta: TableMeta) -> AnnotatedOneDLookup<T,U,C> {
+        AnnotatedOneDLookup { table: self, meta }
+    }
+
+    /// Returns the `create_1d_lookup!` invocation that recreates this lookup table, for generating Rust source from calibration data.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::OneDLookup;
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,4> = create_1d_lookup!((0,500,4500,5000), (0.0,0.0,500.0,500.0));
+    /// assert_eq!(LOOKUP_TABLE.to_macro_source(), "create_1d_lookup!((0, 500, 4500, 5000), (0.0, 0.0, 500.0, 500.0))");
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn to_macro_source(&self) -> String
+    where T: fmt::Debug, U: fmt::Debug{
+        format!("create_1d_lookup!(({}), ({}))", join_debug(&self.breakpoints), join_debug(&self.values))
+    }
+
+    /// Returns a `pub const` declaration of this lookup table named `name`, with a `create_1d_lookup!` invocation as its value.
+    /// The literals are suffixed with their type, so the macro checks them against the types of the table.\
+    /// A table that the macro rejects, like one with a falling edge of unsigned values, is written as `OneDLookup::new` with its cached deltas instead.
+    /// The generated source uses `OneDLookup` and `create_1d_lookup!` unqualified, so include it where `go_lookup_tables::*` is in scope.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns a `NonFiniteAt` error if a breakpoint or value is NaN or infinite, those have no literal to write.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{OneDLookup, NonFiniteAt};
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,2> = create_1d_lookup!((500,4500), (0.0,500.0));
+    /// assert_eq!(LOOKUP_TABLE.to_const_source("PRESSURE").unwrap(), "pub const PRESSURE: OneDLookup<i16, f32, 2> = create_1d_lookup!((500i16, 4500i16), (0.0f32, 500.0f32));\n");
+    /// let falling_table: OneDLookup<u8,u8,2> = OneDLookup::try_new([0,10], [50,10]).unwrap();
+    /// assert_eq!(falling_table.to_const_source("FALLING").unwrap(), "pub const FALLING: OneDLookup<u8, u8, 2> = OneDLookup::new([0u8, 10u8], [50u8, 10u8], 10u8, 216u8, 10u8, 216u8);\n");
+    /// const OPEN_CIRCUIT: OneDLookup<i16,f32,2> = create_1d_lookup!((500,4500), (0.0,f32::INFINITY));
+    /// assert_eq!(OPEN_CIRCUIT.to_const_source("PRESSURE"), Err(NonFiniteAt { index: 1 }));
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn to_const_source(&self, name: &str) -> Result<String, NonFiniteAt>
+    where T: SourceLiteral, U: SourceLiteral{
+        if let Some(index) = (0..C).find(|&i| !self.breakpoints[i].has_literal() || !self.values[i].has_literal()) {
+            return Err(NonFiniteAt { index });
+        }
+        let (breakpoints, values) = (join_literals(&self.breakpoints), join_literals(&self.values));
+        let table = if (1..C).all(|i| T::const_delta_fits(&self.breakpoints[i-1], &self.breakpoints[i]) && U::const_delta_fits(&self.values[i-1], &self.values[i])) {
+            format!("create_1d_lookup!(({}), ({}))", breakpoints, values)
+        } else {
+            let deltas = format!("{}, {}, {}, {}", join_literals(&[self.last_diff_bp]), join_literals(&[self.last_diff_values]), join_literals(&[self.first_diff_bp]), join_literals(&[self.first_diff_values]));
+            format!("OneDLookup::new([{}], [{}], {})", breakpoints, values, deltas)
+        };
+        Ok(format!("pub const {}: OneDLookup<{}, {}, {}> = {};\n", name, T::TYPE_NAME, U::TYPE_NAME, C, table))
+    }
+
+    /// Returns a lookup table built from breakpoints and values that are only known at runtime, the cached deltas are calculated from the data.\
+    /// This is the checked counterpart of `new`, `try_create_1d_lookup!` expands to it.
+    /// With the `checked_construction` feature the cached deltas are checked with `EdgeDelta::edge_delta_fits`,
+    /// so breakpoints or values too far apart for their type are rejected instead of wrapping. Falling edges of unsigned value types are accepted.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns `LookupBuildError::NotAscending` if the breakpoints aren't in ascending order
+    /// and, with the `checked_construction` feature, `LookupBuildError::DiffOverflow` if a cached delta overflows its type.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use::go_lookup_tables::{OneDLookup, LookupBuildError};
+    /// let result: Result<OneDLookup<i16,f32,3>, _> = OneDLookup::try_new([0,500,250], [0.0,1.0,2.0]);
+    /// assert_eq!(result.err(), Some(LookupBuildError::NotAscending { index: 2 }));
+    /// let falling: Result<OneDLookup<u16,u16,2>, _> = OneDLookup::try_new([0,500], [500,0]);
+    /// assert!(falling.is_ok());
+    /// ```
+    /// 
+    /// ```
+    /// # #[cfg(feature = "checked_construction")] {
+    /// use::go_lookup_tables::{OneDLookup, LookupBuildError};
+    /// let result: Result<OneDLookup<i8,i8,2>, _> = OneDLookup::try_new([-100,100], [0,1]);
+    /// assert_eq!(result.err(), Some(LookupBuildError::DiffOverflow { index: 1 }));
+    /// # }
+    /// ```
+    pub fn try_new(breakpoints: [T;C], values: [U;C]) -> Result<OneDLookup<T,U,C>, LookupBuildError>
+    where T: EdgeDelta, U: EdgeDelta{
+        check_ascending(&breakpoints)?;
+        if cfg!(feature = "checked_construction") {
+            let () = Self::HAS_OUTER_SEGMENTS;
+            for index in [1, C-1] {
+                if !T::edge_delta_fits(breakpoints[index-1], breakpoints[index]) || !U::edge_delta_fits(values[index-1], values[index]) {
+                    return Err(LookupBuildError::DiffOverflow { index });
+                }
+            }
+        }
+        Ok(OneDLookup::with_cached_diffs(breakpoints, values))
+    }
+
+    /// Returns a lookup table built from an iterator of breakpoint-value pairs, the cached deltas are calculated from the data.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns `LookupBuildError::ShapeMismatch` if the iterator doesn't yield exactly `C` pairs
+    /// and `LookupBuildError::NotAscending` if the breakpoints aren't in ascending order.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use::go_lookup_tables::{OneDLookup, LookupBuildError};
+    /// let parsed = vec![(0i16, 0.0f32), (500, 0.0), (4500, 500.0), (5000, 500.0)];
+    /// let lookup_table: OneDLookup<i16,f32,4> = OneDLookup::try_from_iter(parsed).unwrap();
+    /// assert_eq!(OneDLookup::<i16,f32,3>::try_from_iter(vec![(0i16, 0.0f32)]).err(), Some(LookupBuildError::ShapeMismatch { expected: 3, found: 1 }));
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn try_from_iter(iter: impl IntoIterator<Item = (T, U)>) -> Result<OneDLookup<T,U,C>, LookupBuildError>
+    where T: Sub<Output = T>, U: EdgeDelta{
+        let pairs: Vec<(T, U)> = iter.into_iter().collect();
+        if pairs.len() != C {
+            return Err(LookupBuildError::ShapeMismatch { expected: C, found: pairs.len() });
+        }
+        let breakpoints: [T;C] = core::array::from_fn(|i| pairs[i].0);
+        let values: [U;C] = core::array::from_fn(|i| pairs[i].1);
+        check_ascending(&breakpoints)?;
+        Ok(OneDLookup::with_cached_diffs(breakpoints, values))
+    }
+
+    /// Returns a lookup table built from breakpoint-value pairs in any order, the pairs are sorted by breakpoint first.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns `LookupBuildError::DuplicateBreakpoint` if a breakpoint occurs more than once,
+    /// `LookupBuildError::NotAscending` if the breakpoints can't be ordered, which happens for NaN,
+    /// and `LookupBuildError::ShapeMismatch` if there aren't exactly `C` pairs.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use::go_lookup_tables::{OneDLookup, Interpolation, Extrapolation};
+    /// let pairs = vec![(4500i16, 500.0f32), (0, 0.0), (5000, 500.0), (500, 0.0)];
+    /// let lookup_table: OneDLookup<i16,f32,4> = OneDLookup::from_unsorted(pairs).unwrap();
+    /// assert_eq!(lookup_table.lookup(&2500i16, Extrapolation::NoneError, Interpolation::Linear).unwrap(), 250.0);
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn from_unsorted(mut pairs: Vec<(T, U)>) -> Result<OneDLookup<T,U,C>, LookupBuildError>
+    where T: Sub<Output = T>, U: EdgeDelta{
+        if let Some(index) = pairs.iter().position(|pair| pair.0.partial_cmp(&pair.0).is_none()) {
+            return Err(LookupBuildError::NotAscending { index });
+        }
+        pairs.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
+        if let Some(index) = pairs.windows(2).position(|pair| pair[0].0 == pair[1].0) {
+            return Err(LookupBuildError::DuplicateBreakpoint { index: index + 1 });
+        }
+        OneDLookup::try_from_iter(pairs)
+    }
+
+    /// Returns a lookup table with the breakpoints and values of `other` appended after those of this lookup table,
+    /// for composing calibration regions that were defined separately.\
+    /// The length of the result is taken from its type, since adding the lengths in the type needs unstable const generics.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns `LookupBuildError::ShapeMismatch` if `D` isn't the sum of both lengths,
+    /// `LookupBuildError::DuplicateBreakpoint` if the last breakpoint of this lookup table equals the first of `other`
+    /// and `LookupBuildError::NotAscending` if it is larger.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{OneDLookup, Interpolation, Extrapolation};
+    /// const IDLE: OneDLookup<i16,f32,2> = create_1d_lookup!((0,800), (0.0,8.0));
+    /// const LOAD: OneDLookup<i16,f32,2> = create_1d_lookup!((1000,6000), (10.0,60.0));
+    /// let lookup_table: OneDLookup<i16,f32,4> = IDLE.concat(&LOAD).unwrap();
+    /// assert_eq!(lookup_table.lookup(&900i16, Extrapolation::NoneError, Interpolation::Linear).unwrap(), 9.0);
+    /// # }
+    /// ```
+    pub fn concat<const B: usize, const D: usize>(&self, other: &OneDLookup<T,U,B>) -> Result<OneDLookup<T,U,D>, LookupBuildError>
+    where T: Sub<Output = T>, U: EdgeDelta{
+        if D != C + B {
+            return Err(LookupBuildError::ShapeMismatch { expected: C + B, found: D });
+        }
+        if self.breakpoints[C-1] == other.breakpoints[0] {
+            return Err(LookupBuildError::DuplicateBreakpoint { index: C });
+        }
+        if self.breakpoints[C-1] > other.breakpoints[0] {
+            return Err(LookupBuildError::NotAscending { index: C });
+        }
+        let breakpoints: [T;D] = core::array::from_fn(|i| if i < C { self.breakpoints[i] } else { other.breakpoints[i-C] });
+        let values: [U;D] = core::array::from_fn(|i| if i < C { self.values[i] } else { other.values[i-C] });
+        Ok(OneDLookup::with_cached_diffs(breakpoints, values))
+    }
+
+    /// Returns whether this lookup table and `other` have identical breakpoints, so their values can be combined index by index, for example with `blend`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::OneDLookup;
+    /// const PRESSURE: OneDLookup<i16,f32,4> = create_1d_lookup!((0,500,4500,5000), (0.0,0.0,500.0,500.0));
+    /// const TEMPERATURE: OneDLookup<i16,i32,4> = create_1d_lookup!((0,500,4500,5000), (-40,-40,150,150));
+    /// assert!(PRESSURE.axes_match(&TEMPERATURE));
+    /// # }
+    /// ```
+    pub fn axes_match<V: Add + Sub + Div + Mul + Copy + Clone>(&self, other: &OneDLookup<T,V,C>) -> bool {
+        self.breakpoints == other.breakpoints
+    }
+
+    /// Returns a lookup table whose values mix the values of this lookup table and `other` linearly, as `value + (other value - value) * t`,
+    /// for fading between two calibrations.\
+    /// `t` is not limited to `0..=1`, values outside of it extrapolate beyond either lookup table.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `other` - The lookup table to mix with, it must have the same breakpoints as this one
+    /// * `t` - The share of `other` in the result, `0` returns this lookup table and `1` returns `other`
+    /// 
+    /// Returns `None` if the breakpoints of both lookup tables differ, see `axes_match`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{OneDLookup, Interpolation, Extrapolation};
+    /// const SUMMER: OneDLookup<i16,f32,2> = create_1d_lookup!((0,100), (10.0,20.0));
+    /// const WINTER: OneDLookup<i16,f32,2> = create_1d_lookup!((0,100), (30.0,60.0));
+    /// let lookup_table = SUMMER.blend(&WINTER, 0.25).unwrap();
+    /// assert_eq!(lookup_table.lookup(&100i16, Extrapolation::NoneError, Interpolation::Linear).unwrap(), 30.0);
+    /// # }
+    /// ```
+    pub fn blend(&self, other: &Self, t: U) -> Option<OneDLookup<T,U,C>>
+    where T: Sub<Output = T>, U: EdgeDelta + Add<Output = U> + Mul<Output = U> + PartialOrd{
+        if !self.axes_match(other) {
+            return None;
+        }
+        //mix towards a lower value the other way around, so unsigned value types don't wrap
+        let values: [U;C] = core::array::from_fn(|i| if other.values[i] < self.values[i] {
+            self.values[i] - (self.values[i] - other.values[i]) * t
+        } else {
+            self.values[i] + (other.values[i] - self.values[i]) * t
+        });
+        Some(OneDLookup::with_cached_diffs(self.breakpoints, values))
+    }
+
+    /// Returns the approximate inverse of this lookup table, which maps values back to breakpoints,
+    /// sampled at the entered value grid. The values must be strictly rising or strictly falling.\
+    /// Samples outside the range of the values hold the breakpoint of the nearest extreme value.
+    /// The inverse is calculated in `f64`, so for integer breakpoint types the result is rounded towards zero.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `new_breakpoints` - The values at which the inverse is sampled, these become the breakpoints of the inverse
+    /// 
+    /// # Errors
+    /// 
+    /// Returns `LookupBuildError::NotMonotone` if the values aren't strictly monotone
+    /// and `LookupBuildError::NotAscending` if the new breakpoints aren't in ascending order.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{OneDLookup, Interpolation, Extrapolation};
+    /// const LOOKUP_TABLE: OneDLookup<f32,f32,3> = create_1d_lookup!((500.0,2500.0,4500.0), (0.0,100.0,500.0));
+    /// let inverse: OneDLookup<f32,f32,3> = LOOKUP_TABLE.invert([0.0, 50.0, 300.0]).unwrap();
+    /// assert_eq!(inverse.lookup(&50.0f32, Extrapolation::NoneError, Interpolation::Linear).unwrap(), 1500.0);
+    /// # }
+    /// ```
+    pub fn invert<const D: usize>(&self, new_breakpoints: [U;D]) -> Result<OneDLookup<U,T,D>, LookupBuildError>
+    where T: EdgeDelta + Mul + Into<f64> + FromF64, U: PartialOrd + Sub<Output = U> + Into<f64>{
+        let rising = self.values[1] > self.values[0];
+        if let Some(index) = self.values.windows(2).position(|pair| if rising { pair[0] >= pair[1] } else { pair[0] <= pair[1] }) {
+            return Err(LookupBuildError::NotMonotone { index: index + 1 });
+        }
+        check_ascending(&new_breakpoints)?;
+        let values: [f64;C] = self.values.map(Into::into);
+        let inverse_values = new_breakpoints.map(|sample| {
+            let sample: f64 = sample.into();
+            if (rising && sample <= values[0]) || (!rising && sample >= values[0]) {
+                return self.breakpoints[0];
+            }
+            let index = match values.windows(2).position(|pair| if rising { pair[1] >= sample } else { pair[1] <= sample }) {
+                Some(index) => index,
+                None => return self.breakpoints[C-1],
+            };
+            let breakpoint_low: f64 = self.breakpoints[index].into();
+            let breakpoint_high: f64 = self.breakpoints[index+1].into();
+            T::from_f64((sample - values[index]) * (breakpoint_high - breakpoint_low) / (values[index+1] - values[index]) + breakpoint_low)
+        });
+        Ok(OneDLookup::with_cached_diffs(new_breakpoints, inverse_values))
+    }
+
+    /// Returns the lookup table encoded as bytes, for storing it in flash or sending it to another device.\
+    /// The encoding is a marker byte for the byte order followed by the breakpoints and then the values, each in the entered byte order.
+    /// The cached deltas aren't stored, `from_bytes` recalculates them.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{OneDLookup, Endianness};
+    /// const LOOKUP_TABLE: OneDLookup<i16,i16,2> = create_1d_lookup!((0,500), (1,2));
+    /// assert_eq!(LOOKUP_TABLE.to_bytes(Endianness::Big), vec![1, 0, 0, 1, 244, 0, 1, 0, 2]);
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn to_bytes(&self, endianness: Endianness) -> Vec<u8>
+    where T: ByteEncoding, U: ByteEncoding{
+        let mut bytes = Vec::with_capacity(1 + C * (T::SIZE + U::SIZE));
+        bytes.push(endianness.marker());
+        for breakpoint in self.breakpoints {
+            breakpoint.write_bytes(&mut bytes, endianness);
+        }
+        for value in self.values {
+            value.write_bytes(&mut bytes, endianness);
+        }
+        bytes
+    }
+
+    /// Returns a lookup table decoded from bytes created by `to_bytes`, in the byte order that is stored in the marker byte.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns `LookupBuildError::InvalidEndiannessMarker` if the first byte isn't a known marker,
+    /// `LookupBuildError::ShapeMismatch` with the expected and found number of bytes if the length doesn't match the lookup table
+    /// and `LookupBuildError::NotAscending` if the decoded breakpoints aren't in ascending order.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use::go_lookup_tables::OneDLookup;
+    /// let lookup_table: OneDLookup<i16,i16,2> = OneDLookup::from_bytes(&[0, 0, 0, 244, 1, 1, 0, 2, 0]).unwrap();
+    /// assert_eq!(lookup_table.to_points(), vec![(0.0, 1.0), (500.0, 2.0)]);
+    /// ```
+    pub fn from_bytes(bytes: &[u8]) -> Result<OneDLookup<T,U,C>, LookupBuildError>
+    where T: ByteEncoding + Sub<Output = T>, U: ByteEncoding + EdgeDelta{
+        let expected = 1 + C * (T::SIZE + U::SIZE);
+        if bytes.len() != expected {
+            return Err(LookupBuildError::ShapeMismatch { expected, found: bytes.len() });
+        }
+        let endianness = Endianness::from_marker(bytes[0])?;
+        let values_start = 1 + C * T::SIZE;
+        let breakpoints: [T;C] = core::array::from_fn(|i| T::read_bytes(&bytes[1 + i * T::SIZE..], endianness));
+        let values: [U;C] = core::array::from_fn(|i| U::read_bytes(&bytes[values_start + i * U::SIZE..], endianness));
+        check_ascending(&breakpoints)?;
+        Ok(OneDLookup::with_cached_diffs(breakpoints, values))
+    }
+
+    /// Fails to compile if the lookup table has less than 2 breakpoints, the cached deltas and linear extrapolation need the outer segments.
+    const HAS_OUTER_SEGMENTS: () = assert!(C >= 2, "A lookup table needs at least 2 breakpoints for its outer segments");
+
+    /// Returns a lookup table with the cached deltas calculated from the breakpoints and values.
+    fn with_cached_diffs(breakpoints: [T;C], values: [U;C]) -> OneDLookup<T,U,C>
+    where T: Sub<Output = T>, U: EdgeDelta{
+        let () = Self::HAS_OUTER_SEGMENTS;
+        OneDLookup {
+            breakpoints,
+            values,
+            last_diff_bp: breakpoints[C-1] - breakpoints[C-2],
+            last_diff_values: U::edge_delta(values[C-2], values[C-1]),
+            first_diff_bp: breakpoints[1] - breakpoints[0],
+            first_diff_values: U::edge_delta(values[0], values[1]),
+        }
+    }
+
+    /// Rescales the values of the lookup table into `[0,1]` in place and returns the original minimum and maximum value,
+    /// so the original values can be recovered with `value * (max - min) + min`. Meant for float value types, for example before uploading the table to a shader.\
+    /// The cached value deltas are recalculated, a table with only one distinct value is rescaled to all zeros.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{OneDLookup, Interpolation, Extrapolation};
+    /// let mut lookup_table: OneDLookup<i16,f32,3> = create_1d_lookup!((0,500,1000), (10.0,20.0,30.0));
+    /// assert_eq!(lookup_table.normalize_values(), (10.0, 30.0));
+    /// assert_eq!(lookup_table.lookup(&250i16, Extrapolation::NoneError, Interpolation::Linear).unwrap(), 0.25);
+    /// # }
+    /// ```
+    pub fn normalize_values(&mut self) -> (U, U)
+    where U: PartialOrd + EdgeDelta + Div<Output = U>{
+        let mut min = self.values[0];
+        let mut max = self.values[0];
+        for value in self.values.iter().skip(1) {
+            if *value < min {
+                min = *value;
+            }
+            if *value > max {
+                max = *value;
+            }
+        }
+        let range = max - min;
+        for value in self.values.iter_mut() {
+            *value = if max > min { (*value - min) / range } else { *value - min };
+        }
+        self.last_diff_values = U::edge_delta(self.values[C-2], self.values[C-1]);
+        self.first_diff_values = U::edge_delta(self.values[0], self.values[1]);
+        (min, max)
+    }
+
+    /// Returns a copy of the lookup table for the opposite axis direction, the breakpoints are negated and both arrays are reversed
+    /// so the breakpoints are still ascending. A lookup at `-x` in the reversed table returns the value at `x` in this one.\
+    /// The cached deltas are recalculated.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{OneDLookup, Interpolation, Extrapolation};
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,4> = create_1d_lookup!((0,500,4500,5000), (0.0,0.0,500.0,500.0));
+    /// let reversed = LOOKUP_TABLE.reversed();
+    /// assert_eq!(reversed.lookup(&-2000i16, Extrapolation::NoneError, Interpolation::Linear).unwrap(), 187.5f32);
+    /// # }
+    /// ```
+    pub fn reversed(&self) -> OneDLookup<T,U,C>
+    where T: Sub<Output = T> + Neg<Output = T>, U: EdgeDelta{
+        let breakpoints: [T;C] = core::array::from_fn(|i| -self.breakpoints[C-1-i]);
+        let values: [U;C] = core::array::from_fn(|i| self.values[C-1-i]);
+        OneDLookup::with_cached_diffs(breakpoints, values)
+    }
+
+    /// Returns a copy of the lookup table whose values are the running sum of the values up to and including each knot,
+    /// turning a table of rates per knot into a table of the accumulated quantity.\
+    /// The values are summed as they are, they aren't weighted by the distance between the breakpoints. The cached deltas are recalculated.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{OneDLookup, Interpolation, Extrapolation};
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,4> = create_1d_lookup!((0,1,2,3), (1.0,2.0,3.0,4.0));
+    /// let cumulative = LOOKUP_TABLE.cumulative();
+    /// assert_eq!(cumulative.lookup(&3i16, Extrapolation::NoneError, Interpolation::Linear).unwrap(), 10.0f32);
+    /// # }
+    /// ```
+    pub fn cumulative(&self) -> OneDLookup<T,U,C>
+    where T: Sub<Output = T>, U: EdgeDelta + Add<Output = U>{
+        let mut values = self.values;
+        for i in 1..C {
+            values[i] = values[i-1] + values[i];
+        }
+        OneDLookup::with_cached_diffs(self.breakpoints, values)
+    }
+
+    /// Returns a copy of the lookup table whose values are the centered moving average over `window` knots, for denoising measured data.\
+    /// The breakpoints are kept. Near the first and last knot the window is shrunk to the knots that exist,
+    /// an even window is rounded up to the next odd number so it stays centered, and a window of 0 or 1 keeps the values.
+    /// The average is calculated in `f64`, integer value types are rounded towards zero. The cached deltas are recalculated.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::OneDLookup;
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,4> = create_1d_lookup!((0,1,2,3), (0.0,3.0,0.0,0.0));
+    /// let smoothed = LOOKUP_TABLE.smooth(3);
+    /// assert_eq!(smoothed.to_points(), vec![(0.0, 1.5), (1.0, 1.0), (2.0, 1.0), (3.0, 0.0)]);
+    /// # }
+    /// ```
+    pub fn smooth(&self, window: usize) -> OneDLookup<T,U,C>
+    where T: Sub<Output = T>, U: EdgeDelta + AsF64 + FromF64{
+        let half = window / 2;
+        let values: [U;C] = core::array::from_fn(|i| {
+            let neighbours = &self.values[i.saturating_sub(half)..(i + half + 1).min(C)];
+            U::from_f64(neighbours.iter().map(|value| value.as_f64()).sum::<f64>() / neighbours.len() as f64)
+        });
+        OneDLookup::with_cached_diffs(self.breakpoints, values)
+    }
+
+    /// Returns the value at the breakpoint nearest to the entered breakpoint, but only if it lies within `tolerance` of it.\
+    /// Useful for discrete actuators that should only ever move to calibrated points.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `breakpoint` - A reference to the breakpoint that should be snapped to a breakpoint of the lookup table
+    /// * `tolerance` - The maximum distance between the entered breakpoint and the nearest breakpoint of the lookup table
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::OneDLookup;
+    /// const LOOKUP_TABLE: OneDLookup<i16,i16,3> = create_1d_lookup!((0,100,200), (0,10,40));
+    /// assert_eq!(LOOKUP_TABLE.quantize(&95i16, 5), Some(10));
+    /// assert_eq!(LOOKUP_TABLE.quantize(&150i16, 5), None);
+    /// # }
+    /// ```
+    pub fn quantize<Y: Copy>(&self, breakpoint: &Y, tolerance: T) -> Option<U>
+    where T: From<Y> + Sub<Output = T>{
+        let calc_breakpoint = T::from(*breakpoint);
+        let (index, distance) = match self.breakpoints.iter().position(|bp| bp >= &calc_breakpoint) {
+            Some(0) => (0, self.breakpoints[0] - calc_breakpoint),
+            Some(index) => {
+                let distance_low = calc_breakpoint - self.breakpoints[index-1];
+                let distance_high = self.breakpoints[index] - calc_breakpoint;
+                if distance_low < distance_high {
+                    (index-1, distance_low)
+                } else {
+                    (index, distance_high)
+                }
+            },
+            None => (C-1, calc_breakpoint - self.breakpoints[C-1]),
+        };
+        if distance <= tolerance {
+            Some(self.values[index])
+        } else {
+            None
+        }
+    }
+}
+
+/// Reads a lookup table that was stored with `OneDLookup::to_bytes` from a data file and returns the Rust source of a `pub const` named `name`,
+/// see `OneDLookup::to_const_source`. Meant for build scripts, so large tables can live in data files instead of source.
+/// 
+/// The workflow is to add `go_lookup_tables` as a build dependency, generate the source into `OUT_DIR` from `build.rs`
+/// and `include!` it in a module that has `go_lookup_tables::*` in scope.
+/// 
+/// # Errors
+/// 
+/// Returns the error of reading the file, or an `io::ErrorKind::InvalidData` error with the `LookupBuildError` of `OneDLookup::from_bytes`
+/// or the `NonFiniteAt` error of `OneDLookup::to_const_source`.
+/// 
+/// # Examples
+/// 
+/// In `build.rs`:
+/// ```no_run
+/// fn main() -> std::io::Result<()> {
+///     let source = go_lookup_tables::build_1d_lookup_source::<i16, f32, 4>("PRESSURE_SENSOR", "data/pressure_sensor.bin")?;
+///     std::fs::write(std::path::Path::new(&std::env::var("OUT_DIR").unwrap()).join("tables.rs"), source)?;
+///     println!("cargo:rerun-if-changed=data/pressure_sensor.bin");
+///     Ok(())
+/// }
+/// ```
+/// In the crate:
+/// ```ignore
+/// use go_lookup_tables::*;
+/// include!(concat!(env!("OUT_DIR"), "/tables.rs"));
+/// ```
+#[cfg(feature = "std")]
+pub fn build_1d_lookup_source<
+T: PartialOrd + Add + Copy + Clone + Sub<Output = T> + Div + ByteEncoding + SourceLiteral,
+U: Add + EdgeDelta + Div + Mul + Copy + Clone + ByteEncoding + SourceLiteral,
+const C: usize
+>(name: &str, path: impl AsRef<Path>) -> io::Result<String> {
+    let bytes = std::fs::read(path)?;
+    let table: OneDLookup<T,U,C> = OneDLookup::from_bytes(&bytes).map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error.to_string()))?;
+    table.to_const_source(name).map_err(|error| io::Error::new(io::ErrorKind::InvalidData, error.to_string()))
+}
+
+/// A wrapper around a 1-D lookup table that remembers the breakpoints the previous breakpoint fell between.\
+/// Queries that change slowly, like a sensor that is sampled often, usually fall in the same segment again, which skips the search.
+pub struct CachedOneDLookup<'a, T, U, const C: usize>{
+    /// The wrapped lookup table.
+    table: &'a OneDLookup<T,U,C>,
+    /// The index of the first breakpoint that was greater than or equal to the previous breakpoint, `None` if it was above the last breakpoint.
+    position: Option<usize>,
+    /// Whether the previous lookup could use the cached position.
+    last_was_cache_hit: bool,
+}
+
+impl<'a,
+T: PartialOrd + Add + Copy + Clone + Sub<Output = T> + Div<Output = T>, 
+U: Sub<Output = U>  + Add<Output = U> + Copy + Clone + From<T> + Mul<Output = U> + Div<Output = U> + EdgeDelta,
+const C: usize
+>
+CachedOneDLookup<'a,T,U,C>{
+    /// Returns a (interpolated) value from the wrapped lookup table that matches the entered breakpoint, see `OneDLookup::lookup`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{OneDLookup, CachedOneDLookup, Interpolation, Extrapolation};
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,4> = create_1d_lookup!((0,500,4500,5000), (0.0,0.0,500.0,500.0));
+    /// let mut cached_table = CachedOneDLookup::new(&LOOKUP_TABLE);
+    /// cached_table.lookup(&2000i16, Extrapolation::NoneHoldExtreme, Interpolation::Linear).unwrap();
+    /// let pressure = cached_table.lookup(&2010i16, Extrapolation::NoneHoldExtreme, Interpolation::Linear).unwrap();
+    /// assert_eq!(pressure, 188.75f32);
+    /// assert!(cached_table.last_was_cache_hit());
+    /// # }
+    /// ```
+    pub fn lookup<Y: Copy>(&mut self, breakpoint: &Y, extrapolation: Extrapolation<U>, interpolation: Interpolation) -> Result<U, ExtrapolationError>
+    where T: From<Y>{
+        let calc_breakpoint = T::from(*breakpoint);
+        let breakpoints = &self.table.breakpoints;
+        self.last_was_cache_hit = match self.position {
+            Some(index) => breakpoints[index] >= calc_breakpoint && (index == 0 || breakpoints[index-1] < calc_breakpoint),
+            None => breakpoints[C-1] < calc_breakpoint,
+        };
+        if !self.last_was_cache_hit {
+            self.position = breakpoints.iter().position(|bp| bp >= &calc_breakpoint);
+        }
+        self.table.evaluate(calc_breakpoint, self.position, extrapolation, interpolation)
+    }
+
+    /// Returns whether the previous lookup could skip the search because its breakpoint fell in the same segment as the one before it.
+    pub fn last_was_cache_hit(&self) -> bool {
+        self.last_was_cache_hit
+    }
+
+    /// Wraps a lookup table, the cache starts out at the first breakpoint.
+    pub fn new(table: &'a OneDLookup<T,U,C>) -> CachedOneDLookup<'a,T,U,C> {
+        CachedOneDLookup { table, position: Some(0), last_was_cache_hit: false }
+    }
+}
+
+/// A wrapper around a 1-D lookup table with a small direct-mapped cache of results, keyed by the query quantized to buckets of a fixed width.\
+/// A query in the same bucket as a cached query returns the cached result, which trades a small accuracy loss for speed when queries recur.
+/// The extrapolation and interpolation methods are fixed so the cached results stay valid, `SLOTS` is the number of cached results.
+pub struct MemoizedOneDLookup<'a, T, U, const C: usize, const SLOTS: usize>{
+    /// The wrapped lookup table.
+    table: &'a OneDLookup<T,U,C>,
+    /// The width of a bucket of queries that share a cached result.
+    quantum: f64,
+    /// The extrapolation method used for every lookup.
+    extrapolation: Extrapolation<U>,
+    /// The interpolation method used for every lookup.
+    interpolation: Interpolation,
+    /// The cached results with the bucket they belong to, a bucket is stored in the slot of its index modulo `SLOTS`.
+    slots: [Option<(i64, U)>; SLOTS],
+}
+
+impl<'a,
+T: PartialOrd + Add + Copy + Clone + Sub<Output = T> + Div<Output = T>, 
+U: Sub<Output = U>  + Add<Output = U> + Copy + Clone + From<T> + Mul<Output = U> + Div<Output = U> + EdgeDelta,
+const C: usize,
+const SLOTS: usize
+>
+MemoizedOneDLookup<'a,T,U,C,SLOTS>{
+    /// Returns the cached result for the bucket of the entered breakpoint, or looks it up and caches it.
+    /// Out of range breakpoints with `Extrapolation::NoneError` return an `ExtrapolationError` and aren't cached.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{OneDLookup, MemoizedOneDLookup, Interpolation, Extrapolation};
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,4> = create_1d_lookup!((0,500,4500,5000), (0.0,0.0,500.0,500.0));
+    /// let mut memoized: MemoizedOneDLookup<i16,f32,4,16> = MemoizedOneDLookup::new(&LOOKUP_TABLE, 10, Extrapolation::NoneHoldExtreme, Interpolation::Linear);
+    /// assert_eq!(memoized.lookup(&2000i16).unwrap(), 187.5f32);
+    /// //same bucket, so the cached result is returned
+    /// assert_eq!(memoized.lookup(&2005i16).unwrap(), 187.5f32);
+    /// # }
+    /// ```
+    pub fn lookup<Y: Copy>(&mut self, breakpoint: &Y) -> Result<U, ExtrapolationError>
+    where T: From<Y> + Into<f64>{
+        let calc_breakpoint = T::from(*breakpoint);
+        let bucket = libm::floor(calc_breakpoint.into() / self.quantum) as i64;
+        let slot = bucket.rem_euclid(SLOTS as i64) as usize;
+        match self.slots[slot] {
+            Some((cached_bucket, value)) if cached_bucket == bucket => Ok(value),
+            _ => {
+                let position = self.table.breakpoints.iter().position(|bp| bp >= &calc_breakpoint);
+                let value = self.table.evaluate(calc_breakpoint, position, self.extrapolation, self.interpolation)?;
+                self.slots[slot] = Some((bucket, value));
+                Ok(value)
+            },
+        }
+    }
+
+    /// Fails to compile if there is no slot to cache a result in.
+    const HAS_SLOTS: () = assert!(SLOTS > 0, "A MemoizedOneDLookup needs at least one slot");
+
+    /// Wraps a lookup table with an empty cache.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `table` - The lookup table to wrap
+    /// * `quantum` - The width of a bucket of queries that share a cached result, must be positive
+    /// * `extrapolation` - The extrapolation method to use for every lookup
+    /// * `interpolation` - The interpolation method to use for every lookup
+    /// 
+    /// # Panics
+    /// 
+    /// `new` fails to compile if `SLOTS` is 0.
+    /// 
+    /// ```compile_fail
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{OneDLookup, MemoizedOneDLookup, Interpolation, Extrapolation};
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,2> = create_1d_lookup!((0,500), (0.0,1.0));
+    /// let memoized: MemoizedOneDLookup<i16,f32,2,0> = MemoizedOneDLookup::new(&LOOKUP_TABLE, 10, Extrapolation::NoneHoldExtreme, Interpolation::Linear);
+    /// # }
+    /// ```
+    pub fn new(table: &'a OneDLookup<T,U,C>, quantum: T, extrapolation: Extrapolation<U>, interpolation: Interpolation) -> MemoizedOneDLookup<'a,T,U,C,SLOTS>
+    where T: Into<f64>{
+        let () = Self::HAS_SLOTS;
+        MemoizedOneDLookup { table, quantum: quantum.into(), extrapolation, interpolation, slots: [None; SLOTS] }
+    }
+}
+
+/// A wrapper around a 1-D lookup table that acts as a step function with hysteresis, for relay and threshold control.\
+/// Like `Interpolation::NoneFloor` the value of a breakpoint is held up to the next breakpoint, but the output only switches to the next value
+/// once the query reaches that breakpoint plus the margin, and only switches back once the query reaches the breakpoint minus the margin.
+/// This keeps a noisy query near a breakpoint from making the output chatter. Queries below the first breakpoint hold the first value.
+pub struct HysteresisLookup<'a, T, U, const C: usize>{
+    /// The wrapped lookup table.
+    table: &'a OneDLookup<T,U,C>,
+    /// How far the query must cross a breakpoint before the output switches.
+    margin: T,
+    /// The index of the breakpoint whose value is the current output, `None` until the first lookup.
+    state: Option<usize>,
+}
+
+impl<'a,
+T: PartialOrd + Sub<Output = T> + Add + Div + Copy + Clone,
+U: Add + Sub + Div + Mul + Copy + Clone,
+const C: usize
+>
+HysteresisLookup<'a,T,U,C>{
+    /// Returns the value for the entered breakpoint, taking the previous output into account.
+    /// The first lookup has no previous output, so it returns the value of the breakpoint at or below the entered breakpoint.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{OneDLookup, HysteresisLookup};
+    /// const FAN_SPEED: OneDLookup<i16,i16,2> = create_1d_lookup!((0,60), (0,100));
+    /// let mut fan = HysteresisLookup::new(&FAN_SPEED, 5);
+    /// assert_eq!(fan.lookup(&62i16), 100);
+    /// assert_eq!(fan.lookup(&57i16), 100);//still within the margin of 60
+    /// assert_eq!(fan.lookup(&55i16), 0);
+    /// assert_eq!(fan.lookup(&63i16), 0);//not yet 60 + 5
+    /// # }
+    /// ```
+    pub fn lookup<Y: Copy>(&mut self, breakpoint: &Y) -> U
+    where T: From<Y>{
+        let calc_breakpoint = T::from(*breakpoint);
+        let breakpoints = &self.table.breakpoints;
+        let mut index = match self.state {
+            Some(index) => index,
+            None => breakpoints.iter().rposition(|bp| bp <= &calc_breakpoint).unwrap_or(0),
+        };
+        //the distances are only calculated on the side where they are positive, so unsigned breakpoint types don't wrap
+        while index + 1 < C && calc_breakpoint >= breakpoints[index+1] && calc_breakpoint - breakpoints[index+1] >= self.margin {
+            index += 1;
+        }
+        while index > 0 && calc_breakpoint < breakpoints[index] && breakpoints[index] - calc_breakpoint >= self.margin {
+            index -= 1;
+        }
+        self.state = Some(index);
+        self.table.values[index]
+    }
+
+    /// Forgets the previous output, so the next lookup behaves like the first one.
+    pub fn reset(&mut self) {
+        self.state = None;
+    }
+
+    /// Wraps a lookup table without a previous output.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `table` - The lookup table to wrap
+    /// * `margin` - How far the query must cross a breakpoint before the output switches, must not be negative
+    pub fn new(table: &'a OneDLookup<T,U,C>, margin: T) -> HysteresisLookup<'a,T,U,C> {
+        HysteresisLookup { table, margin, state: None }
+    }
+}
+
+/// A wrapper around a 1-D lookup table with fixed extrapolation and interpolation methods, so it can be passed on as an `Interpolator`.
+pub struct ConfiguredOneDLookup<'a, T, U, const C: usize>{
+    /// The wrapped lookup table.
+    table: &'a OneDLookup<T,U,C>,
+    /// The extrapolation method used for every lookup.
+    extrapolation: Extrapolation<U>,
+    /// The interpolation method used for every lookup.
+    interpolation: Interpolation,
+}
+
+impl<'a,
+X: Copy,
+T: PartialOrd + Add + Copy + Clone + Sub<Output = T> + Div<Output = T> + From<X>, 
+U: Sub<Output = U>  + Add<Output = U> + Copy + Clone + From<T> + Mul<Output = U> + Div<Output = U> + EdgeDelta,
+const C: usize
+>
+Interpolator<X,U> for ConfiguredOneDLookup<'a,T,U,C>{
+    fn evaluate(&self, x: X) -> Result<U, ExtrapolationError> {
+        self.table.lookup(&x, self.extrapolation, self.interpolation)
+    }
+}
+
+impl<
+X: Copy,
+T: PartialOrd + Add + Copy + Clone + Sub<Output = T> + Div<Output = T> + From<X>, 
+U: Sub<Output = U>  + Add<Output = U> + Copy + Clone + From<T> + Mul<Output = U> + Div<Output = U> + EdgeDelta,
+const C: usize
+>
+Interpolator<X,U> for OneDLookup<T,U,C>{
+    /// Looks up the input with `Extrapolation::NoneError` and `Interpolation::Linear`.
+    fn evaluate(&self, x: X) -> Result<U, ExtrapolationError> {
+        self.lookup(&x, Extrapolation::NoneError, Interpolation::Linear)
+    }
+}
+
+/// Writes the breakpoints and values of the lookup table as two sequences, the cached deltas are left out because they follow from the data.
+#[cfg(feature = "serde")]
+impl<T: Serialize, U: Serialize, const C: usize> Serialize for OneDLookup<T,U,C> {
+    fn serialize<Z: serde::Serializer>(&self, serializer: Z) -> Result<Z::Ok, Z::Error> {
+        use serde::ser::SerializeStruct;
+        let mut state = serializer.serialize_struct("OneDLookup", 2)?;
+        state.serialize_field("breakpoints", &self.breakpoints[..])?;
+        state.serialize_field("values", &self.values[..])?;
+        state.end()
+    }
+}
+
+/// Reads the breakpoints and values written by the `Serialize` implementation and builds the lookup table with `OneDLookup::try_new`,
+/// so data with the wrong length or breakpoints that aren't ascending is rejected with the `LookupBuildError` as message.\
+/// The sequences are read straight into the arrays, so no allocator is needed.
+#[cfg(feature = "serde")]
+impl<'de, T, U, const C: usize> Deserialize<'de> for OneDLookup<T,U,C>
+where T: Deserialize<'de> + PartialOrd + Add + Div + Copy + EdgeDelta, U: Deserialize<'de> + Add + Div + Mul + Copy + EdgeDelta {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde::de::Error;
+        /// An array that is read from a sequence of exactly `C` items.
+        struct Items<X, const C: usize>([X;C]);
+        /// Reads a sequence into `Items`, counting the items past `C` so the error names the length that was found.
+        struct ItemsVisitor<X, const C: usize>(PhantomData<X>);
+        impl<'de, X: Deserialize<'de> + Copy, const C: usize> serde::de::Visitor<'de> for ItemsVisitor<X,C> {
+            type Value = Items<X,C>;
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "a sequence of {} items", C)
+            }
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Items<X,C>, A::Error> {
+                let mut items: [Option<X>;C] = [None;C];
+                let mut found = 0;
+                while let Some(item) = seq.next_element()? {
+                    if found < C {
+                        items[found] = Some(item);
+                    }
+                    found += 1;
+                }
+                if found != C {
+                    return Err(A::Error::custom(LookupBuildError::ShapeMismatch { expected: C, found }));
+                }
+                Ok(Items(items.map(|item| item.expect("every item is filled when the lengths match"))))
+            }
+        }
+        impl<'de, X: Deserialize<'de> + Copy, const C: usize> Deserialize<'de> for Items<X,C> {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                deserializer.deserialize_seq(ItemsVisitor(PhantomData))
+            }
+        }
+        #[derive(Deserialize)]
+        #[serde(rename = "OneDLookup", bound = "Items<T,C>: Deserialize<'de>, Items<U,C>: Deserialize<'de>")]
+        struct Knots<T, U, const C: usize> {
+            breakpoints: Items<T,C>,
+            values: Items<U,C>,
+        }
+        let knots = Knots::<T,U,C>::deserialize(deserializer)?;
+        OneDLookup::try_new(knots.breakpoints.0, knots.values.0).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+/// Descriptive metadata of a lookup table for calibration tooling, attached with `OneDLookup::with_meta`
+pub struct TableMeta {
+    /// The name of the lookup table.
+    pub name: String,
+    /// The unit of the breakpoints.
+    pub x_unit: String,
+    /// The unit of the values.
+    pub y_unit: String,
+}
+
+#[cfg(feature = "std")]
+impl TableMeta {
+    /// Returns metadata with the entered name and units.
+    pub fn new(name: impl Into<String>, x_unit: impl Into<String>, y_unit: impl Into<String>) -> TableMeta {
+        TableMeta { name: name.into(), x_unit: x_unit.into(), y_unit: y_unit.into() }
+    }
+}
+
+/// A 1-D lookup table together with its name and units, kept in a wrapper so `OneDLookup` itself stays small enough for `const` tables on embedded targets.\
+/// With the `serde` feature it can be stored by calibration tooling, see the `Deserialize` implementation of `OneDLookup` for the checks on loading.
+#[cfg(feature = "std")]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "OneDLookup<T,U,C>: Deserialize<'de>")))]
+pub struct AnnotatedOneDLookup<T, U, const C: usize>{
+    /// The wrapped lookup table.
+    table: OneDLookup<T,U,C>,
+    /// The name and units of the lookup table.
+    meta: TableMeta,
+}
+
+#[cfg(feature = "std")]
+impl<
+T: PartialOrd + Sub + Add + Div + Copy + Clone,
+U: Add + Sub + Div + Mul + Copy + Clone,
+const C: usize
+>
+AnnotatedOneDLookup<T,U,C>{
+    /// Returns the wrapped lookup table, for lookups.
+    pub fn table(&self) -> &OneDLookup<T,U,C> {
+        &self.table
+    }
+
+    /// Returns the name and units of the lookup table.
+    pub fn meta(&self) -> &TableMeta {
+        &self.meta
+    }
+
+    /// Returns the lookup table and its metadata, so the metadata can be replaced.
+    pub fn into_parts(self) -> (OneDLookup<T,U,C>, TableMeta) {
+        (self.table, self.meta)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<
+T: PartialOrd + Sub + Add + Div + Copy + Clone + fmt::Display,
+U: Add + Sub + Div + Mul + Copy + Clone + fmt::Display,
+const C: usize
+>
+fmt::Display for AnnotatedOneDLookup<T,U,C>{
+    /// Formats the lookup table as its name followed by every breakpoint-value pair with their units,
+    /// for example `intake pressure: 500 mV -> 0 kPa, 4500 mV -> 500 kPa`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}:", self.meta.name)?;
+        for (index, (bp, value)) in self.table.breakpoints.iter().zip(self.table.values.iter()).enumerate() {
+            let separator = if index == 0 { " " } else { ", " };
+            write!(f, "{}{} {} -> {} {}", separator, bp, self.meta.x_unit, value, self.meta.y_unit)?;
+        }
+        Ok(())
+    }
+}
+
+/// A wrapper around a 1-D lookup table that searches the breakpoints with a `u8` index, for 8-bit microcontrollers where `usize` arithmetic is costly.\
+/// The lookup table can have at most 256 breakpoints, which is checked at compile time.
+pub struct SmallOneDLookup<T, U, const C: usize>{
+    /// The wrapped lookup table.
+    table: OneDLookup<T,U,C>,
+    /// The index of the last breakpoint, the search counts up to it in a `u8`.
+    last_index: u8,
+}
+
+impl<
+T: PartialOrd + Add + Copy + Clone + Sub<Output = T> + Div<Output = T>, 
+U: Sub<Output = U>  + Add<Output = U> + Copy + Clone + From<T> + Mul<Output = U> + Div<Output = U> + EdgeDelta,
+const C: usize
+>
+SmallOneDLookup<T,U,C>{
+    /// Returns a (interpolated) value from the wrapped lookup table that matches the entered breakpoint, see `OneDLookup::lookup`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{OneDLookup, SmallOneDLookup, Interpolation, Extrapolation};
+    /// const LOOKUP_TABLE: SmallOneDLookup<i16,f32,4> = SmallOneDLookup::new(create_1d_lookup!((0,500,4500,5000), (0.0,0.0,500.0,500.0)));
+    /// let pressure = LOOKUP_TABLE.lookup(&2000i16, Extrapolation::NoneHoldExtreme, Interpolation::Linear).unwrap();
+    /// assert_eq!(pressure, 187.5f32);
+    /// # }
+    /// ```
+    pub fn lookup<Y: Copy>(&self, breakpoint: &Y, extrapolation: Extrapolation<U>, interpolation: Interpolation) -> Result<U, ExtrapolationError>
+    where T: From<Y>{
+        let calc_breakpoint = T::from(*breakpoint);
+        let mut index: u8 = 0;
+        let position = loop {
+            if self.table.breakpoints[usize::from(index)] >= calc_breakpoint {
+                break Some(index);
+            }
+            if index == self.last_index {
+                break None;
+            }
+            index += 1;
+        };
+        self.table.evaluate(calc_breakpoint, position.map(usize::from), extrapolation, interpolation)
+    }
+}
+
+impl<
+T: PartialOrd + Sub + Add + Div + Copy + Clone,
+U: Add + Sub + Div + Mul + Copy + Clone,
+const C: usize
+>
+SmallOneDLookup<T,U,C>{
+    /// Fails to compile if the lookup table has more breakpoints than a `u8` can index.
+    const FITS_U8_INDEX: () = assert!(C <= 256, "A SmallOneDLookup can have at most 256 breakpoints");
+
+    /// Wraps a lookup table.
+    /// 
+    /// # Panics
+    /// 
+    /// `new` fails to compile if the lookup table has more than 256 breakpoints.
+    /// 
+    /// ```compile_fail
+    /// use::go_lookup_tables::{OneDLookup, SmallOneDLookup};
+    /// const LOOKUP_TABLE: SmallOneDLookup<u16,u16,257> = SmallOneDLookup::new(OneDLookup::new([0;257], [0;257], 1, 0, 1, 0));
+    /// ```
+    pub const fn new(table: OneDLookup<T,U,C>) -> SmallOneDLookup<T,U,C> {
+        let () = Self::FITS_U8_INDEX;
+        SmallOneDLookup { table, last_index: C.saturating_sub(1) as u8 }
+    }
+
+    /// Returns the wrapped lookup table.
+    pub fn table(&self) -> &OneDLookup<T,U,C> {
+        &self.table
+    }
+}
+
+/// A struct representing a 1-D lookup table whose size is only known at runtime, the breakpoints and values are stored in a `Vec`.\
+/// Lookups behave exactly like `OneDLookup::lookup`, the deltas at the edges are calculated per lookup instead of cached.
+#[cfg(feature = "std")]
+pub struct DynamicOneDLookup<T, U>{
+    /// The breakpoints that act as the index for the values.
+    breakpoints: Vec<T>,
+    /// The values that represent the result from the lookup.
+    values: Vec<U>,
+}
+
+#[cfg(feature = "std")]
+impl<
+T: PartialOrd + Add + Copy + Clone + Sub<Output = T> + Div<Output = T>, 
+U: Sub<Output = U>  + Add<Output = U> + Copy + Clone + From<T> + Mul<Output = U> + Div<Output = U> + EdgeDelta,
+>
+DynamicOneDLookup<T,U>{
+    /// Returns a (interpolated) value from the lookup table that matches the entered breakpoint, see `OneDLookup::lookup`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use::go_lookup_tables::{DynamicOneDLookup, Interpolation, Extrapolation};
+    /// let lookup_table = DynamicOneDLookup::try_new(vec![0i16,500,4500,5000], vec![0.0f32,0.0,500.0,500.0]).unwrap();
+    /// assert_eq!(lookup_table.lookup(&2000i16, Extrapolation::NoneHoldExtreme, Interpolation::Linear).unwrap(), 187.5);
+    /// ```
+    pub fn lookup<Y: Copy>(&self, breakpoint: &Y, extrapolation: Extrapolation<U>, interpolation: Interpolation) -> Result<U, ExtrapolationError>
+    where T: From<Y>{
+        let calc_breakpoint = T::from(*breakpoint);
+        let position = self.breakpoints.iter().position(|bp| bp >= &calc_breakpoint);
+        let len = self.breakpoints.len();
+        KnotsView {
+            knots: (&self.breakpoints[..], &self.values[..]),
+            last_diff_bp: self.breakpoints[len-1] - self.breakpoints[len-2],
+            first_diff_bp: self.breakpoints[1] - self.breakpoints[0],
+        }.evaluate(calc_breakpoint, position, extrapolation, interpolation)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<
+T: PartialOrd + Sub + Add + Div + Copy + Clone,
+U: Add + Sub + Div + Mul + Copy + Clone,
+>
+DynamicOneDLookup<T,U>{
+    /// Returns a lookup table built from breakpoints and values that are only known at runtime.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns `LookupBuildError::ShapeMismatch` if the number of values differs from the number of breakpoints or if there are less than 2 breakpoints,
+    /// and `LookupBuildError::NotAscending` if the breakpoints aren't in ascending order.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use::go_lookup_tables::{DynamicOneDLookup, LookupBuildError};
+    /// let result = DynamicOneDLookup::try_new(vec![0i16,500,250], vec![0.0f32,1.0,2.0]);
+    /// assert_eq!(result.err(), Some(LookupBuildError::NotAscending { index: 2 }));
+    /// ```
+    pub fn try_new(breakpoints: Vec<T>, values: Vec<U>) -> Result<DynamicOneDLookup<T,U>, LookupBuildError> {
+        if values.len() != breakpoints.len() {
+            return Err(LookupBuildError::ShapeMismatch { expected: breakpoints.len(), found: values.len() });
+        }
+        if breakpoints.len() < 2 {
+            return Err(LookupBuildError::ShapeMismatch { expected: 2, found: breakpoints.len() });
+        }
+        check_ascending(&breakpoints)?;
+        Ok(DynamicOneDLookup { breakpoints, values })
+    }
+
+    /// Returns the breakpoints of the lookup table.
+    pub fn breakpoints(&self) -> &[T] {
+        &self.breakpoints
+    }
+
+    /// Returns the values of the lookup table.
+    pub fn values(&self) -> &[U] {
+        &self.values
+    }
+}
+
+/// A struct representing a 1-D lookup table whose breakpoints and values are stored on the heap in a `Box<[T]>` and `Box<[U]>`.\
+/// A `OneDLookup` is passed through the stack while it is built, so very large tables (tens of thousands of entries) can overflow it,
+/// this table is built from `Vec`s instead and never holds its data on the stack.
+/// Lookups behave exactly like `OneDLookup::lookup`, the breakpoints are searched with a binary search.
+#[cfg(feature = "std")]
+pub struct BoxedOneDLookup<T, U>{
+    /// The breakpoints that act as the index for the values.
+    breakpoints: Box<[T]>,
+    /// The values that represent the result from the lookup.
+    values: Box<[U]>,
+    /// The delta between the last two breakpoints.
+    last_diff_bp: T,
+    /// The delta between the first two breakpoints.
+    first_diff_bp: T,
+}
+
+#[cfg(feature = "std")]
+impl<
+T: PartialOrd + Add + Copy + Clone + Sub<Output = T> + Div<Output = T>, 
+U: Sub<Output = U>  + Add<Output = U> + Copy + Clone + From<T> + Mul<Output = U> + Div<Output = U> + EdgeDelta,
+>
+BoxedOneDLookup<T,U>{
+    /// Returns a (interpolated) value from the lookup table that matches the entered breakpoint, see `OneDLookup::lookup`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use::go_lookup_tables::{BoxedOneDLookup, Interpolation, Extrapolation};
+    /// let lookup_table = BoxedOneDLookup::try_new(vec![0i16,500,4500,5000], vec![0.0f32,0.0,500.0,500.0]).unwrap();
+    /// assert_eq!(lookup_table.lookup(&2000i16, Extrapolation::NoneHoldExtreme, Interpolation::Linear).unwrap(), 187.5);
+    /// ```
+    pub fn lookup<Y: Copy>(&self, breakpoint: &Y, extrapolation: Extrapolation<U>, interpolation: Interpolation) -> Result<U, ExtrapolationError>
+    where T: From<Y>{
+        let calc_breakpoint = T::from(*breakpoint);
+        let index = self.breakpoints.partition_point(|bp| bp < &calc_breakpoint);
+        let position = if index < self.breakpoints.len() { Some(index) } else { None };
+        KnotsView {
+            knots: (&self.breakpoints[..], &self.values[..]),
+            last_diff_bp: self.last_diff_bp,
+            first_diff_bp: self.first_diff_bp,
+        }.evaluate(calc_breakpoint, position, extrapolation, interpolation)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<
+T: PartialOrd + Sub + Add + Div + Copy + Clone,
+U: Add + Sub + Div + Mul + Copy + Clone,
+>
+BoxedOneDLookup<T,U>{
+    /// Returns a lookup table that takes ownership of the heap allocations of the entered breakpoints and values.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns `LookupBuildError::ShapeMismatch` if the number of values differs from the number of breakpoints or if there are less than 2 breakpoints,
+    /// and `LookupBuildError::NotAscending` if the breakpoints aren't in ascending order.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use::go_lookup_tables::{BoxedOneDLookup, LookupBuildError};
+    /// let result = BoxedOneDLookup::try_new(vec![0i16,500], vec![0.0f32]);
+    /// assert_eq!(result.err(), Some(LookupBuildError::ShapeMismatch { expected: 2, found: 1 }));
+    /// ```
+    pub fn try_new(breakpoints: Vec<T>, values: Vec<U>) -> Result<BoxedOneDLookup<T,U>, LookupBuildError>
+    where T: Sub<Output = T>{
+        if values.len() != breakpoints.len() {
+            return Err(LookupBuildError::ShapeMismatch { expected: breakpoints.len(), found: values.len() });
+        }
+        if breakpoints.len() < 2 {
+            return Err(LookupBuildError::ShapeMismatch { expected: 2, found: breakpoints.len() });
+        }
+        check_ascending(&breakpoints)?;
+        let len = breakpoints.len();
+        Ok(BoxedOneDLookup {
+            last_diff_bp: breakpoints[len-1] - breakpoints[len-2],
+            first_diff_bp: breakpoints[1] - breakpoints[0],
+            breakpoints: breakpoints.into_boxed_slice(),
+            values: values.into_boxed_slice(),
+        })
+    }
+
+    /// Returns the breakpoints of the lookup table.
+    pub fn breakpoints(&self) -> &[T] {
+        &self.breakpoints
+    }
+
+    /// Returns the values of the lookup table.
+    pub fn values(&self) -> &[U] {
+        &self.values
+    }
+}
+
+/// A struct representing a 1-D lookup table that is read directly from bytes created by `OneDLookup::to_bytes`, for example a memory mapped calibration region.\
+/// Nothing is decoded up front, each lookup only decodes the breakpoints and values it needs in the byte order of the marker byte.
+/// Because every number is decoded from its bytes there are no alignment requirements on the buffer and no `unsafe` is needed.
+/// The number of breakpoints follows from the length of the buffer, so it doesn't have to be known at compile time.
+pub struct OneDLookupView<'a, T, U>{
+    /// The encoded lookup table, starting with the marker byte.
+    bytes: &'a [u8],
+    /// The byte order of the encoded numbers.
+    endianness: Endianness,
+    /// The number of breakpoint-value pairs.
+    count: usize,
+    /// Ties the view to the types of the encoded breakpoints and values.
+    types: PhantomData<(T, U)>,
+}
+
+impl<
+T: PartialOrd + Sub + Add + Div + Copy + Clone + ByteEncoding,
+U: Add + Sub + Div + Mul + Copy + Clone + ByteEncoding,
+>
+Knots<T, U> for &OneDLookupView<'_,T,U>{
+    fn count(&self) -> usize {
+        self.count
+    }
+    fn breakpoint(&self, index: usize) -> T {
+        T::read_bytes(&self.bytes[1 + index * T::SIZE..], self.endianness)
+    }
+    fn value(&self, index: usize) -> U {
+        U::read_bytes(&self.bytes[1 + self.count * T::SIZE + index * U::SIZE..], self.endianness)
+    }
+}
+
+impl<'a,
+T: PartialOrd + Sub + Add + Div + Copy + Clone + ByteEncoding,
+U: Add + Sub + Div + Mul + Copy + Clone + ByteEncoding,
+>
+OneDLookupView<'a,T,U>{
+    /// Returns a view of the lookup table encoded in the bytes, the breakpoints are checked once but not copied.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns `LookupBuildError::InvalidEndiannessMarker` if the first byte isn't a known marker,
+    /// `LookupBuildError::ShapeMismatch` with the expected and found number of bytes if the length isn't that of a table with at least 2 breakpoints
+    /// and `LookupBuildError::NotAscending` if the encoded breakpoints aren't in ascending order.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use::go_lookup_tables::{OneDLookupView, Interpolation, Extrapolation};
+    /// let lookup_table: OneDLookupView<i16,i16> = OneDLookupView::new(&[0, 0, 0, 244, 1, 1, 0, 2, 0]).unwrap();
+    /// assert_eq!(lookup_table.lookup(&250i16, Extrapolation::NoneError, Interpolation::NoneCeiling).unwrap(), 2);
+    /// ```
+    pub fn new(bytes: &'a [u8]) -> Result<OneDLookupView<'a,T,U>, LookupBuildError> {
+        let pair_size = T::SIZE + U::SIZE;
+        let count = bytes.len().saturating_sub(1) / pair_size;
+        if count < 2 || bytes.len() != 1 + count * pair_size {
+            return Err(LookupBuildError::ShapeMismatch { expected: 1 + count.max(2) * pair_size, found: bytes.len() });
+        }
+        let view = OneDLookupView { bytes, endianness: Endianness::from_marker(bytes[0])?, count, types: PhantomData };
+        let knots = &view;
+        if let Some(index) = (1..count).find(|index| knots.breakpoint(index - 1) > knots.breakpoint(*index)) {
+            return Err(LookupBuildError::NotAscending { index });
+        }
+        Ok(view)
+    }
+}
+
+impl<
+T: PartialOrd + Add + Copy + Clone + Sub<Output = T> + Div<Output = T> + ByteEncoding, 
+U: Sub<Output = U>  + Add<Output = U> + Copy + Clone + From<T> + Mul<Output = U> + Div<Output = U> + ByteEncoding + EdgeDelta,
+>
+OneDLookupView<'_,T,U>{
+    /// Returns a (interpolated) value from the encoded lookup table that matches the entered breakpoint, see `OneDLookup::lookup`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{OneDLookup, OneDLookupView, Endianness, Interpolation, Extrapolation};
+    /// const LOOKUP_TABLE: OneDLookup<i16,f32,4> = create_1d_lookup!((0,500,4500,5000), (0.0,0.0,500.0,500.0));
+    /// let bytes = LOOKUP_TABLE.to_bytes(Endianness::Big);
+    /// let view: OneDLookupView<i16,f32> = OneDLookupView::new(&bytes).unwrap();
+    /// assert_eq!(view.lookup(&2000i16, Extrapolation::NoneHoldExtreme, Interpolation::Linear).unwrap(), 187.5);
+    /// # }
+    /// ```
+    pub fn lookup<Y: Copy>(&self, breakpoint: &Y, extrapolation: Extrapolation<U>, interpolation: Interpolation) -> Result<U, ExtrapolationError>
+    where T: From<Y>{
+        let calc_breakpoint = T::from(*breakpoint);
+        let knots = self;
+        let position = (0..self.count).find(|index| knots.breakpoint(*index) >= calc_breakpoint);
+        let last = self.count - 1;
+        KnotsView {
+            knots,
+            last_diff_bp: knots.breakpoint(last) - knots.breakpoint(last-1),
+            first_diff_bp: knots.breakpoint(1) - knots.breakpoint(0),
+        }.evaluate(calc_breakpoint, position, extrapolation, interpolation)
+    }
+}
+
+/// Returns a lookup table. Only use an ascending breakpoints vector! for example  1,2,3,4 and not 4,3,2,1 or 1,2,3,2 \
+/// breakpoints and values must have the same length!
+/// 
+/// # Arguments
+/// 
+/// * `breakpoints` - The breakpoints that act as the index for the values
+/// * `values` - The values that represent the result from the lookup
+/// 
+/// # Panics
+///
+/// `create_1d_lookup!` panics if breakpoints is not in ascending order or if breakpoints.len() != values.len().
+/// It also fails if the delta between two adjacent breakpoints or values overflows the table's types, for example `(-100, 100)` for `i8` breakpoints.
+/// A falling edge of unsigned values, like `(10u8, 0)`, also overflows here, build those tables with `OneDLookup::try_new` instead.
+/// This panic is generated at compile time.
+/// 
+/// # Type requirements
+/// 
+/// The value type must implement `From` for the breakpoint type, so the breakpoint deltas can be represented by the values.
+/// Pairing types without a lossless conversion, like `i32` breakpoints with `f32` values, is rejected at compile time.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # #[macro_use] extern crate go_lookup_tables; fn main() {
+/// use::go_lookup_tables::*;
+/// const LOOKUP_TABLE: OneDLookup<i16,f32,4> = create_1d_lookup!((0,500,4500,5000), (0.0,0.0,500.0,500.0)); //simple 0.5V to 4.5V pressure sensor
+/// # }
+/// ```
+/// 
+/// ```compile_fail
+/// # #[macro_use] extern crate go_lookup_tables; fn main() {
+/// use::go_lookup_tables::*;
+/// const LOOKUP_TABLE: OneDLookup<i32,f32,4> = create_1d_lookup!((0,500,4500,5000), (0.0,0.0,500.0,500.0));
+/// LOOKUP_TABLE.lookup(&250i32, Extrapolation::NoneError, Interpolation::Linear); //f32 doesn't implement From<i32>, use lookup_f64 instead
+/// # }
+/// ```
+/// 
+/// ```compile_fail
+/// # #[macro_use] extern crate go_lookup_tables; fn main() {
+/// use::go_lookup_tables::*;
+/// let lookup_table: OneDLookup<i8,i8,2> = create_1d_lookup!((-100,100), (0,1)); //100 - -100 doesn't fit in an i8
+/// # }
+/// ```
+#[macro_export]
+macro_rules! create_1d_lookup {
+    (($($bps:expr),*), ($($vals:expr),*)) => {{
+        //the checks are inline const blocks whose types are inferred from the table, so every delta is evaluated in the table's types
+        //at compile time and an overflowing subtraction fails the build instead of wrapping
+        let (breakpoints, values) = const {
+            let breakpoints = [ $($bps,)* ];
+            let values = [ $($vals,)* ];
+            if breakpoints.len() != values.len() {
+                panic!("lengths of breakpoints and values don't match");
+            }
+
+            let mut i = 1;
+            while i < breakpoints.len() {
+                if breakpoints[i - 1] > breakpoints[i] {
+                    panic!("breakpoints aren't sorted, they should be in ascending order");
+                }
+                let _ = breakpoints[i] - breakpoints[i - 1];
+                let _ = values[i] - values[i - 1];
+                i += 1;
+            }
+            (breakpoints, values)
+        };
+        let lookup = OneDLookup::new(
+            breakpoints,
+            values,
+            const { [ $($bps,)* ][[ $($bps,)* ].len()-1] - [ $($bps,)* ][[ $($bps,)* ].len()-2] },
+            const { [ $($vals,)* ][[ $($vals,)* ].len()-1] - [ $($vals,)* ][[ $($vals,)* ].len()-2] },
+            const { [ $($bps,)* ][1] - [ $($bps,)* ][0] },
+            const { [ $($vals,)* ][1] - [ $($vals,)* ][0] },
+		);
+        lookup
+    }};
+}
+
+/// Returns a lookup table like `create_1d_lookup!`, but checks the breakpoints at runtime and returns a `Result` instead of panicking.
+/// Use it for tables built from `let` arrays, `const` tables should keep using `create_1d_lookup!` so mistakes fail the build.
+/// 
+/// # Arguments
+/// 
+/// * `breakpoints` - The breakpoints, either as a list of expressions `(0,500,1000)` or as an array expression
+/// * `values` - The values, in the same form as the breakpoints
+/// 
+/// # Errors
+/// 
+/// Returns `LookupBuildError::NotAscending` if the breakpoints aren't in ascending order, see `OneDLookup::try_new`.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # #[macro_use] extern crate go_lookup_tables; fn main() {
+/// use::go_lookup_tables::{OneDLookup, LookupBuildError};
+/// let breakpoints: [i16;3] = [0, 500, 250];
+/// let values: [f32;3] = [0.0, 1.0, 2.0];
+/// let result: Result<OneDLookup<i16,f32,3>, _> = try_create_1d_lookup!(breakpoints, values);
+/// assert_eq!(result.err(), Some(LookupBuildError::NotAscending { index: 2 }));
+/// let lookup_table: OneDLookup<i16,f32,3> = try_create_1d_lookup!((0,250,500), (0.0,1.0,2.0)).unwrap();
+/// # }
+/// ```
+#[macro_export]
+macro_rules! try_create_1d_lookup {
+    (($($bps:expr),*), ($($vals:expr),*)) => {
+        OneDLookup::try_new([$($bps),*], [$($vals),*])
+    };
+    ($bps:expr, $vals:expr) => {
+        OneDLookup::try_new($bps, $vals)
+    };
+}
+
+/// Returns a lookup table like `create_1d_lookup!`, but written as runs of breakpoints that share a value,
+/// so flat regions of a table only state their value once.\
+/// Every run expands to one knot per breakpoint at compile time, with the same checks as `create_1d_lookup!`.
+/// 
+/// # Arguments
+/// 
+/// * `runs` - The breakpoints of every run followed by `=>` and their shared value, like `(0,500) => 0.0`
+/// 
+/// # Panics
+/// 
+/// `create_1d_lookup_rle!` panics if the breakpoints, across all runs, are not in ascending order.
+/// This panic is generated at compile time.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # #[macro_use] extern crate go_lookup_tables; fn main() {
+/// use::go_lookup_tables::*;
+/// const LOOKUP_TABLE: OneDLookup<i16,f32,4> = create_1d_lookup_rle!((0,500) => 0.0, (4500,5000) => 500.0); //simple 0.5V to 4.5V pressure sensor
+/// assert_eq!(LOOKUP_TABLE.to_points(), [(0.0, 0.0), (500.0, 0.0), (4500.0, 500.0), (5000.0, 500.0)]);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! create_1d_lookup_rle {
+    (@value $bp:expr, $val:expr) => {
+        $val
+    };
+    ($(($($bps:expr),+) => $val:expr),+ $(,)?) => {
+        $crate::create_1d_lookup!(($($($bps),+),+), ($($($crate::create_1d_lookup_rle!(@value $bps, $val)),+),+))
+    };
+}
+
+/// A struct representing a 1-D lookup table that acts as a step function, breakpoints must be an ascending vector! 1,2,3,4 and not 4,3,2,1 or 1,2,3,2\
+/// The values are never interpolated, so they can be of any type, like a `bool` or an enum.
+/// 
+/// The breakpoints only need to be ordered, so a C-like enum that derives `PartialOrd` can index the values, like gear positions.
+/// `lookup_floor` and `lookup_ceiling` only compare breakpoints, `lookup` also measures the distance between breakpoints with `Sub`
+/// for `StepInterpolation::NoneClosest`, for an enum that can be any ordered type, for example the difference of the discriminants.
+/// 
+/// ```
+/// use::go_lookup_tables::{StepLookup1D};
+/// #[derive(Clone, Copy, PartialEq, PartialOrd)]
+/// enum Gear { First = 1, Second, Third, Fourth }
+/// const SHIFT_RPM: StepLookup1D<Gear,u16,2> = StepLookup1D::new([Gear::First, Gear::Fourth], [6000, 4500]);
+/// assert_eq!(SHIFT_RPM.lookup_floor(&Gear::Second), 6000);
+/// assert_eq!(SHIFT_RPM.lookup_ceiling(&Gear::Second), 4500);
+/// ```
+pub struct StepLookup1D<T, U, const C: usize>{
+    /// The breakpoints that act as the index for the values.
+    breakpoints: [T;C],
+    /// The values that represent the result from the lookup.
+    values: [U;C],
+}
+
+impl<
+T: PartialOrd + Copy + Clone,
+U: Copy + Clone,
+const C: usize
+>
+StepLookup1D<T,U,C>{
+    /// Returns the value from the lookup table that matches the entered breakpoint.
+    /// Breakpoints outside of the range of the lookup table hold the value at the first or last breakpoint.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `breakpoint` - A reference to the breakpoint for which a value must be found by the lookup table
+    /// * `interpolation` - The rounding method to use for this lookup operation
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use::go_lookup_tables::{StepLookup1D, StepInterpolation};
+    /// const LOOKUP_TABLE: StepLookup1D<i16,bool,3> = StepLookup1D::new([0,500,4500], [false,true,false]);
+    /// assert!(LOOKUP_TABLE.lookup(&2000i16, StepInterpolation::NoneFloor));
+    /// ```
+    pub fn lookup<Y: Copy>(&self, breakpoint: &Y, interpolation: StepInterpolation) -> U
+    where T: From<Y> + Sub, <T as Sub>::Output: PartialOrd{
+        let calc_breakpoint = T::from(*breakpoint);
+        match (self.position(calc_breakpoint), interpolation) {
+            (Ok(index), _) | (Err(index), StepInterpolation::NoneCeiling) => self.values[index],
+            (Err(index), StepInterpolation::NoneFloor) => self.values[index-1],
+            //only rounding to the closest breakpoint measures distances, so the other methods have their own lookups without `Sub`
+            (Err(index), StepInterpolation::NoneClosest) => {
+                if calc_breakpoint - self.breakpoints[index-1] < self.breakpoints[index] - calc_breakpoint {
+                    self.values[index-1]
+                } else {
+                    self.values[index]
+                }
+            }
+        }
+    }
+
+    /// Returns the value at the last breakpoint that isn't above the entered breakpoint, like `lookup` with `StepInterpolation::NoneFloor`.\
+    /// Only the order of the breakpoints is used, so the breakpoint type doesn't need `Sub`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `breakpoint` - A reference to the breakpoint for which a value must be found by the lookup table
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use::go_lookup_tables::{StepLookup1D};
+    /// const LOOKUP_TABLE: StepLookup1D<i16,bool,3> = StepLookup1D::new([0,500,4500], [false,true,false]);
+    /// assert!(LOOKUP_TABLE.lookup_floor(&2000i16));
+    /// ```
+    pub fn lookup_floor<Y: Copy>(&self, breakpoint: &Y) -> U
+    where T: From<Y>{
+        match self.position(T::from(*breakpoint)) {
+            Ok(index) => self.values[index],
+            Err(index) => self.values[index-1],
+        }
+    }
+
+    /// Returns the value at the first breakpoint that isn't below the entered breakpoint, like `lookup` with `StepInterpolation::NoneCeiling`.\
+    /// Only the order of the breakpoints is used, so the breakpoint type doesn't need `Sub`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `breakpoint` - A reference to the breakpoint for which a value must be found by the lookup table
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use::go_lookup_tables::{StepLookup1D};
+    /// const LOOKUP_TABLE: StepLookup1D<i16,bool,3> = StepLookup1D::new([0,500,4500], [false,true,false]);
+    /// assert!(!LOOKUP_TABLE.lookup_ceiling(&2000i16));
+    /// ```
+    pub fn lookup_ceiling<Y: Copy>(&self, breakpoint: &Y) -> U
+    where T: From<Y>{
+        match self.position(T::from(*breakpoint)) {
+            Ok(index) | Err(index) => self.values[index],
+        }
+    }
+
+    /// Returns `Ok` with the index of the breakpoint whose value is held without rounding, the first or last breakpoint out of range,
+    /// or `Err` with the index of the first breakpoint above the entered breakpoint if it lies between two breakpoints.
+    fn position(&self, calc_breakpoint: T) -> Result<usize, usize> {
+        match self.breakpoints.iter().position(|bp| bp >= &calc_breakpoint) {
+            Some(index) if index == 0 || self.breakpoints[index] == calc_breakpoint => Ok(index),
+            Some(index) => Err(index),
+            None => Ok(C-1),
+        }
+    }
+
+    /// Returns a step lookup table. Only use an ascending breakpoints vector! for example  1,2,3,4 and not 4,3,2,1 or 1,2,3,2 \
+    /// breakpoints and values must have the same length!
+    /// 
+    /// # Arguments
+    /// 
+    /// * `breakpoints` - The breakpoints that act as the index for the values
+    /// * `values` - The values that represent the result from the lookup
+    pub const fn new(breakpoints: [T;C], values: [U;C]) -> StepLookup1D<T,U,C> {
+        StepLookup1D { breakpoints, values }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// A horizontal breakpoint for `TwoDLookup::lookup_typed`, so it can't be passed where a vertical breakpoint is expected.
+pub struct Horizontal<Y>(pub Y);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// A vertical breakpoint for `TwoDLookup::lookup_typed`, so it can't be passed where a horizontal breakpoint is expected.
+pub struct Vertical<Z>(pub Z);
+
+/// A struct representing a 2-D lookup table, breakpoints must be an ascending vectors! 1,2,3,4 and not 4,3,2,1 or 1,2,3,2
+/// 
+/// example:
+/// /*
+///     x   0   500 1000
+///     0   3.0 4.2 5.5
+///     3   4.2 5.0 6.0
+///     6   5.0 5.8 6.5
+/// */
+pub struct TwoDLookup<S, T, U, const N: usize, const M: usize>{
+    ///The horizontal breakpoints
+    breakpoints_h: [S;N],
+    ///The vertical breakpoints
+    breakpoints_v: [T;M],
+    ///The values matrix
+    values:        [[U;N];M],
+    ///The start and step of the horizontal breakpoints if they are evenly spaced, with the function that calculates their index
+    uniform_h:     Option<((S,S), UniformPosition<S>)>,
+    ///The start and step of the vertical breakpoints if they are evenly spaced, with the function that calculates their index
+    uniform_v:     Option<((T,T), UniformPosition<T>)>,
+}
+
+impl<
+S: PartialOrd + Add + Copy + Clone + Sub<Output = S> + Div<Output =S>, 
+T: PartialOrd + Add + Copy + Clone + Sub<Output = T> + Div<Output = T>, 
+U: Sub<Output = U>  + Add<Output = U> + Copy + Clone + From<T> + From<S> + Mul<Output = U> + Div<Output = U> + Neg<Output = U> + EdgeDelta,
+const N: usize,
+const M: usize,
+>TwoDLookup<S,T,U,N,M> {
+    /// Returns a (interpolated) value from the lookup table that matches the entered breakpoints.\
+    /// `Interpolation::Smoothstep`, `Smootherstep`, `Cubic` and `AngularLinear` are calculated in `f64`, which `lookup` doesn't require of the table types,
+    /// so it interpolates linearly for them. Use `lookup_nonlinear` for those methods.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `breakpoint_h` - A reference to the horizontal breakpoint for which a value must be found by the lookup table
+    /// * `breakpoint_v` - A reference to the vertical breakpoint for which a value must be found by the lookup table
+    /// * `interpolation` - The interpolation method to use for this lookup operation
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{TwoDLookup, Interpolation};
+    /// let rpm = 750i16;
+    /// let throttle_pos = 4;
+    /// const LOOKUP_TABLE: TwoDLookup<i16,i8,f32,3,3> = create_2d_lookup!((0,500,1000),(0,3,6),(
+    /// 3.0,4.2,5.5;
+    /// 4.2,5.0,6.0;
+    /// 5.0,5.8,6.5)); //only a small part of an actual injector table
+    /// /* full table:
+    ///     x   0   500 1000
+    ///     0   3.0 4.2 5.5
+    ///     3   4.2 5.0 6.0
+    ///     6   5.0 5.8 6.5
+    ///  */
+    /// let injector_time = LOOKUP_TABLE.lookup(&rpm, &throttle_pos, Interpolation::Linear).unwrap();
+    /// assert_eq!(injector_time, 5.7166667f32)
+    /// # }
+    /// ```
+    pub fn lookup<Y: Copy, Z: Copy>(&self, breakpoint_h: &Y, breakpoint_v: &Z, interpolation: Interpolation) -> Result<U, Infallible>
+    where S: From<Y> + From<i8>, T: From<Z> + From<i8>, U: From<i8>{
+        let calc_breakpoint_h = S::from(*breakpoint_h);
+        let calc_breakpoint_v = T::from(*breakpoint_v);
+        let indexes_h = self.indexes_h(calc_breakpoint_h, &interpolation);
+        let indexes_v = self.indexes_v(calc_breakpoint_v, &interpolation);
+        Ok(self.interpolate(indexes_h, indexes_v, calc_breakpoint_h, calc_breakpoint_v))
+    }
+
+    /// Returns a (interpolated) value from the lookup table that matches the entered breakpoints, like `lookup`,
+    /// but also calculates `Interpolation::Smoothstep`, `Smootherstep` and `AngularLinear` on both axes.\
+    /// Those are calculated in `f64`, so the breakpoints must convert to `f64` and the values must be calculated on in `f64` one component at a time.
+    /// `Interpolation::Cubic` falls back to linear interpolation on both axes.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `breakpoint_h` - A reference to the horizontal breakpoint for which a value must be found by the lookup table
+    /// * `breakpoint_v` - A reference to the vertical breakpoint for which a value must be found by the lookup table
+    /// * `interpolation` - The interpolation method to use for this lookup operation
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{TwoDLookup, Interpolation};
+    /// const LOOKUP_TABLE: TwoDLookup<i16,i8,f32,2,2> = create_2d_lookup!((0,400),(0,10),(
+    /// 0.0,100.0;
+    /// 0.0,100.0));
+    /// assert_eq!(LOOKUP_TABLE.lookup_nonlinear(&100i16, &0i8, Interpolation::Smoothstep).unwrap(), 15.625);
+    /// assert_eq!(LOOKUP_TABLE.lookup(&100i16, &0i8, Interpolation::Smoothstep).unwrap(), 25.0);
+    /// # }
+    /// ```
+    pub fn lookup_nonlinear<Y: Copy, Z: Copy>(&self, breakpoint_h: &Y, breakpoint_v: &Z, interpolation: Interpolation) -> Result<U, Infallible>
+    where S: From<Y> + From<i8> + AsF64, T: From<Z> + From<i8> + AsF64, U: From<i8> + F64Components{
+        let calc_breakpoint_h = S::from(*breakpoint_h);
+        let calc_breakpoint_v = T::from(*breakpoint_v);
+        let indexes_h = self.indexes_h(calc_breakpoint_h, &interpolation);
+        let indexes_v = self.indexes_v(calc_breakpoint_v, &interpolation);
+        Ok(self.interpolate_nonlinear(indexes_h, indexes_v, calc_breakpoint_h, calc_breakpoint_v, &interpolation))
+    }
+
+    /// Returns a (interpolated) value from the lookup table that matches the entered breakpoints, like `lookup`,
+    /// but the breakpoints are wrapped in `Horizontal` and `Vertical` so swapping the axes is a compile error.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `breakpoint_h` - The horizontal breakpoint for which a value must be found by the lookup table
+    /// * `breakpoint_v` - The vertical breakpoint for which a value must be found by the lookup table
+    /// * `interpolation` - The interpolation method to use for this lookup operation
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{TwoDLookup, Interpolation, Horizontal, Vertical};
+    /// const LOOKUP_TABLE: TwoDLookup<i16,i8,f32,3,3> = create_2d_lookup!((0,500,1000),(0,3,6),(
+    /// 3.0,4.2,5.5;
+    /// 4.2,5.0,6.0;
+    /// 5.0,5.8,6.5));
+    /// let injector_time = LOOKUP_TABLE.lookup_typed(Horizontal(750i16), Vertical(4i8), Interpolation::Linear).unwrap();
+    /// assert_eq!(injector_time, 5.7166667f32)
+    /// # }
+    /// ```
+    /// 
+    /// Swapping the axes doesn't compile:
+    /// 
+    /// ```compile_fail
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{TwoDLookup, Interpolation, Horizontal, Vertical};
+    /// const LOOKUP_TABLE: TwoDLookup<i16,i16,f32,2,2> = create_2d_lookup!((0,500),(0,3),(
+    /// 3.0,4.2;
+    /// 4.2,5.0));
+    /// let injector_time = LOOKUP_TABLE.lookup_typed(Vertical(4i16), Horizontal(750i16), Interpolation::Linear);
+    /// # }
+    /// ```
+    pub fn lookup_typed<Y: Copy, Z: Copy>(&self, breakpoint_h: Horizontal<Y>, breakpoint_v: Vertical<Z>, interpolation: Interpolation) -> Result<U, Infallible>
+    where S: From<Y> + From<i8>, T: From<Z> + From<i8>, U: From<i8>{
+        self.lookup(&breakpoint_h.0, &breakpoint_v.0, interpolation)
+    }
+
+    /// Returns a linearly interpolated value from the lookup table and the four corners that were blended,
+    /// as `(horizontal index, vertical index, weight)` with weights that sum to 1.0. Useful for debugging a bilinear blend.\
+    /// On a gridline or a knot, or when a breakpoint is out of range, corners are listed more than once and the duplicates get a weight of 0.0.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `breakpoint_h` - A reference to the horizontal breakpoint for which a value must be found by the lookup table
+    /// * `breakpoint_v` - A reference to the vertical breakpoint for which a value must be found by the lookup table
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::TwoDLookup;
+    /// const LOOKUP_TABLE: TwoDLookup<i16,i8,f32,3,3> = create_2d_lookup!((0,500,1000),(0,3,6),(
+    /// 3.0,4.2,5.5;
+    /// 4.2,5.0,6.0;
+    /// 5.0,5.8,6.5));
+    /// let (injector_time, corners) = LOOKUP_TABLE.lookup_with_corners(&750i16, &3i8);
+    /// assert_eq!(injector_time, 5.5f32);
+    /// assert_eq!(corners, [(1, 1, 0.5), (2, 1, 0.5), (1, 1, 0.0), (2, 1, 0.0)]);
+    /// # }
+    /// ```
+    pub fn lookup_with_corners<Y: Copy, Z: Copy>(&self, breakpoint_h: &Y, breakpoint_v: &Z) -> (U, [(usize, usize, f64); 4])
+    where S: From<Y> + From<i8> + Into<f64>, T: From<Z> + From<i8> + Into<f64>, U: From<i8>{
+        let calc_breakpoint_h = S::from(*breakpoint_h);
+        let calc_breakpoint_v = T::from(*breakpoint_v);
+        let indexes_h = self.indexes_h(calc_breakpoint_h, &Interpolation::Linear);
+        let indexes_v = self.indexes_v(calc_breakpoint_v, &Interpolation::Linear);
+        let [(h_low, weight_h_low), (h_high, weight_h_high)] = axis_weights(&self.breakpoints_h, indexes_h, calc_breakpoint_h);
+        let [(v_low, weight_v_low), (v_high, weight_v_high)] = axis_weights(&self.breakpoints_v, indexes_v, calc_breakpoint_v);
+        let corners = [
+            (h_low, v_low, weight_h_low * weight_v_low),
+            (h_high, v_low, weight_h_high * weight_v_low),
+            (h_low, v_high, weight_h_low * weight_v_high),
+            (h_high, v_high, weight_h_high * weight_v_high),
+        ];
+        (self.interpolate(indexes_h, indexes_v, calc_breakpoint_h, calc_breakpoint_v), corners)
+    }
+
+    /// Returns the horizontal breakpoints that bracket the entered horizontal breakpoint and the linear blend factor between them, as used by `lookup` with `Interpolation::Linear`.\
+    /// The result is `(index, index below, fraction)`, where `fraction` is the weight of the breakpoint at `index` and `1.0 - fraction` the weight of the breakpoint below it.
+    /// On a breakpoint or out of range there is nothing to blend, the index below is `None` and the fraction is 1.0.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `breakpoint_h` - A reference to the horizontal breakpoint to bracket
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::TwoDLookup;
+    /// const LOOKUP_TABLE: TwoDLookup<i16,i8,f32,3,3> = create_2d_lookup!((0,500,1000),(0,3,6),(
+    /// 3.0,4.2,5.5;
+    /// 4.2,5.0,6.0;
+    /// 5.0,5.8,6.5));
+    /// assert_eq!(LOOKUP_TABLE.h_bracket(&750i16), (2, Some(1), 0.5));
+    /// # }
+    /// ```
+    pub fn h_bracket<Y: Copy>(&self, breakpoint_h: &Y) -> (usize, Option<usize>, f64)
+    where S: From<Y> + From<i8> + Into<f64>{
+        let calc_breakpoint_h = S::from(*breakpoint_h);
+        axis_bracket(&self.breakpoints_h, self.indexes_h(calc_breakpoint_h, &Interpolation::Linear), calc_breakpoint_h)
+    }
+
+    /// Returns the vertical breakpoints that bracket the entered vertical breakpoint and the linear blend factor between them, as used by `lookup` with `Interpolation::Linear`.\
+    /// The result is `(index, index below, fraction)`, where `fraction` is the weight of the breakpoint at `index` and `1.0 - fraction` the weight of the breakpoint below it.
+    /// On a breakpoint or out of range there is nothing to blend, the index below is `None` and the fraction is 1.0.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `breakpoint_v` - A reference to the vertical breakpoint to bracket
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::TwoDLookup;
+    /// const LOOKUP_TABLE: TwoDLookup<i16,i8,f32,3,3> = create_2d_lookup!((0,500,1000),(0,3,6),(
+    /// 3.0,4.2,5.5;
+    /// 4.2,5.0,6.0;
+    /// 5.0,5.8,6.5));
+    /// assert_eq!(LOOKUP_TABLE.v_bracket(&4i8), (2, Some(1), 1.0 / 3.0));
+    /// # }
+    /// ```
+    pub fn v_bracket<Z: Copy>(&self, breakpoint_v: &Z) -> (usize, Option<usize>, f64)
+    where T: From<Z> + From<i8> + Into<f64>{
+        let calc_breakpoint_v = T::from(*breakpoint_v);
+        axis_bracket(&self.breakpoints_v, self.indexes_v(calc_breakpoint_v, &Interpolation::Linear), calc_breakpoint_v)
+    }
+
+    /// Returns the inverse distance weighted average of the four corners of the cell that the entered breakpoints fall in.\
+    /// Each corner is weighted by one over its squared distance to the entered breakpoints, measured in fractions of the cell on both axes,
+    /// so unlike the separable bilinear weights of `lookup` the weights depend on the straight line distance. At the center of a cell both are the plain average.
+    /// A breakpoint on a knot returns the value of that knot, on a gridline or out of range only the corners on that gridline or edge are averaged.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `breakpoint_h` - A reference to the horizontal breakpoint for which a value must be found by the lookup table
+    /// * `breakpoint_v` - A reference to the vertical breakpoint for which a value must be found by the lookup table
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::TwoDLookup;
+    /// const LOOKUP_TABLE: TwoDLookup<i16,i8,f32,2,2> = create_2d_lookup!((0,100),(0,10),(
+    /// 0.0,10.0;
+    /// 10.0,20.0));
+    /// assert_eq!(LOOKUP_TABLE.lookup_nearest_four(&50i16, &5i8), 10.0);
+    /// assert_eq!(LOOKUP_TABLE.lookup_nearest_four(&100i16, &10i8), 20.0);
+    /// # }
+    /// ```
+    pub fn lookup_nearest_four<Y: Copy, Z: Copy>(&self, breakpoint_h: &Y, breakpoint_v: &Z) -> U
+    where S: From<Y> + From<i8> + Into<f64>, T: From<Z> + From<i8> + Into<f64>, U: Into<f64> + FromF64{
+        let calc_breakpoint_h = S::from(*breakpoint_h);
+        let calc_breakpoint_v = T::from(*breakpoint_v);
+        let indexes_h = self.indexes_h(calc_breakpoint_h, &Interpolation::Linear);
+        let indexes_v = self.indexes_v(calc_breakpoint_v, &Interpolation::Linear);
+        let [(h_low, _), (h_high, fraction_h)] = axis_weights(&self.breakpoints_h, indexes_h, calc_breakpoint_h);
+        let [(v_low, _), (v_high, fraction_v)] = axis_weights(&self.breakpoints_v, indexes_v, calc_breakpoint_v);
+        //without a segment on an axis both corners are the same knot, so it is only counted once
+        let corners_h = if h_low == h_high { &[(h_low, 0.0)][..] } else { &[(h_low, 0.0), (h_high, 1.0)][..] };
+        let corners_v = if v_low == v_high { &[(v_low, 0.0)][..] } else { &[(v_low, 0.0), (v_high, 1.0)][..] };
+        let mut weighted_sum = 0.0;
+        let mut weight_sum = 0.0;
+        for &(v, position_v) in corners_v {
+            for &(h, position_h) in corners_h {
+                let distance_squared: f64 = (fraction_h - position_h) * (fraction_h - position_h) + (fraction_v - position_v) * (fraction_v - position_v);
+                if distance_squared == 0.0 {
+                    return self.values[v][h];
+                }
+                weighted_sum += self.values[v][h].into() / distance_squared;
+                weight_sum += 1.0 / distance_squared;
+            }
+        }
+        U::from_f64(weighted_sum / weight_sum)
+    }
+
+    /// Returns a (interpolated) value from the lookup table that matches the entered breakpoints, like `lookup`,
+    /// but every subtraction, multiplication and division is checked. Instead of wrapping or panicking this returns
+    /// `LookupError::ArithmeticOverflow`, so the result is either correct or an error.
+    /// `Interpolation::Cubic` falls back to linear interpolation, like it does for `lookup`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `breakpoint_h` - A reference to the horizontal breakpoint for which a value must be found by the lookup table
+    /// * `breakpoint_v` - A reference to the vertical breakpoint for which a value must be found by the lookup table
+    /// * `interpolation` - The interpolation method to use for this lookup operation
+    pub fn lookup_checked<Y: Copy, Z: Copy>(&self, breakpoint_h: &Y, breakpoint_v: &Z, interpolation: Interpolation) -> Result<U, LookupError>
+    where S: From<Y> + CheckedArithmetic + AsF64, T: From<Z> + CheckedArithmetic + AsF64, U: CheckedArithmetic + F64Components{
+        let calc_breakpoint_h = S::from(*breakpoint_h);
+        let calc_breakpoint_v = T::from(*breakpoint_v);
+        let blend_h = |row: usize, indexes_h: (usize,Option<usize>)| match indexes_h.1 {
+            Some(index_h_low) => calc_breakpoint_h.checked_sub(self.breakpoints_h[index_h_low])
+                .zip(self.breakpoints_h[indexes_h.0].checked_sub(self.breakpoints_h[index_h_low]))
+                .and_then(|(offset, span)| checked_blend(self.values[row][index_h_low], self.values[row][indexes_h.0], offset, span, &interpolation)),
+            None => Some(self.values[row][indexes_h.0]),
+        };
+        let result = checked_indexes(&self.breakpoints_h, calc_breakpoint_h, &interpolation)
+            .zip(checked_indexes(&self.breakpoints_v, calc_breakpoint_v, &interpolation))
+            .and_then(|(indexes_h, indexes_v)| match indexes_v.1 {
+                Some(index_v_low) => blend_h(index_v_low, indexes_h)
+                    .zip(blend_h(indexes_v.0, indexes_h))
+                    .zip(calc_breakpoint_v.checked_sub(self.breakpoints_v[index_v_low]))
+                    .zip(self.breakpoints_v[indexes_v.0].checked_sub(self.breakpoints_v[index_v_low]))
+                    .and_then(|(((low, high), offset), span)| checked_blend(low, high, offset, span, &interpolation)),
+                None => blend_h(indexes_v.0, indexes_h),
+            });
+        result.ok_or(LookupError::ArithmeticOverflow)
+    }
+
+    /// Returns a row of the lookup table at a fixed vertical breakpoint, the vertical search is done once here instead of in every lookup.\
+    /// The vertical fraction is calculated here as well, so lookups on the row only blend horizontally and mix the two rows.
+    /// Like `lookup`, the row interpolates linearly for the methods that are calculated in `f64`, use `prepared_at_v_nonlinear` for those.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `breakpoint_v` - A reference to the vertical breakpoint for which the row must be prepared
+    /// * `interpolation` - The interpolation method to use for the lookup operations on the row
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{TwoDLookup, Interpolation};
+    /// const LOOKUP_TABLE: TwoDLookup<i16,i8,f32,3,3> = create_2d_lookup!((0,500,1000),(0,3,6),(
+    /// 3.0,4.2,5.5;
+    /// 4.2,5.0,6.0;
+    /// 5.0,5.8,6.5));
+    /// let row = LOOKUP_TABLE.prepared_at_v(&4i8, Interpolation::Linear);
+    /// assert_eq!(row.lookup_h(&750i16).unwrap(), 5.7166667f32)
+    /// # }
+    /// ```
+    pub fn prepared_at_v<Z: Copy>(&self, breakpoint_v: &Z, interpolation: Interpolation) -> PreparedRow<'_,S,T,U,N,M>
+    where T: From<Z> + From<i8>{
+        let calc_breakpoint_v = T::from(*breakpoint_v);
+        let indexes_v = self.indexes_v(calc_breakpoint_v, &interpolation);
+        let fraction_v = indexes_v.1.map(|index_v_low| {
+            let offset_v = calc_breakpoint_v - self.breakpoints_v[index_v_low];
+            let span_v = self.breakpoints_v[indexes_v.0] - self.breakpoints_v[index_v_low];
+            (U::from(offset_v), U::from(span_v))
+        });
+        let interpolate = |table: &TwoDLookup<S,T,U,N,M>, indexes_h, indexes_v, breakpoint_h, breakpoint_v, _: &Interpolation| table.interpolate(indexes_h, indexes_v, breakpoint_h, breakpoint_v);
+        PreparedRow { table: self, breakpoint_v: calc_breakpoint_v, indexes_v, fraction_v, interpolation, interpolate }
+    }
+
+    /// Returns a row of the lookup table at a fixed vertical breakpoint like `prepared_at_v`,
+    /// but lookups on the row also calculate the interpolation methods that are calculated in `f64`, see `lookup_nonlinear`.\
+    /// The eased vertical fraction is calculated once here.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `breakpoint_v` - A reference to the vertical breakpoint for which the row must be prepared
+    /// * `interpolation` - The interpolation method to use for the lookup operations on the row
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{TwoDLookup, Interpolation};
+    /// const LOOKUP_TABLE: TwoDLookup<i16,i8,f32,2,2> = create_2d_lookup!((0,400),(0,10),(
+    /// 0.0,100.0;
+    /// 0.0,100.0));
+    /// let row = LOOKUP_TABLE.prepared_at_v_nonlinear(&5i8, Interpolation::Smoothstep);
+    /// assert_eq!(row.lookup_h(&100i16).unwrap(), 15.625)
+    /// # }
+    /// ```
+    pub fn prepared_at_v_nonlinear<Z: Copy>(&self, breakpoint_v: &Z, interpolation: Interpolation) -> PreparedRow<'_,S,T,U,N,M>
+    where S: AsF64, T: From<Z> + From<i8> + AsF64, U: F64Components{
+        let calc_breakpoint_v = T::from(*breakpoint_v);
+        let indexes_v = self.indexes_v(calc_breakpoint_v, &interpolation);
+        let fraction_v = match (indexes_v.1, &interpolation) {
+            (_, Interpolation::AngularLinear { .. }) | (None, _) => None,
+            (Some(index_v_low), _) => {
+                let offset_v = calc_breakpoint_v - self.breakpoints_v[index_v_low];
+                let span_v = self.breakpoints_v[indexes_v.0] - self.breakpoints_v[index_v_low];
+                Some((ease(offset_v, span_v, &interpolation), U::from(span_v)))
+            }
+        };
+        PreparedRow { table: self, breakpoint_v: calc_breakpoint_v, indexes_v, fraction_v, interpolation, interpolate: TwoDLookup::interpolate_nonlinear }
+    }
+
+    /// Returns the index of the horizontal breakpoint to use and the index of the breakpoint below it if the result must be interpolated.
+    fn indexes_h(&self, calc_breakpoint_h: S, interpolation: &Interpolation) -> (usize,Option<usize>)
+    where S: From<i8>{
+        let position = match self.uniform_h {
+            Some((uniform_h, position)) => position(&self.breakpoints_h, uniform_h, calc_breakpoint_h),
+            None => self.breakpoints_h.iter().position(|bp| bp >= &calc_breakpoint_h),
+        };
+        axis_indexes_at(&self.breakpoints_h, position, calc_breakpoint_h, interpolation)
+    }
+
+    /// Returns the index of the vertical breakpoint to use and the index of the breakpoint below it if the result must be interpolated.
+    fn indexes_v(&self, calc_breakpoint_v: T, interpolation: &Interpolation) -> (usize,Option<usize>)
+    where T: From<i8>{
+        let position = match self.uniform_v {
+            Some((uniform_v, position)) => position(&self.breakpoints_v, uniform_v, calc_breakpoint_v),
+            None => self.breakpoints_v.iter().position(|bp| bp >= &calc_breakpoint_v),
+        };
+        axis_indexes_at(&self.breakpoints_v, position, calc_breakpoint_v, interpolation)
+    }
+
+    fn interpolate(&self, indexes_h: (usize,Option<usize>), indexes_v: (usize,Option<usize>), breakpoint_h: S, breakpoint_v: T) -> U {
+        interpolate_2d((&self.breakpoints_h, &self.breakpoints_v), |index_v, index_h| self.values[index_v][index_h], (indexes_h, indexes_v), (breakpoint_h, breakpoint_v),
+            |from, to, offset, span| U::lerp(from, to, U::from(offset), U::from(span)), |from, to, offset, span| U::lerp(from, to, U::from(offset), U::from(span)))
+    }
+
+    fn interpolate_nonlinear(&self, indexes_h: (usize,Option<usize>), indexes_v: (usize,Option<usize>), breakpoint_h: S, breakpoint_v: T, interpolation: &Interpolation) -> U
+    where S: AsF64, T: AsF64, U: F64Components{
+        interpolate_2d((&self.breakpoints_h, &self.breakpoints_v), |index_v, index_h| self.values[index_v][index_h], (indexes_h, indexes_v), (breakpoint_h, breakpoint_v),
+            |from, to, offset, span| blend(from, to, offset, span, interpolation), |from, to, offset, span| blend(from, to, offset, span, interpolation))
+    }
+
+    /// This method is unsafe, consider using the create_2d_lookup!() macro instead.
+    /// Returns a lookup table. Only use an ascending breakpoints vectors! for example  1,2,3,4 and not 4,3,2,1 or 1,2,3,2 \
+    /// breakpoints and values must have the same length in the horizontal and vertical direction!
+    /// 
+    /// # Arguments
+    /// 
+    /// * `breakpoints_h` - The breakpoints that act as the horizontal index for the values
+    /// * `breakpoints_v` - The breakpoints that act as the vertical index for the values
+    /// * `values` - The values that represent the result from the lookup
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use::go_lookup_tables::{TwoDLookup};
+    /// const LOOKUP_TABLE: TwoDLookup<i16,i8,f32,3,3> = TwoDLookup::new([0,500,1000],[0,3,6],[
+    /// [3.0,4.2,5.5],
+    /// [4.2,5.0,6.0],
+    /// [5.0,5.8,6.5]]); //simple 0.5V to 4.5V pressure sensor
+    /// /* full table:
+    ///     x   0   500 1000
+    ///     0   3.0 4.2 5.5
+    ///     3   4.2 5.0 6.0
+    ///     6   5.0 5.8 6.5
+    ///  */
+    /// ```
+    pub const fn new(breakpoints_h: [S;N], breakpoints_v: [T;M], values: [[U;N];M])-> TwoDLookup<S,T,U,N,M> {
+        TwoDLookup { breakpoints_h, breakpoints_v, values, uniform_h: None, uniform_v: None }
+    }
+}
+
+impl<
+S: PartialOrd + Sub + Add + Div + Copy + Clone,
+T: PartialOrd + Sub + Add + Div + Copy + Clone,
+U: Add + Sub + Div + Mul + Copy + Clone,
+const N: usize,
+const M: usize,
+>TwoDLookup<S,T,U,N,M> {
+    /// Returns the lookup table as an `f64` surface, ready to be handed to a plotting library.\
+    /// The tuple contains the horizontal breakpoints, the vertical breakpoints and the values matrix (one row per vertical breakpoint).
+    #[cfg(feature = "std")]
+    pub fn to_surface(&self) -> (Vec<f64>, Vec<f64>, Vec<Vec<f64>>)
+    where S: Into<f64>, T: Into<f64>, U: Into<f64>{
+        (
+            self.breakpoints_h.iter().map(|bp| (*bp).into()).collect(),
+            self.breakpoints_v.iter().map(|bp| (*bp).into()).collect(),
+            self.values.iter().map(|row| row.iter().map(|value| (*value).into()).collect()).collect(),
+        )
+    }
+
+    /// Returns the `create_2d_lookup!` invocation that recreates this lookup table, for generating Rust source from calibration data.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::TwoDLookup;
+    /// const LOOKUP_TABLE: TwoDLookup<i16,i16,f32,2,2> = create_2d_lookup!((0,10),(0,10),(
+    /// 1.0,2.0;
+    /// 3.0,4.0));
+    /// assert_eq!(LOOKUP_TABLE.to_macro_source(), "create_2d_lookup!((0, 10), (0, 10), (1.0, 2.0; 3.0, 4.0))");
+    /// # }
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn to_macro_source(&self) -> String
+    where S: fmt::Debug, T: fmt::Debug, U: fmt::Debug{
+        let rows: Vec<String> = self.values.iter().map(|row| join_debug(row)).collect();
+        format!("create_2d_lookup!(({}), ({}), ({}))", join_debug(&self.breakpoints_h), join_debug(&self.breakpoints_v), rows.join("; "))
+    }
+
+    /// Returns a 1-D lookup table of the main diagonal of the values matrix, using the horizontal breakpoints as its breakpoints.
+    /// Only square lookup tables have a main diagonal, so this returns an error if `N != M`.
+    /// 
+    /// # Panics
+    /// 
+    /// `diagonal` fails to compile if the lookup table has less than 2 breakpoints per axis.
+    /// 
+    /// ```compile_fail
+    /// use::go_lookup_tables::{TwoDLookup, OneDLookup};
+    /// let lookup_table: TwoDLookup<i16,i16,f32,1,1> = TwoDLookup::new([0], [0], [[1.0]]);
+    /// let diagonal: OneDLookup<i16,f32,1> = lookup_table.diagonal().unwrap();
+    /// ```
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{TwoDLookup, OneDLookup, Extrapolation, Interpolation};
+    /// const LOOKUP_TABLE: TwoDLookup<i16,i16,f32,2,2> = create_2d_lookup!((0,10),(0,10),(
+    /// 1.0,2.0;
+    /// 3.0,4.0));
+    /// let diagonal: OneDLookup<i16,f32,2> = LOOKUP_TABLE.diagonal().unwrap();
+    /// assert_eq!(diagonal.lookup(&5i16, Extrapolation::NoneError, Interpolation::Linear).unwrap(), 2.5f32);
+    /// # }
+    /// ```
+    pub fn diagonal(&self) -> Result<OneDLookup<S,U,N>, LookupBuildError>
+    where S: Sub<Output = S>, U: EdgeDelta{
+        if N != M {
+            return Err(LookupBuildError::ShapeMismatch { expected: N, found: M });
+        }
+        let values: [U;N] = core::array::from_fn(|i| self.values[i][i]);
+        Ok(OneDLookup::with_cached_diffs(self.breakpoints_h, values))
+    }
+
+    /// Returns the lookup table with its horizontal and vertical axes swapped and the values matrix transposed,
+    /// for maps that were delivered with the opposite axis convention.\
+    /// Looking up `(v, h)` in the transposed lookup table gives the same result as looking up `(h, v)` in this one.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{TwoDLookup, Interpolation};
+    /// const LOOKUP_TABLE: TwoDLookup<i16,i8,f32,3,2> = create_2d_lookup!((0,500,1000),(0,6),(
+    /// 3.0,4.2,5.5;
+    /// 5.0,5.8,6.5));
+    /// let transposed: TwoDLookup<i8,i16,f32,2,3> = LOOKUP_TABLE.transpose();
+    /// assert_eq!(transposed.lookup(&3i8, &250i16, Interpolation::Linear).unwrap(), LOOKUP_TABLE.lookup(&250i16, &3i8, Interpolation::Linear).unwrap());
+    /// # }
+    /// ```
+    pub fn transpose(&self) -> TwoDLookup<T,S,U,M,N> {
+        let values: [[U;M];N] = core::array::from_fn(|h| core::array::from_fn(|v| self.values[v][h]));
+        TwoDLookup { breakpoints_h: self.breakpoints_v, breakpoints_v: self.breakpoints_h, values, uniform_h: self.uniform_v, uniform_v: self.uniform_h }
+    }
+
+    /// Returns true if both entered breakpoints lie within the range of their axis, so they can be looked up without holding the extreme values.\
+    /// NaN breakpoints are never contained.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::TwoDLookup;
+    /// const LOOKUP_TABLE: TwoDLookup<i16,i8,f32,2,2> = create_2d_lookup!((0,1000),(0,6),(
+    /// 3.0,5.5;
+    /// 5.0,6.5));
+    /// assert!(LOOKUP_TABLE.contains(&1000i16, &0i8));
+    /// assert!(!LOOKUP_TABLE.contains(&1000i16, &7i8));
+    /// # }
+    /// ```
+    pub fn contains<Y: Copy, Z: Copy>(&self, breakpoint_h: &Y, breakpoint_v: &Z) -> bool
+    where S: From<Y>, T: From<Z>{
+        let (calc_breakpoint_h, calc_breakpoint_v) = (S::from(*breakpoint_h), T::from(*breakpoint_v));
+        calc_breakpoint_h >= self.breakpoints_h[0] && calc_breakpoint_h <= self.breakpoints_h[N-1]
+            && calc_breakpoint_v >= self.breakpoints_v[0] && calc_breakpoint_v <= self.breakpoints_v[M-1]
+    }
+
+    /// Returns a lookup table built from breakpoints and values that are only known at runtime.\
+    /// This is the checked counterpart of `new`, `try_create_2d_lookup!` expands to it.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns `LookupBuildError::NotAscending` if either of the breakpoint arrays isn't in ascending order.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use::go_lookup_tables::{TwoDLookup, LookupBuildError};
+    /// let result: Result<TwoDLookup<i16,i8,f32,2,2>, _> = TwoDLookup::try_new([0,500], [3,0], [[3.0,4.2],[4.2,5.0]]);
+    /// assert_eq!(result.err(), Some(LookupBuildError::NotAscending { index: 1 }));
+    /// ```
+    pub fn try_new(breakpoints_h: [S;N], breakpoints_v: [T;M], values: [[U;N];M]) -> Result<TwoDLookup<S,T,U,N,M>, LookupBuildError> {
+        check_ascending(&breakpoints_h)?;
+        check_ascending(&breakpoints_v)?;
+        Ok(TwoDLookup { breakpoints_h, breakpoints_v, values, uniform_h: None, uniform_v: None })
+    }
+
+    /// Returns a lookup table built from rows of values that are only known at runtime, for example parsed from a file.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `breakpoints_h` - The breakpoints that act as the horizontal index for the values
+    /// * `breakpoints_v` - The breakpoints that act as the vertical index for the values
+    /// * `rows` - The rows of values, one row per vertical breakpoint with one value per horizontal breakpoint
+    /// 
+    /// # Errors
+    /// 
+    /// Returns `LookupBuildError::ShapeMismatch` if there aren't exactly `M` rows of exactly `N` values
+    /// and `LookupBuildError::NotAscending` if either of the breakpoint arrays isn't in ascending order.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use::go_lookup_tables::{TwoDLookup, LookupBuildError};
+    /// let rows = vec![vec![3.0, 4.2, 5.5], vec![4.2, 5.0]];
+    /// let result: Result<TwoDLookup<i16,i8,f32,3,2>, _> = TwoDLookup::try_from_rows([0,500,1000], [0,3], &rows);
+    /// assert_eq!(result.err(), Some(LookupBuildError::ShapeMismatch { expected: 3, found: 2 }));
+    /// ```
+    pub fn try_from_rows<R: AsRef<[U]>>(breakpoints_h: [S;N], breakpoints_v: [T;M], rows: &[R]) -> Result<TwoDLookup<S,T,U,N,M>, LookupBuildError> {
+        if rows.len() != M {
+            return Err(LookupBuildError::ShapeMismatch { expected: M, found: rows.len() });
+        }
+        if let Some(row) = rows.iter().find(|row| row.as_ref().len() != N) {
+            return Err(LookupBuildError::ShapeMismatch { expected: N, found: row.as_ref().len() });
+        }
+        check_ascending(&breakpoints_h)?;
+        check_ascending(&breakpoints_v)?;
+        let values: [[U;N];M] = core::array::from_fn(|v| core::array::from_fn(|h| rows[v].as_ref()[h]));
+        Ok(TwoDLookup { breakpoints_h, breakpoints_v, values, uniform_h: None, uniform_v: None })
+    }
+
+    /// Returns a lookup table built by stacking 1-D lookup tables as its rows, for maps that are calibrated row by row.\
+    /// The horizontal breakpoints are taken from the rows, so they must be identical for every row.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `breakpoints_v` - The breakpoints that act as the vertical index for the rows
+    /// * `rows` - The 1-D lookup tables that make up the rows, one per vertical breakpoint
+    /// 
+    /// # Errors
+    /// 
+    /// Returns `LookupBuildError::BreakpointMismatch` if the breakpoints of a row differ from those of the first row,
+    /// `LookupBuildError::NotAscending` if the vertical breakpoints aren't in ascending order
+    /// and `LookupBuildError::ShapeMismatch` if there are no rows at all.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
+    /// use::go_lookup_tables::{OneDLookup, TwoDLookup, Interpolation};
+    /// const ROW_0: OneDLookup<i16,f32,3> = create_1d_lookup!((0,500,1000), (3.0,4.2,5.5));
+    /// const ROW_3: OneDLookup<i16,f32,3> = create_1d_lookup!((0,500,1000), (4.2,5.0,6.0));
+    /// let lookup_table: TwoDLookup<i16,i8,f32,3,2> = TwoDLookup::from_rows([0,3], [ROW_0, ROW_3]).unwrap();
+    /// assert_eq!(lookup_table.lookup(&500i16, &3i8, Interpolation::Linear).unwrap(), 5.0);
+    /// # }
+    /// ```
+    pub fn from_rows(breakpoints_v: [T;M], rows: [OneDLookup<S,U,N>;M]) -> Result<TwoDLookup<S,T,U,N,M>, LookupBuildError> {
+        let breakpoints_h = match rows.first() {
+            Some(row) => row.breakpoints,
+            None => return Err(LookupBuildError::ShapeMismatch { expected: 1, found: 0 }),
+        };
+        if let Some(row) = rows.iter().position(|row| row.breakpoints != breakpoints_h) {
+            return Err(LookupBuildError::BreakpointMismatch { row });
+        }
+        check_ascending(&breakpoints_v)?;
+        Ok(TwoDLookup { breakpoints_h, breakpoints_v, values: rows.map(|row| row.values), uniform_h: None, uniform_v: None })
+    }
+
+    /// Returns a lookup table built from a flat slice of values in row-major order, see `try_from_rows`.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use::go_lookup_tables::TwoDLookup;
+    /// let values = [3.0, 4.2, 5.5, 4.2, 5.0, 6.0];
+    /// let lookup_table: TwoDLookup<i16,i8,f32,3,2> = TwoDLookup::try_from_flat([0,500,1000], [0,3], &values).unwrap();
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn try_from_flat(breakpoints_h: [S;N], breakpoints_v: [T;M], values: &[U]) -> Result<TwoDLookup<S,T,U,N,M>, LookupBuildError> {
+        let rows: Vec<&[U]> = values.chunks(N.max(1)).collect();
+        TwoDLookup::try_from_rows(breakpoints_h, breakpoints_v, &rows)
+    }
 
-            let mut i = 1;
-            while i < breakpoints.len() {
-                if breakpoints[i - 1] > breakpoints[i] {
-                    panic!("breakpoints aren't sorted, they should be in ascending order");
-                }
-                i += 1;
+    /// Returns a lookup table with evenly spaced breakpoints on both axes, derived from a start and a step.\
+    /// The start and step are stored with the table, so lookups calculate the index of a breakpoint instead of searching the axis.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `h_start` - The first horizontal breakpoint
+    /// * `h_step` - The delta between two horizontal breakpoints, must be positive
+    /// * `v_start` - The first vertical breakpoint
+    /// * `v_step` - The delta between two vertical breakpoints, must be positive
+    /// * `values` - The values that represent the result from the lookup
+    /// 
+    /// # Panics
+    /// 
+    /// `from_uniform` panics if the derived breakpoints aren't ascending, which happens when a step isn't positive or a breakpoint overflows.\
+    /// Use `create_uniform_2d_lookup!` to build a `const` table, which derives the breakpoints at compile time.
+    /// 
+    /// # Examples
+    /// 
+    /// ```
+    /// use::go_lookup_tables::{TwoDLookup};
+    /// let lookup_table: TwoDLookup<i16,i8,f32,3,3> = TwoDLookup::from_uniform(0, 500, 0, 3, [
+    /// [3.0,4.2,5.5],
+    /// [4.2,5.0,6.0],
+    /// [5.0,5.8,6.5]]); //breakpoints (0,500,1000) and (0,3,6)
+    /// ```
+    pub fn from_uniform(h_start: S, h_step: S, v_start: T, v_step: T, values: [[U;N];M]) -> TwoDLookup<S,T,U,N,M>
+    where S: Add<Output = S> + Sub<Output = S> + AsF64, T: Add<Output = T> + Sub<Output = T> + AsF64{
+        let mut breakpoints_h = [h_start;N];
+        for i in 1..N {
+            breakpoints_h[i] = breakpoints_h[i-1] + h_step;
+            if breakpoints_h[i-1] >= breakpoints_h[i] {
+                panic!("horizontal breakpoints aren't ascending, the step must be positive and the breakpoints may not overflow");
             }
-        };
-        let lookup = OneDLookup::new(
-            [$($bps),+],
-            [$($vals),+],
-            [ $($bps,)* ][[ $($bps,)* ].len()-1] - [ $($bps,)* ][[ $($bps,)* ].len()-2],
-            [ $($vals,)* ][[ $($vals,)* ].len()-1] - [ $($vals,)* ][[ $($vals,)* ].len()-2],
-            [ $($bps,)* ][1] - [ $($bps,)* ][0],
-            [ $($vals,)* ][1] - [ $($vals,)* ][0],
-		);
-        lookup
-    }};
+        }
+        let mut breakpoints_v = [v_start;M];
+        for i in 1..M {
+            breakpoints_v[i] = breakpoints_v[i-1] + v_step;
+            if breakpoints_v[i-1] >= breakpoints_v[i] {
+                panic!("vertical breakpoints aren't ascending, the step must be positive and the breakpoints may not overflow");
+            }
+        }
+        TwoDLookup::from_uniform_parts(breakpoints_h, breakpoints_v, (h_start, h_step), (v_start, v_step), values)
+    }
+
+    /// Returns a lookup table with evenly spaced breakpoints that were already derived from their start and step, used by `create_uniform_2d_lookup!`.\
+    /// The breakpoints aren't checked, they must match the start and step or lookups return wrong values.
+    #[doc(hidden)]
+    pub const fn from_uniform_parts(breakpoints_h: [S;N], breakpoints_v: [T;M], uniform_h: (S,S), uniform_v: (T,T), values: [[U;N];M]) -> TwoDLookup<S,T,U,N,M>
+    where S: Sub<Output = S> + AsF64, T: Sub<Output = T> + AsF64{
+        TwoDLookup { breakpoints_h, breakpoints_v, values, uniform_h: Some((uniform_h, uniform_position::<S>)), uniform_v: Some((uniform_v, uniform_position::<T>)) }
+    }
 }
 
-/// A struct representing a 2-D lookup table, breakpoints must be an ascending vectors! 1,2,3,4 and not 4,3,2,1 or 1,2,3,2
-/// 
-/// example:
-/// /*
-///     x   0   500 1000
-///     0   3.0 4.2 5.5
-///     3   4.2 5.0 6.0
-///     6   5.0 5.8 6.5
-/// */
-pub struct TwoDLookup<
-S: PartialOrd + Sub + Add + Div + Copy + Clone,
-T: PartialOrd + Sub + Add + Div + Copy + Clone,
-U: Add + Sub + Div + Mul + Copy + Clone,
+/// A row of a 2-D lookup table at a fixed vertical breakpoint, created by `TwoDLookup::prepared_at_v`.
+pub struct PreparedRow<'a, S, T, U, const N: usize, const M: usize>{
+    /// The lookup table the row belongs to.
+    table: &'a TwoDLookup<S,T,U,N,M>,
+    /// The vertical breakpoint of the row.
+    breakpoint_v: T,
+    /// The vertical indexes found for the vertical breakpoint.
+    indexes_v: (usize,Option<usize>),
+    /// The (eased) vertical offset and the vertical span as values, `None` if the row isn't blended vertically or is blended along an arc.
+    fraction_v: Option<(U,U)>,
+    /// The interpolation method to use for the lookup operations.
+    interpolation: Interpolation,
+    /// Interpolates the table at the entered indexes and breakpoints, chosen by `prepared_at_v` or `prepared_at_v_nonlinear`.
+    interpolate: RowInterpolation<S,T,U,N,M>,
+}
+
+/// Interpolates a 2-D lookup table at the entered horizontal and vertical indexes and breakpoints, with the entered interpolation method.
+type RowInterpolation<S, T, U, const N: usize, const M: usize> = fn(&TwoDLookup<S,T,U,N,M>, (usize,Option<usize>), (usize,Option<usize>), S, T, &Interpolation) -> U;
+
+impl<
+S: PartialOrd + Add + Copy + Clone + Sub<Output = S> + Div<Output =S>, 
+T: PartialOrd + Add + Copy + Clone + Sub<Output = T> + Div<Output = T>, 
+U: Sub<Output = U>  + Add<Output = U> + Copy + Clone + From<T> + From<S> + Mul<Output = U> + Div<Output = U> + Neg<Output = U> + EdgeDelta,
 const N: usize,
-const M: usize>{
+const M: usize,
+>PreparedRow<'_,S,T,U,N,M> {
+    /// Returns a (interpolated) value from the row that matches the entered horizontal breakpoint.
+    /// This gives the same result as `TwoDLookup::lookup`, or `TwoDLookup::lookup_nonlinear` for a row from `prepared_at_v_nonlinear`, with the vertical breakpoint of the row.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `breakpoint_h` - A reference to the horizontal breakpoint for which a value must be found by the lookup table
+    pub fn lookup_h<Y: Copy>(&self, breakpoint_h: &Y) -> Result<U, Infallible>
+    where S: From<Y> + From<i8>{
+        let calc_breakpoint_h = S::from(*breakpoint_h);
+        let indexes_h = self.table.indexes_h(calc_breakpoint_h, &self.interpolation);
+        match (self.indexes_v.1, self.fraction_v) {
+            (Some(index_v_low), Some((offset_v, span_v))) => {
+                let row = |index_v| (self.interpolate)(self.table, indexes_h, (index_v, None), calc_breakpoint_h, self.breakpoint_v, &self.interpolation);
+                Ok(U::lerp(row(index_v_low), row(self.indexes_v.0), offset_v, span_v))
+            },
+            _ => Ok((self.interpolate)(self.table, indexes_h, self.indexes_v, calc_breakpoint_h, self.breakpoint_v, &self.interpolation)),
+        }
+    }
+}
+
+/// A struct representing a 2-D lookup table whose dimensions are only known at runtime, for example for maps loaded from files.\
+/// The values are stored in a flat `Vec` in row-major order, a row holds the values of one vertical breakpoint.
+/// Lookups behave exactly like `TwoDLookup::lookup`.
+#[cfg(feature = "std")]
+pub struct DynamicTwoDLookup<S, T, U>{
     ///The horizontal breakpoints
-    breakpoints_h: [S;N],
+    breakpoints_h: Vec<S>,
     ///The vertical breakpoints
-    breakpoints_v: [T;M],
-    ///The values matrix
-    values:        [[U;N];M],
+    breakpoints_v: Vec<T>,
+    ///The values in row-major order
+    values:        Vec<U>,
 }
 
+#[cfg(feature = "std")]
 impl<
 S: PartialOrd + Add + Copy + Clone + Sub<Output = S> + Div<Output =S>, 
 T: PartialOrd + Add + Copy + Clone + Sub<Output = T> + Div<Output = T>, 
-U: Sub<Output = U>  + Add<Output = U> + Copy + Clone + From<T> + From<S> + Mul<Output = U> + Div<Output = U> + Neg<Output = U>,
-const N: usize,
-const M: usize,
->TwoDLookup<S,T,U,N,M> {
-    /// Returns a (interpolated) value from the lookup table that matches the entered breakpoints.
+U: Sub<Output = U>  + Add<Output = U> + Copy + Clone + From<T> + From<S> + Mul<Output = U> + Div<Output = U> + EdgeDelta,
+>
+DynamicTwoDLookup<S,T,U>{
+    /// Returns a (interpolated) value from the lookup table that matches the entered breakpoints, see `TwoDLookup::lookup`.
     /// 
     /// # Arguments
     /// 
@@ -263,174 +5364,137 @@ const M: usize,
     /// # Examples
     /// 
     /// ```
-    /// # #[macro_use] extern crate go_lookup_tables; fn main() {
-    /// use::go_lookup_tables::{TwoDLookup, Interpolation};
-    /// let rpm = 750i16;
-    /// let throttle_pos = 4;
-    /// const LOOKUP_TABLE: TwoDLookup<i16,i8,f32,3,3> = create_2d_lookup!((0,500,1000),(0,3,6),(
-    /// 3.0,4.2,5.5;
-    /// 4.2,5.0,6.0;
-    /// 5.0,5.8,6.5)); //only a small part of an actual injector table
-    /// /* full table:
-    ///     x   0   500 1000
-    ///     0   3.0 4.2 5.5
-    ///     3   4.2 5.0 6.0
-    ///     6   5.0 5.8 6.5
-    ///  */
-    /// let injector_time = LOOKUP_TABLE.lookup(&rpm, &throttle_pos, Interpolation::Linear).unwrap();
-    /// assert_eq!(injector_time, 5.7166667f32)
-    /// # }
+    /// use::go_lookup_tables::{DynamicTwoDLookup, Interpolation};
+    /// let lookup_table = DynamicTwoDLookup::try_new(vec![0i16,500,1000], vec![0i8,3,6], vec![
+    /// 3.0f32,4.2,5.5,
+    /// 4.2,5.0,6.0,
+    /// 5.0,5.8,6.5]).unwrap();
+    /// assert_eq!(lookup_table.lookup(&750i16, &4i8, Interpolation::Linear).unwrap(), 5.7166667f32);
     /// ```
     pub fn lookup<Y: Copy, Z: Copy>(&self, breakpoint_h: &Y, breakpoint_v: &Z, interpolation: Interpolation) -> Result<U, Infallible>
-    where S: From<Y> + From<i8>, T: From<Z> + From<i8>, U: From<i8>{
+    where S: From<Y>, T: From<Z>{
         let calc_breakpoint_h = S::from(*breakpoint_h);
         let calc_breakpoint_v = T::from(*breakpoint_v);
-        //get horizontal index which will be used to generate an intermediary array of values
-        let indexes_h = match self.breakpoints_h.iter().position(|bp| bp >= &calc_breakpoint_h) {
-            Some(index) => {
-                //easy exit if bp matches existing bp
-                if self.breakpoints_h[index] == calc_breakpoint_h {
-                    (index,None)
-                //horizontal interpolation zone
-                } else if index != 0 {
-                    match interpolation {
-                        Interpolation::Linear => {
-                            (index, Some(index -1))
-                        },
-                        Interpolation::NoneCeiling => (index,None),
-                        Interpolation::NoneFloor => (index-1,None),
-                        Interpolation::NoneClosest => {
-                            let interpolated_diff_bp_h = calc_breakpoint_h - self.breakpoints_h[index -1];
-                            let diff_actual_bp_h = self.breakpoints_h[index] - self.breakpoints_h[index-1];
-                            let diff_factor_h = diff_actual_bp_h - interpolated_diff_bp_h;
-                            let round: usize = if diff_factor_h > (diff_actual_bp_h/S::from(2))
-                                {
-                                0
-                            } else {
-                                1
-                            };
-                            (index-1+round,None)
-                        }
-                    }
-                } else {
-                    //low end out of bounds horizontal
-                    (0,None)
-                }
-            },
-            //high end out of bounds horizontal
-            None => (self.breakpoints_h.len()-1,None)
-        };
-        //get the vertical index and calculate the value
-        match self.breakpoints_v.iter().position(|bp| bp >= &calc_breakpoint_v) {
-            Some(index) => {
-                //easy exit if bp matches existing bp
-                if self.breakpoints_v[index] == calc_breakpoint_v {
-                    match interpolation {
-                        Interpolation::Linear => {
-                            Ok(self.interpolate(indexes_h, (index,None), calc_breakpoint_h, calc_breakpoint_v))
-                        },
-                        Interpolation::NoneCeiling | Interpolation::NoneFloor | Interpolation::NoneClosest => Ok(self.values[index][indexes_h.0]),
-                    }
-                //vertical interpolation zone
-                } else if index != 0 {
-                    match interpolation {
-                        Interpolation::Linear => {
-                            Ok(self.interpolate(indexes_h, (index,Some(index-1)), calc_breakpoint_h, calc_breakpoint_v))
-                        },
-                        Interpolation::NoneCeiling => Ok(self.values[index][indexes_h.0]),
-                        Interpolation::NoneFloor => Ok(self.values[index-1][indexes_h.0]),
-                        Interpolation::NoneClosest => {
-                            let interpolated_diff_bp_v = calc_breakpoint_v - self.breakpoints_v[index -1];
-                            let diff_actual_bp_v = self.breakpoints_v[index] - self.breakpoints_v[index-1];
-                            let diff_factor_v = diff_actual_bp_v - interpolated_diff_bp_v;
-                            let round: usize = if diff_factor_v > (diff_actual_bp_v/T::from(2))
-                                {
-                                0
-                            } else {
-                                1
-                            };
-                            Ok(self.values[index-1 + round][indexes_h.0])
-                        }
-                    }
-                } else {
-                    //low end out of bounds vertical
-                    match interpolation {                
-                        Interpolation::Linear => {
-                            Ok(self.interpolate(indexes_h, (0,None), calc_breakpoint_h, calc_breakpoint_v))
-                        },
-                        Interpolation::NoneCeiling | Interpolation::NoneFloor | Interpolation::NoneClosest => Ok(self.values[0][indexes_h.0]),
-                    }
-                }
-            },
-            //high end out of bounds vertical
-            None => {
-                match interpolation {                
-                    Interpolation::Linear => {
-                        Ok(self.interpolate(indexes_h, (self.breakpoints_v.len()-1,None), calc_breakpoint_h, calc_breakpoint_v))
-                    },
-                    Interpolation::NoneCeiling | Interpolation::NoneFloor | Interpolation::NoneClosest => Ok(self.values[self.values.len()-1][indexes_h.0]),
-                }
-            }
-        }
+        let indexes_h = axis_indexes(&self.breakpoints_h, calc_breakpoint_h, &interpolation);
+        let indexes_v = axis_indexes(&self.breakpoints_v, calc_breakpoint_v, &interpolation);
+        let stride = self.breakpoints_h.len();
+        Ok(interpolate_2d((&self.breakpoints_h, &self.breakpoints_v), |index_v, index_h| self.values[index_v * stride + index_h],
+            (indexes_h, indexes_v), (calc_breakpoint_h, calc_breakpoint_v),
+            |from, to, offset, span| U::lerp(from, to, U::from(offset), U::from(span)), |from, to, offset, span| U::lerp(from, to, U::from(offset), U::from(span))))
     }
 
-    fn interpolate(&self, indexes_h: (usize,Option<usize>), indexes_v: (usize,Option<usize>), breakpoint_h: S, breakpoint_v: T) -> U {
-        let intermediary_values = if indexes_h.1.is_some() && indexes_v.1.is_some() {
-            let interpolated_diff_bp_h = breakpoint_h - self.breakpoints_h[indexes_h.1.unwrap()];
-            let diff_actual_bp_h = self.breakpoints_h[indexes_h.0] - self.breakpoints_h[indexes_h.1.unwrap()];
-            let diff_values_l = self.values[indexes_v.1.unwrap()][indexes_h.0] - self.values[indexes_v.1.unwrap()][indexes_h.1.unwrap()];
-            let diff_values_h = self.values[indexes_v.0][indexes_h.0] - self.values[indexes_v.0][indexes_h.1.unwrap()];
-
-            [
-                (U::from(interpolated_diff_bp_h) * diff_values_l) / U::from(diff_actual_bp_h) + self.values[indexes_v.1.unwrap()][indexes_h.1.unwrap()],
-                (U::from(interpolated_diff_bp_h) * diff_values_h) / U::from(diff_actual_bp_h) + self.values[indexes_v.0][indexes_h.1.unwrap()]
-            ]
-        } else if indexes_h.1.is_none() && indexes_v.1.is_none() {
-            return self.values[indexes_v.0][indexes_h.0]
-        } else if indexes_h.1.is_none() {
-             [
-                self.values[indexes_v.1.unwrap()][indexes_h.0],
-                self.values[indexes_v.0][indexes_h.0]
-            ]
-        } else {
-            let interpolated_diff_bp_h = breakpoint_h - self.breakpoints_h[indexes_h.1.unwrap()];
-            let diff_actual_bp_h = self.breakpoints_h[indexes_h.0] - self.breakpoints_h[indexes_h.1.unwrap()];
-            let diff_values_h = self.values[indexes_v.0][indexes_h.0] - self.values[indexes_v.0][indexes_h.1.unwrap()];
-            return (U::from(interpolated_diff_bp_h) * diff_values_h) / U::from(diff_actual_bp_h) + self.values[indexes_v.0][indexes_h.1.unwrap()]
-        }; 
-        
-        let interpolated_diff_bp_v = breakpoint_v - self.breakpoints_v[indexes_v.1.unwrap()];
-        let diff_actual_bp_v = self.breakpoints_v[indexes_v.0] - self.breakpoints_v[indexes_v.1.unwrap()];
-        (U::from(interpolated_diff_bp_v) * (intermediary_values[1]-intermediary_values[0]))/ U::from(diff_actual_bp_v)+intermediary_values[0]
+    /// Returns a (interpolated) value from the lookup table that matches the entered breakpoints, see `TwoDLookup::lookup_nonlinear`.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `breakpoint_h` - A reference to the horizontal breakpoint for which a value must be found by the lookup table
+    /// * `breakpoint_v` - A reference to the vertical breakpoint for which a value must be found by the lookup table
+    /// * `interpolation` - The interpolation method to use for this lookup operation
+    pub fn lookup_nonlinear<Y: Copy, Z: Copy>(&self, breakpoint_h: &Y, breakpoint_v: &Z, interpolation: Interpolation) -> Result<U, Infallible>
+    where S: From<Y> + AsF64, T: From<Z> + AsF64, U: F64Components{
+        let calc_breakpoint_h = S::from(*breakpoint_h);
+        let calc_breakpoint_v = T::from(*breakpoint_v);
+        let indexes_h = axis_indexes(&self.breakpoints_h, calc_breakpoint_h, &interpolation);
+        let indexes_v = axis_indexes(&self.breakpoints_v, calc_breakpoint_v, &interpolation);
+        let stride = self.breakpoints_h.len();
+        Ok(interpolate_2d((&self.breakpoints_h, &self.breakpoints_v), |index_v, index_h| self.values[index_v * stride + index_h],
+            (indexes_h, indexes_v), (calc_breakpoint_h, calc_breakpoint_v),
+            |from, to, offset, span| blend(from, to, offset, span, &interpolation), |from, to, offset, span| blend(from, to, offset, span, &interpolation)))
     }
+}
 
-    /// This method is unsafe, consider using the create_2d_lookup!() macro instead.
-    /// Returns a lookup table. Only use an ascending breakpoints vectors! for example  1,2,3,4 and not 4,3,2,1 or 1,2,3,2 \
-    /// breakpoints and values must have the same length in the horizontal and vertical direction!
+#[cfg(feature = "std")]
+impl<
+S: PartialOrd + Sub + Add + Div + Copy + Clone,
+T: PartialOrd + Sub + Add + Div + Copy + Clone,
+U: Add + Sub + Div + Mul + Copy + Clone,
+>
+DynamicTwoDLookup<S,T,U>{
+    /// Returns a lookup table built from breakpoints and values that are only known at runtime.
     /// 
     /// # Arguments
     /// 
     /// * `breakpoints_h` - The breakpoints that act as the horizontal index for the values
     /// * `breakpoints_v` - The breakpoints that act as the vertical index for the values
-    /// * `values` - The values that represent the result from the lookup
+    /// * `values` - The values in row-major order, a row holds the values of one vertical breakpoint
+    /// 
+    /// # Errors
+    /// 
+    /// Returns `LookupBuildError::EmptyAxis` if an axis has no breakpoints,
+    /// `LookupBuildError::ShapeMismatch` if the number of values differs from the number of horizontal breakpoints times the number of vertical breakpoints,
+    /// and `LookupBuildError::NotAscending` if the breakpoints of an axis aren't in ascending order.
     /// 
     /// # Examples
     /// 
     /// ```
-    /// use::go_lookup_tables::{TwoDLookup};
-    /// const LOOKUP_TABLE: TwoDLookup<i16,i8,f32,3,3> = TwoDLookup::new([0,500,1000],[0,3,6],[
-    /// [3.0,4.2,5.5],
-    /// [4.2,5.0,6.0],
-    /// [5.0,5.8,6.5]]); //simple 0.5V to 4.5V pressure sensor
-    /// /* full table:
-    ///     x   0   500 1000
-    ///     0   3.0 4.2 5.5
-    ///     3   4.2 5.0 6.0
-    ///     6   5.0 5.8 6.5
-    ///  */
+    /// use::go_lookup_tables::{DynamicTwoDLookup, LookupBuildError};
+    /// let result = DynamicTwoDLookup::try_new(vec![0i16,500,1000], vec![0i8,3], vec![3.0f32,4.2,5.5,4.2,5.0]);
+    /// assert_eq!(result.err(), Some(LookupBuildError::ShapeMismatch { expected: 6, found: 5 }));
     /// ```
-    pub const fn new(breakpoints_h: [S;N], breakpoints_v: [T;M], values: [[U;N];M])-> TwoDLookup<S,T,U,N,M> {
-        TwoDLookup { breakpoints_h, breakpoints_v, values }
+    pub fn try_new(breakpoints_h: Vec<S>, breakpoints_v: Vec<T>, values: Vec<U>) -> Result<DynamicTwoDLookup<S,T,U>, LookupBuildError> {
+        if breakpoints_h.is_empty() || breakpoints_v.is_empty() {
+            return Err(LookupBuildError::EmptyAxis);
+        }
+        if values.len() != breakpoints_h.len() * breakpoints_v.len() {
+            return Err(LookupBuildError::ShapeMismatch { expected: breakpoints_h.len() * breakpoints_v.len(), found: values.len() });
+        }
+        check_ascending(&breakpoints_h)?;
+        check_ascending(&breakpoints_v)?;
+        Ok(DynamicTwoDLookup { breakpoints_h, breakpoints_v, values })
+    }
+
+    /// Returns the horizontal breakpoints of the lookup table.
+    pub fn breakpoints_h(&self) -> &[S] {
+        &self.breakpoints_h
+    }
+
+    /// Returns the vertical breakpoints of the lookup table.
+    pub fn breakpoints_v(&self) -> &[T] {
+        &self.breakpoints_v
+    }
+
+    /// Returns the values of the lookup table in row-major order.
+    pub fn values(&self) -> &[U] {
+        &self.values
+    }
+}
+
+/// Returns an axis with as many breakpoints as the entered array has items, all set to the start of the axis, used by `create_uniform_2d_lookup!`.
+#[doc(hidden)]
+pub const fn uniform_axis<B: Copy, X, const C: usize>(_items: &[X;C], start: B) -> [B;C] {
+    [start; C]
+}
+
+/// Panics with the index of the row of `create_2d_lookup!` that doesn't have as many values as the first row.\
+/// Const panics can only format `&str`, so the index is written into the message by hand.
+#[doc(hidden)]
+pub const fn panic_jagged_row(row: usize) -> ! {
+    const PREFIX: &[u8] = b"values row ";
+    const SUFFIX: &[u8] = b" doesn't have as many values as the first row";
+    let mut message = [0u8; PREFIX.len() + 20 + SUFFIX.len()];
+    let mut len = 0;
+    while len < PREFIX.len() {
+        message[len] = PREFIX[len];
+        len += 1;
+    }
+    let mut divisor = 1;
+    while row / divisor >= 10 {
+        divisor *= 10;
+    }
+    while divisor > 0 {
+        message[len] = b'0' + (row / divisor % 10) as u8;
+        len += 1;
+        divisor /= 10;
+    }
+    let mut i = 0;
+    while i < SUFFIX.len() {
+        message[len] = SUFFIX[i];
+        len += 1;
+        i += 1;
+    }
+    match core::str::from_utf8(message.split_at(len).0) {
+        Ok(text) => panic!("{}", text),
+        Err(_) => panic!("values rows don't all have as many values as the first row"),
     }
 }
 
@@ -445,9 +5509,15 @@ const M: usize,
 /// 
 /// # Panics
 ///
-/// `create_2d_lookup!` panics if breakpoints is not in ascending order or if breakpoints.len() != values.len().
+/// `create_2d_lookup!` panics if breakpoints is not in ascending order
+/// or if a row of values doesn't have as many values as the first row, the message names the index of that row.
 /// This panic is generated at compile time.
 /// 
+/// # Type requirements
+/// 
+/// The values must have one row per vertical breakpoint and one value per horizontal breakpoint in every row.
+/// `TwoDLookup::new` takes the values as `[[U;N];M]`, so a shape that doesn't match the breakpoints is a type error instead of a panic.
+/// 
 /// # Examples
 /// 
 /// ```
@@ -465,24 +5535,57 @@ const M: usize,
 ///  */
 /// # }
 /// ```
+/// 
+/// ```compile_fail
+/// # #[macro_use] extern crate go_lookup_tables; fn main() {
+/// use::go_lookup_tables::*;
+/// //there are 3 horizontal breakpoints but every row only has 2 values
+/// const LOOKUP_TABLE: TwoDLookup<i16,i8,f32,3,2> = create_2d_lookup!((0,500,1000),(0,3),(
+/// 3.0,4.2;
+/// 4.2,5.0));
+/// # }
+/// ```
+/// 
+/// ```compile_fail
+/// # #[macro_use] extern crate go_lookup_tables; fn main() {
+/// use::go_lookup_tables::*;
+/// //the values aren't a rectangle, "values row 1 doesn't have as many values as the first row"
+/// let lookup_table = create_2d_lookup!((0i16,500,1000),(0i8,3,6),(
+/// 3.0f32,4.2,5.5;
+/// 4.2,5.0,6.0,7.0;
+/// 5.0,5.8,6.5));
+/// # }
+/// ```
+/// 
+/// ```compile_fail
+/// # #[macro_use] extern crate go_lookup_tables; fn main() {
+/// use::go_lookup_tables::*;
+/// //there are 3 vertical breakpoints but only 2 rows
+/// let lookup_table = create_2d_lookup!((0i16,500,1000),(0i8,3,6),(
+/// 3.0f32,4.2,5.5;
+/// 4.2,5.0,6.0));
+/// # }
+/// ```
 #[macro_export]
 macro_rules! create_2d_lookup {
     (($($bps_h:expr),*), ($($bps_v:expr),*), ($($($vals:expr),*);*)) => {{
 
+        //the shape of the values is checked by the types of TwoDLookup::new, [[U;N];M] must match [S;N] and [T;M],
+        //counting the tokens of every row first also names the row that breaks the rectangle
+        const _: () = {
+            let row_lengths = [ $( [ $(stringify!($vals)),* ].len() ),* ];
+            let mut row = 1;
+            while row < row_lengths.len() {
+                if row_lengths[row] != row_lengths[0] {
+                    $crate::panic_jagged_row(row);
+                }
+                row += 1;
+            }
+        };
         let breakpoints_h = [ $($bps_h,)* ];
         let breakpoints_v = [ $($bps_v,)* ];
-        let values = [ $( [ $($vals),* ] ),* ];
-        if breakpoints_v.len() != values.len() {
-            panic!("the vertical lengths of breakpoints and values don't match");
-        }
-
-        if breakpoints_h.len() != values[0].len() {
-            panic!("the horizontal lengths of breakpoints and values don't match.");
-        }
 
-        let mut i = 0;
-
-        i = 1;
+        let mut i = 1;
         while i < breakpoints_h.len() {
             if breakpoints_h[i - 1] > breakpoints_h[i] {
                 panic!("horizontal breakpoints aren't sorted, they should be in ascending order");
@@ -503,4 +5606,116 @@ macro_rules! create_2d_lookup {
             [ $( [ $($vals),+ ] ),+ ],
         )
     }};
-}
\ No newline at end of file
+}
+
+/// Returns a lookup table like `TwoDLookup::from_uniform`, with evenly spaced breakpoints derived from a start and a step on both axes.\
+/// The breakpoints are derived at compile time, so the table can be a `const` and lookups calculate the index of a breakpoint instead of searching the axis.
+/// 
+/// # Arguments
+/// 
+/// * `horizontal` - The first horizontal breakpoint and the delta between two horizontal breakpoints, like `(0,500)`
+/// * `vertical` - The first vertical breakpoint and the delta between two vertical breakpoints
+/// * `values` - The values that represent the result from the lookup, as rows separated by `;`
+/// 
+/// # Panics
+/// 
+/// `create_uniform_2d_lookup!` panics if a step isn't positive or a derived breakpoint overflows, or if the rows don't have as many values as the first row.
+/// This panic is generated at compile time.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # #[macro_use] extern crate go_lookup_tables; fn main() {
+/// use::go_lookup_tables::{TwoDLookup, Interpolation};
+/// const LOOKUP_TABLE: TwoDLookup<i16,i8,f32,3,3> = create_uniform_2d_lookup!((0,500),(0,3),(
+/// 3.0,4.2,5.5;
+/// 4.2,5.0,6.0;
+/// 5.0,5.8,6.5)); //breakpoints (0,500,1000) and (0,3,6)
+/// assert_eq!(LOOKUP_TABLE.lookup(&750i16, &4i8, Interpolation::Linear).unwrap(), 5.7166667f32);
+/// # }
+/// ```
+/// 
+/// ```compile_fail
+/// # #[macro_use] extern crate go_lookup_tables; fn main() {
+/// use::go_lookup_tables::*;
+/// //the third horizontal breakpoint, 200, doesn't fit in an i8
+/// const LOOKUP_TABLE: TwoDLookup<i8,i8,f32,3,2> = create_uniform_2d_lookup!((0,100),(0,1),(
+/// 3.0,4.2,5.5;
+/// 4.2,5.0,6.0));
+/// # }
+/// ```
+#[macro_export]
+macro_rules! create_uniform_2d_lookup {
+    (($h_start:expr, $h_step:expr), ($v_start:expr, $v_step:expr), ($($($vals:expr),*);*)) => {{
+        const _: () = {
+            let row_lengths = [ $( [ $(stringify!($vals)),* ].len() ),* ];
+            let mut row = 1;
+            while row < row_lengths.len() {
+                if row_lengths[row] != row_lengths[0] {
+                    $crate::panic_jagged_row(row);
+                }
+                row += 1;
+            }
+        };
+        let values = [ $( [ $($vals),+ ] ),+ ];
+        let mut breakpoints_h = $crate::uniform_axis(&values[0], $h_start);
+        let mut breakpoints_v = $crate::uniform_axis(&values, $v_start);
+
+        //an overflowing breakpoint fails the build, a step that isn't positive is caught by the comparison
+        let mut i = 1;
+        while i < breakpoints_h.len() {
+            breakpoints_h[i] = breakpoints_h[i - 1] + $h_step;
+            if breakpoints_h[i - 1] >= breakpoints_h[i] {
+                panic!("horizontal breakpoints aren't ascending, the step must be positive");
+            }
+            i += 1;
+        }
+        i = 1;
+        while i < breakpoints_v.len() {
+            breakpoints_v[i] = breakpoints_v[i - 1] + $v_step;
+            if breakpoints_v[i - 1] >= breakpoints_v[i] {
+                panic!("vertical breakpoints aren't ascending, the step must be positive");
+            }
+            i += 1;
+        }
+
+        TwoDLookup::from_uniform_parts(breakpoints_h, breakpoints_v, ($h_start, $h_step), ($v_start, $v_step), values)
+    }};
+}
+
+/// Returns a lookup table like `create_2d_lookup!`, but checks the breakpoints at runtime and returns a `Result` instead of panicking.
+/// Use it for tables built from `let` arrays, `const` tables should keep using `create_2d_lookup!` so mistakes fail the build.
+/// 
+/// # Arguments
+/// 
+/// * `breakpoints_horizontal` - The horizontal breakpoints, either as a list of expressions or as an array expression
+/// * `breakpoints_vertical` - The vertical breakpoints, in the same form as the horizontal breakpoints
+/// * `values` - The values, either as rows separated by `;` or as an array of rows
+/// 
+/// # Errors
+/// 
+/// Returns `LookupBuildError::NotAscending` if either of the breakpoint arrays isn't in ascending order, see `TwoDLookup::try_new`.
+/// 
+/// # Examples
+/// 
+/// ```
+/// # #[macro_use] extern crate go_lookup_tables; fn main() {
+/// use::go_lookup_tables::{TwoDLookup, LookupBuildError};
+/// let breakpoints_h: [i16;3] = [0, 1000, 500];
+/// let values = [[3.0f32, 4.2, 5.5], [4.2, 5.0, 6.0]];
+/// let result: Result<TwoDLookup<i16,i8,f32,3,2>, _> = try_create_2d_lookup!(breakpoints_h, [0, 3], values);
+/// assert_eq!(result.err(), Some(LookupBuildError::NotAscending { index: 2 }));
+/// let lookup_table: TwoDLookup<i16,i8,f32,3,2> = try_create_2d_lookup!((0,500,1000),(0,3),(
+/// 3.0,4.2,5.5;
+/// 4.2,5.0,6.0)).unwrap();
+/// # }
+/// ```
+#[macro_export]
+macro_rules! try_create_2d_lookup {
+    (($($bps_h:expr),*), ($($bps_v:expr),*), ($($($vals:expr),*);*)) => {
+        TwoDLookup::try_new([$($bps_h),*], [$($bps_v),*], [ $( [ $($vals),* ] ),* ])
+    };
+    ($bps_h:expr, $bps_v:expr, $vals:expr) => {
+        TwoDLookup::try_new($bps_h, $bps_v, $vals)
+    };
+}
@@ -0,0 +1,21 @@
+#![cfg(feature = "num-complex")]
+
+use go_lookup_tables::*;
+use num_complex::Complex;
+
+#[test]
+fn complex_impedance_1d() {
+    const IMPEDANCE: OneDLookup<f32, Complex<f32>, 3> = OneDLookup::new(
+        [1.0e6, 2.0e6, 4.0e6],
+        [Complex::new(50.0, 10.0), Complex::new(40.0, -20.0), Complex::new(60.0, -30.0)],
+        2.0e6, Complex::new(20.0, -10.0), 1.0e6, Complex::new(-10.0, -30.0));
+    let result1 = IMPEDANCE.lookup(&1.5e6f32, Extrapolation::NoneError, Interpolation::Linear).unwrap();
+    let result2 = IMPEDANCE.lookup(&3.0e6f32, Extrapolation::NoneError, Interpolation::Linear).unwrap();
+    let result3 = IMPEDANCE.lookup(&5.0e6f32, Extrapolation::Linear, Interpolation::Linear).unwrap();
+    assert_eq!(result1, Complex::new(45.0, -5.0));
+    assert_eq!(result2, Complex::new(50.0, -25.0));
+    assert_eq!(result3, Complex::new(70.0, -35.0));
+    //the easing curve applies to both parts
+    let eased = IMPEDANCE.lookup_nonlinear(&1.25e6f32, Extrapolation::NoneError, Interpolation::Smoothstep).unwrap();
+    assert_eq!(eased, Complex::new(48.4375, 5.3125));
+}
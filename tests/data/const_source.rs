@@ -0,0 +1,2 @@
+pub const WIDE: OneDLookup<u32, u64, 2> = create_1d_lookup!((0u32, 10u32), (0u64, 5000000000u64));
+pub const FALLING: OneDLookup<u16, u16, 2> = OneDLookup::new([0u16, 10u16], [500u16, 100u16], 10u16, 65136u16, 10u16, 65136u16);
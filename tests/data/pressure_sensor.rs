@@ -0,0 +1 @@
+pub const PRESSURE_SENSOR: OneDLookup<i16, f32, 4> = create_1d_lookup!((0i16, 500i16, 4500i16, 5000i16), (0.0f32, 0.0f32, 500.0f32, 500.0f32));
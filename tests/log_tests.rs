@@ -0,0 +1,40 @@
+#![cfg(feature = "log")]
+
+use go_lookup_tables::*;
+use std::sync::Mutex;
+
+/// Collects the log messages so the test can check them.
+struct CapturingLogger {
+    messages: Mutex<Vec<String>>,
+}
+
+impl log::Log for CapturingLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.level() <= log::Level::Warn
+    }
+    fn log(&self, record: &log::Record) {
+        if self.enabled(record.metadata()) {
+            self.messages.lock().unwrap().push(format!("{} {}", record.level(), record.args()));
+        }
+    }
+    fn flush(&self) {}
+}
+
+static LOGGER: CapturingLogger = CapturingLogger { messages: Mutex::new(Vec::new()) };
+
+#[test]
+fn out_of_range_warning_1d() {
+    log::set_logger(&LOGGER).unwrap();
+    log::set_max_level(log::LevelFilter::Warn);
+    const LOOKUP_TABLE: OneDLookup<i16,f32,4> = create_1d_lookup!((0,500,4500,5000), (0.0,0.0,500.0,500.0));
+    //breakpoints inside of the table, including the first one, aren't logged
+    LOOKUP_TABLE.lookup(&0i16, Extrapolation::NoneError, Interpolation::Linear).unwrap();
+    LOOKUP_TABLE.lookup(&2500i16, Extrapolation::NoneError, Interpolation::Linear).unwrap();
+    assert!(LOGGER.messages.lock().unwrap().is_empty());
+    LOOKUP_TABLE.lookup(&5500i16, Extrapolation::Linear, Interpolation::Linear).unwrap();
+    assert!(LOOKUP_TABLE.lookup(&-100i16, Extrapolation::NoneError, Interpolation::Linear).is_err());
+    assert_eq!(*LOGGER.messages.lock().unwrap(), vec![
+        "WARN lookup above the last of the 4 breakpoints of the table, the result is extrapolated".to_string(),
+        "WARN lookup below the first of the 4 breakpoints of the table, NoneError returned an error".to_string(),
+    ]);
+}
@@ -1,4 +1,5 @@
 use go_lookup_tables::*;
+use std::cmp::Ordering;
 
 #[test]
 fn extrapolation_linear_1d() {
@@ -69,4 +70,1369 @@ fn interpolation_ceiling_1d() {
     let result1 = LOOKUP_TABLE.lookup(&4i8, crate::Extrapolation::NoneHoldExtreme, crate::Interpolation::NoneCeiling).unwrap();
     assert_eq!(result, 6i8);
     assert_eq!(result, result1);
-}
\ No newline at end of file
+}
+
+#[test]
+fn to_points_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16, f32, 4> = create_1d_lookup!((0i16,500,4500,5000), (0f32,0.0,500.0,500.0));
+    let points = LOOKUP_TABLE.to_points();
+    assert_eq!(points, vec![(0.0, 0.0), (500.0, 0.0), (4500.0, 500.0), (5000.0, 500.0)]);
+}
+
+#[test]
+fn cast_values_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16, i32, 3> = create_1d_lookup!((0i16,100,200), (0i32,5,25));
+    let float_table: OneDLookup<i16, f64, 3> = LOOKUP_TABLE.cast_values();
+    let result1 = LOOKUP_TABLE.lookup(&50i16, crate::Extrapolation::NoneHoldExtreme, crate::Interpolation::Linear).unwrap();
+    let result2 = float_table.lookup(&50i16, crate::Extrapolation::NoneHoldExtreme, crate::Interpolation::Linear).unwrap();
+    let result3 = float_table.lookup(&250i16, crate::Extrapolation::Linear, crate::Interpolation::Linear).unwrap();
+    assert_eq!(result1, 2i32);
+    assert_eq!(result2, 2.5f64);
+    assert_eq!(result3, 35f64);
+}
+
+#[test]
+fn quantize_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16, i32, 4> = create_1d_lookup!((0i16,500,4500,5000), (0i32,0,500,500));
+    assert_eq!(LOOKUP_TABLE.quantize(&4490i16, 10), Some(500));
+    assert_eq!(LOOKUP_TABLE.quantize(&4489i16, 10), None);
+    assert_eq!(LOOKUP_TABLE.quantize(&5010i16, 10), Some(500));
+    assert_eq!(LOOKUP_TABLE.quantize(&-11i16, 10), None);
+}
+
+#[test]
+fn extrapolation_linear_slope_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16, f32, 2> = create_1d_lookup!((0i16,5000),(0f32,500.0));
+    let result1 = LOOKUP_TABLE.lookup(&-1000i16, crate::Extrapolation::LinearSlope { low: 0.2, high: 0.05 }, crate::Interpolation::Linear).unwrap();
+    let result2 = LOOKUP_TABLE.lookup(&6000i16, crate::Extrapolation::LinearSlope { low: 0.2, high: 0.05 }, crate::Interpolation::Linear).unwrap();
+    let result3 = LOOKUP_TABLE.lookup(&2500i16, crate::Extrapolation::LinearSlope { low: 0.2, high: 0.05 }, crate::Interpolation::Linear).unwrap();
+    //the edge segments have a slope of 0.1
+    let result4 = LOOKUP_TABLE.lookup(&-1000i16, crate::Extrapolation::LinearSlope { low: 0.1, high: 0.1 }, crate::Interpolation::Linear).unwrap();
+    let result5 = LOOKUP_TABLE.lookup(&-1000i16, crate::Extrapolation::Linear, crate::Interpolation::Linear).unwrap();
+    assert_eq!(result1, -200f32);
+    assert_eq!(result2, 550f32);
+    assert_eq!(result3, 250f32);
+    assert_eq!(result4, result5);
+}
+
+#[test]
+fn interpolation_residual_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16, i32, 3> = create_1d_lookup!((0i16,3,6), (0i32,10,20));
+    let result1 = LOOKUP_TABLE.interpolation_residual(&1i16, crate::Interpolation::Linear);
+    let result2 = LOOKUP_TABLE.interpolation_residual(&3i16, crate::Interpolation::Linear);
+    let result3 = LOOKUP_TABLE.interpolation_residual(&1i16, crate::Interpolation::NoneFloor);
+    let result4 = LOOKUP_TABLE.interpolation_residual(&10i16, crate::Interpolation::Linear);
+    assert!((result1 - 1.0/3.0).abs() < 1e-9); //3.333 is truncated to 3
+    assert_eq!(result2, 0.0);
+    assert_eq!(result3, 0.0);
+    assert_eq!(result4, 0.0);
+}
+
+#[test]
+fn wrapping_breakpoints_1d() {
+    use std::num::Wrapping;
+    let lookup_table: OneDLookup<Wrapping<i32>, Wrapping<i32>, 4> = OneDLookup::new(
+        [Wrapping(0), Wrapping(90), Wrapping(180), Wrapping(270)],
+        [Wrapping(0), Wrapping(100), Wrapping(0), Wrapping(-100)],
+        Wrapping(90), Wrapping(-100), Wrapping(90), Wrapping(100));
+    let result1 = lookup_table.lookup(&Wrapping(45), crate::Extrapolation::NoneError, crate::Interpolation::Linear).unwrap();
+    let result2 = lookup_table.lookup(&Wrapping(134), crate::Extrapolation::NoneError, crate::Interpolation::NoneClosest).unwrap();
+    let result3 = lookup_table.lookup(&Wrapping(136), crate::Extrapolation::NoneError, crate::Interpolation::NoneClosest).unwrap();
+    let result4 = lookup_table.lookup(&Wrapping(315), crate::Extrapolation::Linear, crate::Interpolation::Linear).unwrap();
+    assert_eq!(result1, Wrapping(50));
+    assert_eq!(result2, Wrapping(100));
+    assert_eq!(result3, Wrapping(0));
+    assert_eq!(result4, Wrapping(-150));
+}
+
+#[test]
+fn custom_extrapolator_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16, f32, 2> = create_1d_lookup!((0i16,5000),(0f32,500.0));
+    let calls = std::cell::Cell::new(0);
+    let extrapolator = |side: Side, _: i16, _: &OneDLookup<i16, f32, 2>| {
+        calls.set(calls.get() + 1);
+        match side {
+            Side::Low => -1f32,
+            Side::High => 1000f32,
+        }
+    };
+    let result1 = LOOKUP_TABLE.lookup_with_extrapolator(&2500i16, crate::Interpolation::Linear, extrapolator).unwrap();
+    let result2 = LOOKUP_TABLE.lookup_with_extrapolator(&5000i16, crate::Interpolation::Linear, extrapolator).unwrap();
+    assert_eq!(calls.get(), 0);
+    let result3 = LOOKUP_TABLE.lookup_with_extrapolator(&-1i16, crate::Interpolation::Linear, extrapolator).unwrap();
+    let result4 = LOOKUP_TABLE.lookup_with_extrapolator(&5001i16, crate::Interpolation::Linear, extrapolator).unwrap();
+    assert_eq!(calls.get(), 2);
+    assert_eq!(result1, 250f32);
+    assert_eq!(result2, 500f32);
+    assert_eq!(result3, -1f32);
+    assert_eq!(result4, 1000f32);
+}
+
+#[test]
+fn cached_lookup_hit_rate_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16, f32, 4> = create_1d_lookup!((0i16,500,4500,5000), (0f32,0.0,500.0,500.0));
+    let mut cached_table = CachedOneDLookup::new(&LOOKUP_TABLE);
+    let mut hits = 0;
+    let mut queries = 0;
+    for breakpoint in (-100i16..5100).step_by(10) {
+        let result = cached_table.lookup(&breakpoint, crate::Extrapolation::Linear, crate::Interpolation::Linear).unwrap();
+        assert_eq!(result, LOOKUP_TABLE.lookup(&breakpoint, crate::Extrapolation::Linear, crate::Interpolation::Linear).unwrap());
+        if cached_table.last_was_cache_hit() {
+            hits += 1;
+        }
+        queries += 1;
+    }
+    //only the 4 segment crossings require a search, the sweep starts in the cached first segment
+    assert_eq!(queries - hits, 4);
+    assert!(hits as f32 / queries as f32 > 0.99);
+}
+
+#[test]
+fn unsigned_falling_edges_1d() {
+    //the cached first value delta of 400 - 500 wraps around for u16
+    let lookup_table: OneDLookup<u16, u16, 3> = OneDLookup::new([100, 200, 300], [500, 400, 600], 100, 200, 100, 400u16.wrapping_sub(500));
+    let result1 = lookup_table.lookup(&50u16, crate::Extrapolation::Linear, crate::Interpolation::Linear).unwrap();
+    let result2 = lookup_table.lookup(&150u16, crate::Extrapolation::Linear, crate::Interpolation::Linear).unwrap();
+    let result3 = lookup_table.lookup(&350u16, crate::Extrapolation::Linear, crate::Interpolation::Linear).unwrap();
+    assert_eq!(result1, 550u16);
+    assert_eq!(result2, 450u16);
+    assert_eq!(result3, 700u16);
+
+    let falling_table: OneDLookup<u16, u16, 2> = OneDLookup::new([100, 200], [600, 500], 100, 500u16.wrapping_sub(600), 100, 500u16.wrapping_sub(600));
+    let result4 = falling_table.lookup(&50u16, crate::Extrapolation::Linear, crate::Interpolation::Linear).unwrap();
+    let result5 = falling_table.lookup(&250u16, crate::Extrapolation::Linear, crate::Interpolation::Linear).unwrap();
+    assert_eq!(result4, 650u16);
+    assert_eq!(result5, 450u16);
+}
+
+#[test]
+fn delta_formula_1d() {
+    //only the delta of a segment is multiplied, 50 * (0 + 100) and 500 * 3_000_000 would overflow
+    const SMALL_TABLE: OneDLookup<i8, i8, 3> = create_1d_lookup!((-100i8,0,100), (0i8,1,2));
+    const OFFSET_TABLE: OneDLookup<i32, i32, 2> = create_1d_lookup!((0i32,1000), (3_000_000i32,3_000_001));
+    assert_eq!(SMALL_TABLE.lookup(&50i8, Extrapolation::NoneError, Interpolation::Linear).unwrap(), 1);
+    assert_eq!(OFFSET_TABLE.lookup(&500i32, Extrapolation::NoneError, Interpolation::Linear).unwrap(), 3_000_000);
+    assert_eq!(OFFSET_TABLE.lookup_checked(&500i32, Extrapolation::NoneError, Interpolation::Linear), Ok(3_000_000));
+
+    //a falling signed segment truncates its delta towards zero, 10 + (1 * -10) / 3 = 7
+    const FALLING_TABLE: OneDLookup<i16, i16, 2> = create_1d_lookup!((0i16,3), (10i16,0));
+    assert_eq!(FALLING_TABLE.lookup(&1i16, Extrapolation::NoneError, Interpolation::Linear).unwrap(), 7);
+
+    //extrapolation continues the edge segment from its inner value like lookup_checked, 5 + (4 * 5) / 3 = 11
+    const RISING_TABLE: OneDLookup<i16, i16, 2> = create_1d_lookup!((0i16,3), (5i16,10));
+    assert_eq!(RISING_TABLE.lookup(&4i16, Extrapolation::Linear, Interpolation::Linear).unwrap(), 11);
+    assert_eq!(RISING_TABLE.lookup_checked(&4i16, Extrapolation::Linear, Interpolation::Linear), Ok(11));
+    assert_eq!(RISING_TABLE.lookup(&-1i16, Extrapolation::Linear, Interpolation::Linear).unwrap(), 4);
+    assert_eq!(RISING_TABLE.lookup_checked(&-1i16, Extrapolation::Linear, Interpolation::Linear), Ok(4));
+}
+
+/// A Q8 fixed-point number, which can't be converted to `f64`.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+struct Fixed(i32);
+
+impl std::ops::Add for Fixed {
+    type Output = Fixed;
+    fn add(self, other: Fixed) -> Fixed { Fixed(self.0 + other.0) }
+}
+
+impl std::ops::Sub for Fixed {
+    type Output = Fixed;
+    fn sub(self, other: Fixed) -> Fixed { Fixed(self.0 - other.0) }
+}
+
+impl std::ops::Mul for Fixed {
+    type Output = Fixed;
+    fn mul(self, other: Fixed) -> Fixed { Fixed((self.0 * other.0) >> 8) }
+}
+
+impl std::ops::Div for Fixed {
+    type Output = Fixed;
+    fn div(self, other: Fixed) -> Fixed { Fixed((self.0 << 8) / other.0) }
+}
+
+impl EdgeDelta for Fixed {}
+
+#[test]
+fn fixed_point_1d() {
+    let lookup_table: OneDLookup<Fixed, Fixed, 2> = OneDLookup::try_new([Fixed(0), Fixed(10 << 8)], [Fixed(0), Fixed(100 << 8)]).unwrap();
+    assert_eq!(lookup_table.lookup(&Fixed(5 << 8), Extrapolation::NoneError, Interpolation::Linear).unwrap(), Fixed(50 << 8));
+    assert_eq!(lookup_table.lookup(&Fixed(15 << 8), Extrapolation::Linear, Interpolation::Linear).unwrap(), Fixed(150 << 8));
+    //the methods that are calculated in f64 interpolate linearly
+    assert_eq!(lookup_table.lookup(&Fixed(5 << 8), Extrapolation::NoneError, Interpolation::Smoothstep).unwrap(), Fixed(50 << 8));
+}
+
+#[test]
+fn try_from_iter_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16, f32, 4> = create_1d_lookup!((0i16,500,4500,5000), (0f32,0.0,500.0,500.0));
+    let lookup_table: OneDLookup<i16, f32, 4> = OneDLookup::try_from_iter([(0i16, 0f32), (500, 0.0), (4500, 500.0), (5000, 500.0)]).unwrap();
+    for breakpoint in [-1000i16, 250, 2000, 4750, 6000] {
+        assert_eq!(lookup_table.lookup(&breakpoint, crate::Extrapolation::Linear, crate::Interpolation::Linear).unwrap(),
+            LOOKUP_TABLE.lookup(&breakpoint, crate::Extrapolation::Linear, crate::Interpolation::Linear).unwrap());
+    }
+}
+
+#[test]
+fn try_from_iter_wrong_count_1d() {
+    let too_short = OneDLookup::<i16, f32, 4>::try_from_iter([(0i16, 0f32), (500, 0.0), (4500, 500.0)]);
+    let too_long = OneDLookup::<i16, f32, 2>::try_from_iter([(0i16, 0f32), (500, 0.0), (4500, 500.0)]);
+    assert_eq!(too_short.err(), Some(LookupBuildError::ShapeMismatch { expected: 4, found: 3 }));
+    assert_eq!(too_long.err(), Some(LookupBuildError::ShapeMismatch { expected: 2, found: 3 }));
+}
+
+#[test]
+fn try_from_iter_not_ascending_1d() {
+    let result = OneDLookup::<i16, f32, 4>::try_from_iter([(0i16, 0f32), (500, 0.0), (400, 500.0), (5000, 500.0)]);
+    assert_eq!(result.err(), Some(LookupBuildError::NotAscending { index: 2 }));
+}
+
+#[test]
+fn to_macro_source_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16, f32, 4> = create_1d_lookup!((0i16,500,4500,5000), (0f32,0.0,500.0,500.0));
+    //the generated source pasted back into the macro
+    const GENERATED_TABLE: OneDLookup<i16, f32, 4> = create_1d_lookup!((0, 500, 4500, 5000), (0.0, 0.0, 500.0, 500.0));
+    assert_eq!(LOOKUP_TABLE.to_macro_source(), "create_1d_lookup!((0, 500, 4500, 5000), (0.0, 0.0, 500.0, 500.0))");
+    assert_eq!(GENERATED_TABLE.to_macro_source(), LOOKUP_TABLE.to_macro_source());
+    assert_eq!(GENERATED_TABLE.to_points(), LOOKUP_TABLE.to_points());
+}
+
+#[test]
+fn saturating_convert_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16, f32, 2> = create_1d_lookup!((0i16,5000),(0f32,500.0));
+    let result1 = LOOKUP_TABLE.lookup_saturating_convert(&100_000u32, crate::Extrapolation::NoneHoldExtreme, crate::Interpolation::Linear).unwrap();
+    let result2 = LOOKUP_TABLE.lookup_saturating_convert(&-100_000i64, crate::Extrapolation::NoneHoldExtreme, crate::Interpolation::Linear).unwrap();
+    let result3 = LOOKUP_TABLE.lookup_saturating_convert(&2500u32, crate::Extrapolation::NoneHoldExtreme, crate::Interpolation::Linear).unwrap();
+    //saturates to i16::MAX before extrapolating
+    let result4 = LOOKUP_TABLE.lookup_saturating_convert(&100_000u32, crate::Extrapolation::Linear, crate::Interpolation::Linear).unwrap();
+    let result5 = LOOKUP_TABLE.lookup_saturating_convert(&100_000u32, crate::Extrapolation::NoneError, crate::Interpolation::Linear);
+    assert_eq!(result1, 500f32);
+    assert_eq!(result2, 0f32);
+    assert_eq!(result3, 250f32);
+    assert_eq!(result4, 3276.7f32);
+    assert!(result5.is_err());
+}
+
+#[test]
+fn checked_lookup_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16, i16, 2> = create_1d_lookup!((0i16,1000),(0i16,1000));
+    const UNSIGNED_TABLE: OneDLookup<u16, u16, 2> = create_1d_lookup!((100u16,200),(50u16,150));
+    //500 * 1000 overflows the multiplication
+    let result1 = LOOKUP_TABLE.lookup_checked(&500i16, crate::Extrapolation::NoneHoldExtreme, crate::Interpolation::Linear);
+    //150 - 200 underflows the subtraction
+    let result2 = UNSIGNED_TABLE.lookup_checked(&0u16, crate::Extrapolation::Linear, crate::Interpolation::Linear);
+    let result3 = UNSIGNED_TABLE.lookup_checked(&75u16, crate::Extrapolation::Linear, crate::Interpolation::Linear);
+    let result4 = UNSIGNED_TABLE.lookup_checked(&150u16, crate::Extrapolation::NoneError, crate::Interpolation::Linear);
+    let result5 = UNSIGNED_TABLE.lookup_checked(&250u16, crate::Extrapolation::NoneError, crate::Interpolation::Linear);
+    let result6 = LOOKUP_TABLE.lookup_checked(&600i16, crate::Extrapolation::NoneHoldExtreme, crate::Interpolation::NoneClosest);
+    assert_eq!(result1, Err(LookupError::ArithmeticOverflow));
+    assert_eq!(result2, Err(LookupError::ArithmeticOverflow));
+    assert_eq!(result3, Ok(25u16));
+    assert_eq!(result4, Ok(100u16));
+    assert_eq!(result5, Err(LookupError::OutOfRange));
+    assert_eq!(result6, Ok(1000i16));
+}
+
+#[test]
+fn weights_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16, f32, 4> = create_1d_lookup!((0i16,500,4500,5000), (0f32,0.0,500.0,500.0));
+    let result1 = LOOKUP_TABLE.weights(&1500i16).unwrap();
+    let result2 = LOOKUP_TABLE.weights(&500i16).unwrap();
+    let result3 = LOOKUP_TABLE.weights(&5001i16);
+    assert_eq!(result1, [(1, 0.75), (2, 0.25)]);
+    assert_eq!(result2, [(1, 1.0), (1, 0.0)]);
+    assert!(result3.is_err());
+}
+
+#[test]
+fn assert_monotone_1d() {
+    const RISING_TABLE: OneDLookup<i16, f32, 4> = create_1d_lookup!((0i16,500,4500,5000), (0f32,0.0,500.0,500.0));
+    const DIP_TABLE: OneDLookup<i16, f32, 4> = create_1d_lookup!((0i16,500,4500,5000), (0f32,200.0,150.0,500.0));
+    assert_eq!(RISING_TABLE.assert_monotone(Ordering::Less, NanPolicy::Propagate), Ok(()));
+    assert_eq!(DIP_TABLE.assert_monotone(Ordering::Less, NanPolicy::Propagate), Err(MonotoneError::NonMonotone(NonMonotoneAt { index: 2 })));
+    assert_eq!(DIP_TABLE.assert_monotone(Ordering::Greater, NanPolicy::Propagate), Err(MonotoneError::NonMonotone(NonMonotoneAt { index: 1 })));
+}
+
+#[test]
+fn parse_modes() {
+    assert_eq!("linear".parse::<Interpolation>(), Ok(Interpolation::Linear));
+    assert_eq!("FLOOR".parse::<Interpolation>(), Ok(Interpolation::NoneFloor));
+    assert_eq!("none_ceiling".parse::<Interpolation>(), Ok(Interpolation::NoneCeiling));
+    assert_eq!("Closest".parse::<Interpolation>(), Ok(Interpolation::NoneClosest));
+    assert_eq!("error".parse::<Extrapolation<f32>>(), Ok(Extrapolation::NoneError));
+    assert_eq!("hold_extreme".parse::<Extrapolation<f32>>(), Ok(Extrapolation::NoneHoldExtreme));
+    assert_eq!("Linear".parse::<Extrapolation<f32>>(), Ok(Extrapolation::Linear));
+    assert_eq!("cubic".parse::<Interpolation>(), Ok(Interpolation::Cubic));
+    assert_eq!("quadratic".parse::<Interpolation>(), Err(ParseModeError { input: "quadratic".to_string() }));
+    assert!("slope".parse::<Extrapolation<f32>>().is_err());
+}
+
+#[test]
+fn self_consistency_check_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16, f32, 4> = create_1d_lookup!((0i16,500,4500,5000), (0f32,0.0,500.0,500.0));
+    const CORRUPT_CACHE: OneDLookup<i16, f32, 4> = OneDLookup::new([0,500,4500,5000], [0.0,0.0,500.0,500.0], 123, 45.0, 678, 9.0);
+    const INCONSISTENT_TABLE: OneDLookup<i16, f32, 4> = OneDLookup::new([0,4500,500,5000], [0.0,500.0,0.0,500.0], 4500, 500.0, 4500, 500.0);
+    assert!(LOOKUP_TABLE.self_consistency_check());
+    assert!(LOOKUP_TABLE.self_consistency_check_strict());
+    assert!(CORRUPT_CACHE.self_consistency_check());
+    assert!(!CORRUPT_CACHE.self_consistency_check_strict());
+    assert!(!INCONSISTENT_TABLE.self_consistency_check_strict());
+}
+
+#[test]
+fn normalize_values_1d() {
+    const INJECTOR_TIMES: OneDLookup<i16, f32, 4> = create_1d_lookup!((0i16,500,1000,1500), (3.0f32,4.2,5.5,6.5));
+    let mut lookup_table = INJECTOR_TIMES;
+    let (min, max) = lookup_table.normalize_values();
+    assert_eq!((min, max), (3.0, 6.5));
+    assert_eq!(lookup_table.lookup(&0i16, Extrapolation::NoneError, Interpolation::Linear).unwrap(), 0.0);
+    assert_eq!(lookup_table.lookup(&1500i16, Extrapolation::NoneError, Interpolation::Linear).unwrap(), 1.0);
+    assert!(lookup_table.self_consistency_check_strict());
+    for bp in [0i16, 250, 500, 1000, 1500] {
+        let normalized = lookup_table.lookup(&bp, Extrapolation::NoneError, Interpolation::Linear).unwrap();
+        let original = INJECTOR_TIMES.lookup(&bp, Extrapolation::NoneError, Interpolation::Linear).unwrap();
+        assert!((normalized * (max - min) + min - original).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn from_unsorted_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16, f32, 4> = create_1d_lookup!((0i16,500,4500,5000), (0f32,0.0,500.0,500.0));
+    let shuffled = vec![(4500i16, 500.0f32), (0, 0.0), (5000, 500.0), (500, 0.0)];
+    let lookup_table: OneDLookup<i16, f32, 4> = OneDLookup::from_unsorted(shuffled).unwrap();
+    assert_eq!(lookup_table.to_points(), LOOKUP_TABLE.to_points());
+    assert!(lookup_table.self_consistency_check_strict());
+
+    let duplicates = vec![(4500i16, 500.0f32), (0, 0.0), (4500, 400.0), (500, 0.0)];
+    let result1: Result<OneDLookup<i16, f32, 4>, _> = OneDLookup::from_unsorted(duplicates);
+    let result2: Result<OneDLookup<f32, f32, 2>, _> = OneDLookup::from_unsorted(vec![(1.0f32, 0.0f32), (f32::NAN, 1.0)]);
+    assert_eq!(result1.err(), Some(LookupBuildError::DuplicateBreakpoint { index: 3 }));
+    assert_eq!(result2.err(), Some(LookupBuildError::NotAscending { index: 1 }));
+}
+
+#[test]
+fn segment_coefficients_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16, f32, 4> = create_1d_lookup!((0i16,500,4500,5000), (100f32,0.0,500.0,450.0));
+    let coefficients = LOOKUP_TABLE.segment_coefficients();
+    assert_eq!(coefficients.len(), 3);
+    for bp in [0i16, 250, 500, 1234, 4500, 4800, 5000] {
+        let (bp_low, slope, intercept) = *coefficients.iter().rev().find(|segment| segment.0 <= bp).unwrap();
+        let expected = LOOKUP_TABLE.lookup(&bp, Extrapolation::NoneError, Interpolation::Linear).unwrap();
+        assert!((slope * f32::from(bp - bp_low) + intercept - expected).abs() < 1e-3);
+    }
+}
+
+#[test]
+fn small_index_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16, f32, 4> = create_1d_lookup!((0i16,500,4500,5000), (0f32,0.0,500.0,500.0));
+    const SMALL_TABLE: SmallOneDLookup<i16, f32, 4> = SmallOneDLookup::new(LOOKUP_TABLE);
+    for bp in [-100i16, 0, 250, 500, 2000, 4500, 4750, 5000, 5500] {
+        for interpolation in [Interpolation::Linear, Interpolation::NoneFloor, Interpolation::NoneCeiling, Interpolation::NoneClosest] {
+            assert_eq!(
+                SMALL_TABLE.lookup(&bp, Extrapolation::Linear, interpolation).unwrap(),
+                LOOKUP_TABLE.lookup(&bp, Extrapolation::Linear, interpolation).unwrap()
+            );
+        }
+    }
+    assert!(SMALL_TABLE.lookup(&5500i16, Extrapolation::NoneError, Interpolation::Linear).is_err());
+    //the u8 index reaches the last of 256 breakpoints without overflowing
+    let full_table: OneDLookup<u16, u32, 256> = OneDLookup::try_new(std::array::from_fn(|i| i as u16 * 2), std::array::from_fn(|i| i as u32)).unwrap();
+    let small_full = SmallOneDLookup::new(full_table);
+    assert_eq!(small_full.lookup(&510u16, Extrapolation::NoneError, Interpolation::Linear).unwrap(), 255);
+    assert_eq!(small_full.lookup(&509u16, Extrapolation::NoneError, Interpolation::NoneCeiling).unwrap(), 255);
+    assert!(small_full.lookup(&511u16, Extrapolation::NoneError, Interpolation::Linear).is_err());
+}
+
+#[test]
+fn edge_slopes_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16, f32, 4> = create_1d_lookup!((0i16,500,4500,5000), (0f32,0.0,500.0,500.0));
+    assert_eq!(LOOKUP_TABLE.edge_slopes(), (0.0, 0.0));
+}
+
+#[test]
+fn lookup_or_clamped_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16, f32, 4> = create_1d_lookup!((0i16,500,4500,5000), (0f32,0.0,500.0,500.0));
+    let (result1, error1) = LOOKUP_TABLE.lookup_or_clamped(&2000i16, Interpolation::Linear);
+    let (result2, error2) = LOOKUP_TABLE.lookup_or_clamped(&5000i16, Interpolation::Linear);
+    let (result3, error3) = LOOKUP_TABLE.lookup_or_clamped(&5500i16, Interpolation::Linear);
+    let (result4, error4) = LOOKUP_TABLE.lookup_or_clamped(&-1i16, Interpolation::Linear);
+    assert_eq!((result1, error1.is_none()), (187.5, true));
+    assert_eq!((result2, error2.is_none()), (500.0, true));
+    assert_eq!((result3, error3.is_some()), (500.0, true));
+    assert_eq!((result4, error4.is_some()), (0.0, true));
+}
+
+#[test]
+fn concat_1d() {
+    const RAMP_UP: OneDLookup<i16, f32, 3> = create_1d_lookup!((0i16,100,200), (0f32,10.0,20.0));
+    const RAMP_DOWN: OneDLookup<i16, f32, 2> = create_1d_lookup!((300i16,400), (40f32,0.0));
+    let lookup_table: OneDLookup<i16, f32, 5> = RAMP_UP.concat(&RAMP_DOWN).unwrap();
+    assert_eq!(lookup_table.lookup(&250i16, Extrapolation::NoneError, Interpolation::Linear).unwrap(), 30.0);
+    assert_eq!(lookup_table.lookup(&350i16, Extrapolation::NoneError, Interpolation::Linear).unwrap(), 20.0);
+    assert!(lookup_table.self_consistency_check_strict());
+
+    let result1: Result<OneDLookup<i16, f32, 4>, _> = RAMP_UP.concat(&RAMP_DOWN);
+    let result2: Result<OneDLookup<i16, f32, 5>, _> = RAMP_DOWN.concat(&RAMP_UP);
+    let result3: Result<OneDLookup<i16, f32, 6>, _> = RAMP_UP.concat(&RAMP_UP);
+    assert_eq!(result1.err(), Some(LookupBuildError::ShapeMismatch { expected: 5, found: 4 }));
+    assert_eq!(result2.err(), Some(LookupBuildError::NotAscending { index: 2 }));
+    assert_eq!(result3.err(), Some(LookupBuildError::NotAscending { index: 3 }));
+}
+
+#[test]
+fn lookup_fma_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16, f32, 3> = create_1d_lookup!((0i16,3,10), (0.1f32,0.7,-0.3));
+    let result1 = LOOKUP_TABLE.lookup_fma(&1i16, Extrapolation::NoneError, Interpolation::Linear).unwrap();
+    let result2 = LOOKUP_TABLE.lookup_fma(&12i16, Extrapolation::Linear, Interpolation::Linear).unwrap();
+    let result3 = LOOKUP_TABLE.lookup_fma(&-2i16, Extrapolation::LinearSlope { low: 0.25, high: 0.0 }, Interpolation::Linear).unwrap();
+    let result4 = LOOKUP_TABLE.lookup_fma(&2i16, Extrapolation::NoneError, Interpolation::NoneFloor).unwrap();
+    assert_eq!(result1.to_bits(), 0x3e999999);
+    assert_eq!(result2.to_bits(), 0xbf15f15f);
+    assert_eq!(result3.to_bits(), 0xbecccccd);
+    assert_eq!(result4, 0.1);
+    //the methods that aren't linear are calculated like lookup_nonlinear
+    let result5 = LOOKUP_TABLE.lookup_fma(&1i16, Extrapolation::NoneError, Interpolation::Smoothstep).unwrap();
+    assert_eq!(result5, LOOKUP_TABLE.lookup_nonlinear(&1i16, Extrapolation::NoneError, Interpolation::Smoothstep).unwrap());
+}
+
+#[test]
+fn at_fractional_index_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16, f32, 4> = create_1d_lookup!((0i16,500,4500,5000), (10f32,20.0,500.0,400.0));
+    assert_eq!(LOOKUP_TABLE.at_fractional_index(1.5), (20.0 + 500.0) / 2.0);
+    assert_eq!(LOOKUP_TABLE.at_fractional_index(2.0), 500.0);
+    assert_eq!(LOOKUP_TABLE.at_fractional_index(-1.0), 10.0);
+    assert_eq!(LOOKUP_TABLE.at_fractional_index(3.5), 400.0);
+    assert_eq!(LOOKUP_TABLE.at_fractional_index(f64::NAN), 10.0);
+}
+
+#[test]
+fn invert_1d() {
+    const RAMP: OneDLookup<f32, f32, 4> = create_1d_lookup!((0f32,10.0,20.0,40.0), (-5f32,0.0,20.0,30.0));
+    const FALLING: OneDLookup<f32, f32, 3> = create_1d_lookup!((0f32,10.0,20.0), (30f32,10.0,0.0));
+    let samples = [-5.0f32, 0.0, 5.0, 12.5, 20.0, 25.0, 30.0];
+    let inverse: OneDLookup<f32, f32, 7> = RAMP.invert(samples).unwrap();
+    for sample in samples {
+        let breakpoint = inverse.lookup(&sample, Extrapolation::NoneError, Interpolation::Linear).unwrap();
+        let round_trip = RAMP.lookup(&breakpoint, Extrapolation::NoneError, Interpolation::Linear).unwrap();
+        assert!((round_trip - sample).abs() < 1e-4);
+    }
+    let falling_inverse: OneDLookup<f32, f32, 3> = FALLING.invert([0.0, 20.0, 40.0]).unwrap();
+    assert_eq!(falling_inverse.lookup(&20.0f32, Extrapolation::NoneError, Interpolation::Linear).unwrap(), 5.0);
+    assert_eq!(falling_inverse.lookup(&40.0f32, Extrapolation::NoneError, Interpolation::Linear).unwrap(), 0.0);
+
+    const PRESSURE_SENSOR: OneDLookup<f32, f32, 4> = create_1d_lookup!((0f32,500.0,4500.0,5000.0), (0f32,0.0,500.0,500.0));
+    let result: Result<OneDLookup<f32, f32, 2>, _> = PRESSURE_SENSOR.invert([0.0, 500.0]);
+    assert_eq!(result.err(), Some(LookupBuildError::NotMonotone { index: 1 }));
+}
+
+#[test]
+fn resample_into_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16, f32, 4> = create_1d_lookup!((0i16,500,4500,5000), (0f32,0.0,500.0,500.0));
+    let mut pressures = Vec::new();
+    LOOKUP_TABLE.resample_into((0..1000i16).map(|sample| sample * 5), &mut pressures, Extrapolation::NoneError, Interpolation::Linear).unwrap();
+    assert_eq!(pressures.len(), 1000);
+    assert_eq!(pressures[400], LOOKUP_TABLE.lookup(&2000i16, Extrapolation::NoneError, Interpolation::Linear).unwrap());
+
+    let mut partial = Vec::new();
+    let result = LOOKUP_TABLE.resample_into([100i16, 6000, 200].into_iter(), &mut partial, Extrapolation::NoneError, Interpolation::Linear);
+    assert!(result.is_err());
+    assert_eq!(partial, vec![0.0]);
+}
+
+#[test]
+fn lookup_adjusted_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16, f32, 4> = create_1d_lookup!((0i16,500,4500,5000), (0f32,0.0,500.0,500.0));
+    let plain = LOOKUP_TABLE.lookup(&2000i16, Extrapolation::NoneError, Interpolation::Linear).unwrap();
+    let adjusted = LOOKUP_TABLE.lookup_adjusted(&2000i16, Extrapolation::NoneError, Interpolation::Linear, 2.0, 1.0).unwrap();
+    assert_eq!(adjusted, plain * 2.0 + 1.0);
+    assert!(LOOKUP_TABLE.lookup_adjusted(&6000i16, Extrapolation::NoneError, Interpolation::Linear, 2.0, 1.0).is_err());
+}
+
+#[test]
+fn min_resolvable_query_step_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16, i16, 5> = create_1d_lookup!((0i16,100,101,101,300), (0i16,10,20,30,40));
+    assert_eq!(LOOKUP_TABLE.min_resolvable_query_step(), 1);
+    //the values only step by 1 every 10 and 20 breakpoint units, so the value type sets the step instead of the segment width
+    const QUANTISED: OneDLookup<i16, i16, 3> = create_1d_lookup!((0i16,100,200), (0i16,10,15));
+    assert_eq!(QUANTISED.min_resolvable_query_step(), 10);
+    //rounded up to a whole breakpoint unit
+    const UNEVEN: OneDLookup<u16, u32, 2> = create_1d_lookup!((0u16,100), (0u32,40));
+    assert_eq!(UNEVEN.min_resolvable_query_step(), 3);
+    const FLOAT_VALUES: OneDLookup<i16, f32, 3> = create_1d_lookup!((0i16,100,200), (0f32,10.0,15.0));
+    assert_eq!(FLOAT_VALUES.min_resolvable_query_step(), 1);
+    const FLOAT_BREAKPOINTS: OneDLookup<f32, f32, 2> = create_1d_lookup!((0f32,100.0), (0f32,4.0));
+    assert_eq!(FLOAT_BREAKPOINTS.min_resolvable_query_step(), 0.0);
+    const FLAT: OneDLookup<i16, i16, 3> = create_1d_lookup!((0i16,100,200), (5i16,5,5));
+    assert_eq!(FLAT.min_resolvable_query_step(), 200);
+}
+
+#[test]
+fn reversed_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16, f32, 4> = create_1d_lookup!((0i16,500,4500,5000), (0f32,100.0,500.0,450.0));
+    let reversed = LOOKUP_TABLE.reversed();
+    assert_eq!(reversed.to_points()[0], (-5000.0, 450.0));
+    assert_eq!(reversed.reversed().to_points(), LOOKUP_TABLE.to_points());
+    assert!(reversed.self_consistency_check_strict());
+    for bp in [-100i16, 0, 250, 2000, 4800, 5500] {
+        assert_eq!(
+            reversed.lookup(&-bp, Extrapolation::Linear, Interpolation::Linear).unwrap(),
+            LOOKUP_TABLE.lookup(&bp, Extrapolation::Linear, Interpolation::Linear).unwrap()
+        );
+    }
+}
+
+#[test]
+fn memoized_1d() {
+    const LOOKUP_TABLE: OneDLookup<f32, f32, 4> = create_1d_lookup!((0f32,500.0,4500.0,5000.0), (0f32,0.0,500.0,500.0));
+    let quantum = 2.0f32;
+    let mut memoized: MemoizedOneDLookup<f32, f32, 4, 8> = MemoizedOneDLookup::new(&LOOKUP_TABLE, quantum, Extrapolation::NoneError, Interpolation::Linear);
+    //the steepest segment rises 0.125 per breakpoint unit
+    let tolerance = 0.125 * quantum;
+    for step in 0..2000 {
+        let bp = (step * 37 % 5000) as f32 + 0.5;
+        let cached = memoized.lookup(&bp).unwrap();
+        let uncached = LOOKUP_TABLE.lookup(&bp, Extrapolation::NoneError, Interpolation::Linear).unwrap();
+        assert!((cached - uncached).abs() <= tolerance);
+    }
+    assert_eq!(memoized.lookup(&2000.0f32).unwrap(), memoized.lookup(&2001.5f32).unwrap());
+    assert!(memoized.lookup(&6000.0f32).is_err());
+}
+
+#[test]
+fn bytes_round_trip_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16, f32, 4> = create_1d_lookup!((0i16,500,4500,5000), (0f32,0.0,500.0,500.0));
+    let big_endian = LOOKUP_TABLE.to_bytes(Endianness::Big);
+    assert_eq!(big_endian.len(), 1 + 4 * 2 + 4 * 4);
+    assert_eq!(&big_endian[..5], &[1, 0, 0, 0x01, 0xf4]);
+    let decoded: OneDLookup<i16, f32, 4> = OneDLookup::from_bytes(&big_endian).unwrap();
+    assert_eq!(decoded.to_points(), LOOKUP_TABLE.to_points());
+    assert_eq!(decoded.lookup(&2000i16, Extrapolation::NoneError, Interpolation::Linear).unwrap(), 187.5);
+    assert_eq!(decoded.to_bytes(Endianness::Little)[3..5], [0xf4, 0x01]);
+
+    let mut corrupt_marker = big_endian.clone();
+    corrupt_marker[0] = 7;
+    let result1: Result<OneDLookup<i16, f32, 4>, _> = OneDLookup::from_bytes(&corrupt_marker);
+    let result2: Result<OneDLookup<i16, f32, 4>, _> = OneDLookup::from_bytes(&big_endian[..20]);
+    assert_eq!(result1.err(), Some(LookupBuildError::InvalidEndiannessMarker { found: 7 }));
+    assert_eq!(result2.err(), Some(LookupBuildError::ShapeMismatch { expected: 25, found: 20 }));
+}
+
+#[test]
+fn smoothstep_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16, f32, 3> = create_1d_lookup!((0i16,400,800), (0f32,100.0,20.0));
+    let linear_mid = LOOKUP_TABLE.lookup(&200i16, Extrapolation::NoneError, Interpolation::Linear).unwrap();
+    let linear_quarter = LOOKUP_TABLE.lookup(&100i16, Extrapolation::NoneError, Interpolation::Linear).unwrap();
+    for interpolation in [Interpolation::Smoothstep, Interpolation::Smootherstep] {
+        assert_eq!(LOOKUP_TABLE.lookup_nonlinear(&200i16, Extrapolation::NoneError, interpolation).unwrap(), linear_mid);
+        //lookup doesn't calculate in f64, so it interpolates linearly
+        assert_eq!(LOOKUP_TABLE.lookup(&100i16, Extrapolation::NoneError, interpolation).unwrap(), linear_quarter);
+        assert_ne!(LOOKUP_TABLE.lookup_nonlinear(&100i16, Extrapolation::NoneError, interpolation).unwrap(), linear_quarter);
+        assert_eq!(LOOKUP_TABLE.lookup_checked(&100i16, Extrapolation::NoneError, interpolation), Ok(LOOKUP_TABLE.lookup_nonlinear(&100i16, Extrapolation::NoneError, interpolation).unwrap()));
+    }
+    assert_eq!(LOOKUP_TABLE.lookup_nonlinear(&100i16, Extrapolation::NoneError, Interpolation::Smoothstep).unwrap(), 15.625);
+    assert_eq!(LOOKUP_TABLE.lookup_nonlinear(&100i16, Extrapolation::NoneError, Interpolation::Smootherstep).unwrap(), 10.3515625);
+    //falling segment
+    assert_eq!(LOOKUP_TABLE.lookup_nonlinear(&500i16, Extrapolation::NoneError, Interpolation::Smoothstep).unwrap(), 87.5);
+    assert_eq!("SmoothStep".parse::<Interpolation>(), Ok(Interpolation::Smoothstep));
+}
+
+#[test]
+fn smoothstep_integer_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16, i32, 3> = create_1d_lookup!((0i16,400,800), (0i32,1000,200));
+    let falling_table: OneDLookup<u16, u32, 2> = OneDLookup::try_new([400u16,800], [1000u32,200]).unwrap();
+    //the eased offset is 62.5 of 400, truncated to 62
+    assert_eq!(LOOKUP_TABLE.lookup_nonlinear(&100i16, Extrapolation::NoneError, Interpolation::Smoothstep).unwrap(), 155);
+    assert_eq!(LOOKUP_TABLE.lookup_nonlinear(&100i16, Extrapolation::NoneError, Interpolation::Smootherstep).unwrap(), 102);
+    assert_eq!(LOOKUP_TABLE.lookup_nonlinear(&500i16, Extrapolation::NoneError, Interpolation::Smoothstep).unwrap(), 876);
+    assert_eq!(falling_table.lookup_nonlinear(&500u16, Extrapolation::NoneError, Interpolation::Smoothstep).unwrap(), 876);
+    for interpolation in [Interpolation::Smoothstep, Interpolation::Smootherstep] {
+        assert_eq!(LOOKUP_TABLE.lookup_checked(&100i16, Extrapolation::NoneError, interpolation), Ok(LOOKUP_TABLE.lookup_nonlinear(&100i16, Extrapolation::NoneError, interpolation).unwrap()));
+    }
+}
+
+#[test]
+fn lookup_q16_1d() {
+    const LOOKUP_TABLE: OneDLookup<i32, i32, 3> = create_1d_lookup!((0i32,3,10), (0i32,1,-6));
+    let result1 = LOOKUP_TABLE.lookup_q16(&1i32).unwrap();
+    let result2 = LOOKUP_TABLE.lookup_q16(&5i32).unwrap();
+    let result3 = LOOKUP_TABLE.lookup_q16(&10i32).unwrap();
+    //1/3 rounded down to 16 fractional bits
+    assert_eq!(result1, 0x5555);
+    assert_eq!(result1 & 0xffff, (65536.0f64 / 3.0).floor() as i32);
+    //1 - 7 * 2/7 = -1
+    assert_eq!(result2, -0x10000);
+    assert_eq!(result3, -6 << 16);
+    assert!(LOOKUP_TABLE.lookup_q16(&11i32).is_err());
+    //values beyond 2^47 saturate instead of overflowing the shift
+    const HUGE: OneDLookup<i64, i64, 2> = create_1d_lookup!((0i64,2), (i64::MAX / 2,i64::MAX));
+    assert_eq!(HUGE.lookup_q16(&0i64).unwrap(), i32::MAX);
+    assert_eq!(HUGE.lookup_q16(&1i64).unwrap(), i32::MAX);
+}
+
+#[test]
+fn local_extrema_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16, f32, 8> = create_1d_lookup!((0i16,1,2,3,4,5,6,7), (0f32,5.0,9.0,9.0,4.0,2.0,3.0,3.0));
+    assert_eq!(LOOKUP_TABLE.local_extrema(), vec![(2, Extremum::Maximum), (5, Extremum::Minimum)]);
+    const RAMP: OneDLookup<i16, f32, 4> = create_1d_lookup!((0i16,500,4500,5000), (0f32,0.0,500.0,500.0));
+    assert!(RAMP.local_extrema().is_empty());
+}
+
+#[test]
+fn try_create_1d() {
+    let mut breakpoints = [0i16, 500, 4500, 5000];
+    breakpoints.swap(1, 2);
+    let values = [0f32, 0.0, 500.0, 500.0];
+    let lookup_table: Result<OneDLookup<i16, f32, 4>, LookupBuildError> = try_create_1d_lookup!(breakpoints, values);
+    match lookup_table {
+        Err(LookupBuildError::NotAscending { index }) => assert_eq!(index, 2),
+        other => panic!("expected NotAscending, got {:?}", other.map(|_| ())),
+    }
+    breakpoints.swap(1, 2);
+    let lookup_table: OneDLookup<i16, f32, 4> = try_create_1d_lookup!(breakpoints, values).unwrap();
+    assert_eq!(lookup_table.lookup(&2500i16, Extrapolation::NoneError, Interpolation::Linear).unwrap(), 250.0);
+    let literal: OneDLookup<i16, f32, 4> = try_create_1d_lookup!((0,500,4500,5000), (0.0,0.0,500.0,500.0)).unwrap();
+    assert_eq!(literal.to_points(), lookup_table.to_points());
+}
+
+#[test]
+fn subrange_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16, f32, 4> = create_1d_lookup!((0i16,500,4500,5000), (0f32,0.0,500.0,500.0));
+    let window = LOOKUP_TABLE.subrange(250, 4750, Interpolation::Linear);
+    assert_eq!(window.breakpoints(), &[250, 500, 4500, 4750]);
+    assert_eq!(window.values(), &[0.0, 0.0, 500.0, 500.0]);
+    let window = LOOKUP_TABLE.subrange(1000, 4000, Interpolation::Linear);
+    assert_eq!(window.breakpoints(), &[1000, 4000]);
+    assert_eq!(window.values(), &[62.5, 437.5]);
+    for bp in (1000i16..=4000).step_by(125) {
+        assert_eq!(window.lookup(&bp, Extrapolation::NoneError, Interpolation::Linear).unwrap(), LOOKUP_TABLE.lookup(&bp, Extrapolation::NoneError, Interpolation::Linear).unwrap());
+    }
+    assert!(window.lookup(&900i16, Extrapolation::NoneError, Interpolation::Linear).is_err());
+}
+
+#[test]
+#[should_panic]
+fn subrange_single_breakpoint_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16, f32, 4> = create_1d_lookup!((0i16,500,4500,5000), (0f32,0.0,500.0,500.0));
+    let _ = LOOKUP_TABLE.subrange(1000, 1000, Interpolation::Linear);
+}
+
+#[test]
+fn nan_policy_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16, f32, 4> = create_1d_lookup!((0i16,500,4500,5000), (0f32,f32::NAN,500.0,600.0));
+    let (min, max) = LOOKUP_TABLE.value_range(NanPolicy::Propagate).unwrap();
+    assert!(min.is_nan() && max.is_nan());
+    assert_eq!(LOOKUP_TABLE.value_range(NanPolicy::Ignore), Ok((0.0, 600.0)));
+    assert_eq!(LOOKUP_TABLE.value_range(NanPolicy::Error), Err(NanAt { index: 1 }));
+    assert_eq!(LOOKUP_TABLE.assert_monotone(Ordering::Less, NanPolicy::Propagate), Err(MonotoneError::NonMonotone(NonMonotoneAt { index: 1 })));
+    assert_eq!(LOOKUP_TABLE.assert_monotone(Ordering::Less, NanPolicy::Ignore), Ok(()));
+    assert_eq!(LOOKUP_TABLE.assert_monotone(Ordering::Less, NanPolicy::Error), Err(MonotoneError::Nan(NanAt { index: 1 })));
+    const ALL_NAN: OneDLookup<i16, f32, 2> = create_1d_lookup!((0i16,500), (f32::NAN,f32::NAN));
+    assert!(ALL_NAN.value_range(NanPolicy::Ignore).unwrap().0.is_nan());
+}
+
+#[test]
+fn lookup_reporting_cubic_1d() {
+    const SMALL_TABLE: OneDLookup<i16, f64, 3> = create_1d_lookup!((0i16,2,4), (0f64,4.0,16.0));
+    let (value, applied) = SMALL_TABLE.lookup_reporting(&3i16, Extrapolation::NoneError, Interpolation::Cubic).unwrap();
+    assert_eq!(applied, Interpolation::Linear);
+    assert_eq!(value, SMALL_TABLE.lookup(&3i16, Extrapolation::NoneError, Interpolation::Linear).unwrap());
+    //the cubic through the knots of x² reproduces the parabola between the inner knots
+    const SQUARES: OneDLookup<i16, f64, 4> = create_1d_lookup!((0i16,2,4,6), (0f64,4.0,16.0,36.0));
+    assert_eq!(SQUARES.lookup_reporting(&3i16, Extrapolation::NoneError, Interpolation::Cubic).unwrap(), (9.0, Interpolation::Cubic));
+    assert_eq!(SQUARES.lookup_reporting(&3i16, Extrapolation::NoneError, Interpolation::NoneFloor).unwrap(), (4.0, Interpolation::NoneFloor));
+    assert!(SQUARES.lookup_reporting(&7i16, Extrapolation::NoneError, Interpolation::Cubic).is_err());
+}
+
+#[test]
+fn cubic_unsigned_falling_1d() {
+    const FLOAT_TABLE: OneDLookup<u16, f64, 4> = create_1d_lookup!((0u16,10,20,30), (500f64,300.0,100.0,0.0));
+    let unsigned_table: OneDLookup<u16, u16, 4> = OneDLookup::try_new([0,10,20,30], [500,300,100,0]).unwrap();
+    assert_eq!(FLOAT_TABLE.lookup_nonlinear(&15u16, Extrapolation::NoneError, Interpolation::Cubic).unwrap(), 193.75);
+    //the f64 cubic is rounded towards zero
+    assert_eq!(unsigned_table.lookup_nonlinear(&15u16, Extrapolation::NoneError, Interpolation::Cubic).unwrap(), 193);
+    assert_eq!(unsigned_table.lookup_nonlinear(&5u16, Extrapolation::NoneError, Interpolation::Cubic).unwrap(), FLOAT_TABLE.lookup_nonlinear(&5u16, Extrapolation::NoneError, Interpolation::Cubic).unwrap() as u16);
+}
+
+#[test]
+fn lookup_linear_extrapolation_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16, f32, 4> = create_1d_lookup!((0i16,500,4500,5000), (0f32,0.0,500.0,500.0));
+    for breakpoint in [-1000i16, 250, 2500, 6000] {
+        assert_eq!(LOOKUP_TABLE.lookup_linear_extrapolation(&breakpoint, Interpolation::Linear), LOOKUP_TABLE.lookup(&breakpoint, Extrapolation::Linear, Interpolation::Linear).unwrap());
+    }
+}
+
+#[test]
+fn max_abs_diff_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16, f32, 4> = create_1d_lookup!((0i16,500,4500,5000), (0f32,0.0,500.0,500.0));
+    const PERTURBED: OneDLookup<i16, f32, 4> = create_1d_lookup!((0i16,500,4500,5000), (0f32,10.0,500.0,500.0));
+    assert_eq!(LOOKUP_TABLE.max_abs_diff(&LOOKUP_TABLE, 50, Interpolation::Linear), 0.0);
+    assert_eq!(LOOKUP_TABLE.max_abs_diff(&PERTURBED, 11, Interpolation::Linear), 10.0);
+    assert_eq!(PERTURBED.max_abs_diff(&LOOKUP_TABLE, 11, Interpolation::Linear), 10.0);
+    //with 2 samples only the ends are compared, which weren't changed
+    assert_eq!(LOOKUP_TABLE.max_abs_diff(&PERTURBED, 0, Interpolation::Linear), 0.0);
+    const SHIFTED: OneDLookup<i16, f32, 4> = create_1d_lookup!((6000i16,6500,7000,7500), (0f32,0.0,500.0,500.0));
+    assert!(LOOKUP_TABLE.max_abs_diff(&SHIFTED, 11, Interpolation::Linear).is_nan());
+}
+
+#[test]
+fn view_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16, f32, 4> = create_1d_lookup!((0i16,500,4500,5000), (0f32,0.0,500.0,500.0));
+    for endianness in [Endianness::Little, Endianness::Big] {
+        let bytes = LOOKUP_TABLE.to_bytes(endianness);
+        let view: OneDLookupView<i16, f32> = OneDLookupView::new(&bytes).unwrap();
+        for breakpoint in (-500i16..=5500).step_by(250) {
+            for extrapolation in [Extrapolation::NoneHoldExtreme, Extrapolation::Linear] {
+                for interpolation in [Interpolation::Linear, Interpolation::NoneClosest, Interpolation::Cubic] {
+                    assert_eq!(view.lookup(&breakpoint, extrapolation, interpolation).unwrap(), LOOKUP_TABLE.lookup(&breakpoint, extrapolation, interpolation).unwrap());
+                }
+            }
+        }
+        //the view doesn't need the buffer to be aligned for the numbers
+        let mut shifted = vec![0u8];
+        shifted.extend_from_slice(&bytes);
+        let view: OneDLookupView<i16, f32> = OneDLookupView::new(&shifted[1..]).unwrap();
+        assert_eq!(view.lookup(&2000i16, Extrapolation::NoneError, Interpolation::Linear).unwrap(), 187.5);
+    }
+    let bytes = LOOKUP_TABLE.to_bytes(Endianness::Little);
+    assert_eq!(OneDLookupView::<i16, f32>::new(&bytes[..20]).err(), Some(LookupBuildError::ShapeMismatch { expected: 19, found: 20 }));
+    assert_eq!(OneDLookupView::<i16, f32>::new(&bytes[..7]).err(), Some(LookupBuildError::ShapeMismatch { expected: 13, found: 7 }));
+    let mut unsorted = bytes.clone();
+    unsorted.swap(3, 5);
+    unsorted.swap(4, 6);
+    assert_eq!(OneDLookupView::<i16, f32>::new(&unsorted).err(), Some(LookupBuildError::NotAscending { index: 2 }));
+}
+
+#[test]
+fn is_exact_knot_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16, f32, 4> = create_1d_lookup!((0i16,500,4500,5000), (0f32,0.0,500.0,500.0));
+    assert!(LOOKUP_TABLE.is_exact_knot(&0i16));
+    assert!(LOOKUP_TABLE.is_exact_knot(&4500i16));
+    assert!(!LOOKUP_TABLE.is_exact_knot(&2500i16));
+    assert!(!LOOKUP_TABLE.is_exact_knot(&-1i16));
+    assert!(!LOOKUP_TABLE.is_exact_knot(&6000i16));
+    const FLOAT_TABLE: OneDLookup<f64, f64, 3> = create_1d_lookup!((0.0f64,0.3,1.0), (0f64,3.0,10.0));
+    assert!(FLOAT_TABLE.is_exact_knot(&-0.0f64));
+    assert!(FLOAT_TABLE.is_exact_knot(&0.3f64));
+    //0.1 + 0.2 rounds to a different f64 than 0.3
+    assert!(!FLOAT_TABLE.is_exact_knot(&(0.1f64 + 0.2)));
+    assert!(!FLOAT_TABLE.is_exact_knot(&f64::NAN));
+    assert!(!FLOAT_TABLE.is_exact_knot(&f64::MIN_POSITIVE));
+}
+
+#[test]
+fn hysteresis_1d() {
+    const RELAY: OneDLookup<i16, i16, 3> = create_1d_lookup!((0i16,500,1000), (0i16,1,2));
+    let mut relay = HysteresisLookup::new(&RELAY, 50);
+    let switch_up = (0i16..=1000).find(|query| relay.lookup(query) == 1).unwrap();
+    let switch_down = (0i16..=switch_up).rev().find(|query| relay.lookup(query) == 0).unwrap();
+    assert_eq!(switch_up, 550);
+    assert_eq!(switch_down, 450);
+    //both directions switch exactly at the margin
+    relay.reset();
+    assert_eq!(relay.lookup(&500i16), 1);
+    assert_eq!(relay.lookup(&451i16), 1);
+    assert_eq!(relay.lookup(&450i16), 0);
+    assert_eq!(relay.lookup(&549i16), 0);
+    assert_eq!(relay.lookup(&550i16), 1);
+    //a jump over several breakpoints switches straight to the last one
+    assert_eq!(relay.lookup(&1200i16), 2);
+    relay.reset();
+    assert_eq!(relay.lookup(&520i16), 1);
+}
+
+#[test]
+fn breakpoint_of_nearest_value_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16, i32, 6> = create_1d_lookup!((0i16,10,20,30,40,50), (10i32,80,30,95,5,60));
+    assert_eq!(LOOKUP_TABLE.breakpoint_of_nearest_value(&90), (3, 30));
+    assert_eq!(LOOKUP_TABLE.breakpoint_of_nearest_value(&0), (4, 40));
+    assert_eq!(LOOKUP_TABLE.breakpoint_of_nearest_value(&255), (3, 30));
+    assert_eq!(LOOKUP_TABLE.breakpoint_of_nearest_value(&60), (5, 50));
+    //45 is as close to 30 as to 60, the first knot wins
+    assert_eq!(LOOKUP_TABLE.breakpoint_of_nearest_value(&45), (2, 20));
+}
+
+#[test]
+fn lookup_via_f64_1d() {
+    const EPOCH: i64 = 1_700_000_000_000_000_000;
+    let lookup_table: OneDLookup<i64, f32, 3> = OneDLookup::try_new([EPOCH, EPOCH + 1_000, EPOCH + 3_000], [0.0, 1.0, 5.0]).unwrap();
+    //EPOCH + 500 isn't representable in an f64, the nearest ones are 256 apart
+    assert_ne!((EPOCH + 500) as f64 - EPOCH as f64, 500.0);
+    assert_eq!(lookup_table.lookup_via_f64(&(EPOCH + 500), Extrapolation::NoneError, Interpolation::Linear).unwrap(), 0.5);
+    assert_eq!(lookup_table.lookup_via_f64(&(EPOCH + 1), Extrapolation::NoneError, Interpolation::Linear).unwrap(), 0.001);
+    assert_eq!(lookup_table.lookup_via_f64(&(EPOCH + 2_000), Extrapolation::NoneError, Interpolation::Linear).unwrap(), 3.0);
+    assert_eq!(lookup_table.lookup_via_f64(&(EPOCH + 2_001), Extrapolation::NoneError, Interpolation::NoneFloor).unwrap(), 1.0);
+    assert_eq!(lookup_table.lookup_via_f64(&(EPOCH - 500), Extrapolation::Linear, Interpolation::Linear).unwrap(), -0.5);
+    assert_eq!(lookup_table.lookup_via_f64(&(EPOCH + 4_000), Extrapolation::LinearSlope { low: 0.0, high: 0.001 }, Interpolation::Linear).unwrap(), 6.0);
+    assert!(lookup_table.lookup_via_f64(&(EPOCH + 4_000), Extrapolation::NoneError, Interpolation::Linear).is_err());
+    let unsigned: OneDLookup<u64, f32, 2> = OneDLookup::try_new([u64::MAX - 1_000, u64::MAX], [0.0, 1.0]).unwrap();
+    assert_eq!(unsigned.lookup_via_f64(&(u64::MAX - 1_500), Extrapolation::Linear, Interpolation::Linear).unwrap(), -0.5);
+}
+
+#[test]
+fn normalized_axis_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16, f64, 4> = create_1d_lookup!((-1000i16,0,4000,5000), (0f64,0.0,500.0,500.0));
+    let unit_table = LOOKUP_TABLE.normalized_axis();
+    let breakpoints: Vec<f64> = unit_table.to_points().iter().map(|point| point.0).collect();
+    assert_eq!(breakpoints, vec![0.0, 1.0 / 6.0, 5.0 / 6.0, 1.0]);
+    assert_eq!(unit_table.lookup(&0.5f64, Extrapolation::NoneError, Interpolation::Linear).unwrap(), LOOKUP_TABLE.lookup(&2000i16, Extrapolation::NoneError, Interpolation::Linear).unwrap());
+    assert_eq!(unit_table.lookup(&1.0f64, Extrapolation::NoneError, Interpolation::Linear).unwrap(), 500.0);
+    const FLAT: OneDLookup<i16, f64, 2> = create_1d_lookup!((7i16,7), (1f64,2.0));
+    assert_eq!(FLAT.normalized_axis().to_points(), vec![(0.0, 1.0), (0.0, 2.0)]);
+}
+
+#[test]
+fn segments_1d() {
+    const INJECTOR_TIMES: OneDLookup<i16, f32, 4> = create_1d_lookup!((0i16,500,1000,1500), (3.0f32,4.2,5.5,6.5));
+    let slopes: Vec<f32> = INJECTOR_TIMES.segments().map(|segment| segment.slope.unwrap()).collect();
+    assert_eq!(slopes, vec![(4.2f32 - 3.0) / 500.0, (5.5f32 - 4.2) / 500.0, (6.5f32 - 5.5) / 500.0]);
+    assert!((slopes[0] - 0.0024).abs() < 1e-7 && (slopes[1] - 0.0026).abs() < 1e-7 && (slopes[2] - 0.002).abs() < 1e-7);
+    let last = INJECTOR_TIMES.segments().last().unwrap();
+    assert_eq!((last.lower, last.upper, last.lower_value, last.upper_value), (1000, 1500, 5.5, 6.5));
+    assert_eq!(INJECTOR_TIMES.segments().count(), 3);
+    for segment in INJECTOR_TIMES.segments() {
+        let middle = (segment.lower + segment.upper) / 2;
+        let from_segment = segment.lower_value + segment.slope.unwrap() * f32::from(middle - segment.lower);
+        assert!((from_segment - INJECTOR_TIMES.lookup(&middle, Extrapolation::NoneError, Interpolation::Linear).unwrap()).abs() < 1e-5);
+    }
+    //a falling segment of an unsigned type and a step between equal breakpoints have no slope
+    const STEPPED: OneDLookup<u16, u16, 4> = OneDLookup::new([0, 10, 10, 20], [0, 50, 80, 40], 10, 40, 10, 50);
+    let slopes: Vec<Option<u16>> = STEPPED.segments().map(|segment| segment.slope).collect();
+    assert_eq!(slopes, vec![Some(5), None, None]);
+    const FLOAT_STEP: OneDLookup<f32, f32, 3> = OneDLookup::new([0.0, 1.0, 1.0], [0.0, 2.0, 3.0], 0.0, 1.0, 1.0, 2.0);
+    let slopes: Vec<Option<f32>> = FLOAT_STEP.segments().map(|segment| segment.slope).collect();
+    assert_eq!(slopes, vec![Some(2.0), None]);
+}
+
+#[cfg(feature = "defmt")]
+#[test]
+fn defmt_format_errors() {
+    fn assert_format<E: defmt::Format>() {}
+    assert_format::<ExtrapolationError>();
+    assert_format::<LookupError>();
+}
+
+#[test]
+fn sample_uniform_1d() {
+    const RAMP: OneDLookup<i16, f32, 2> = create_1d_lookup!((1000i16,3000), (10f32,30.0));
+    let curve = RAMP.sample_uniform(5, Extrapolation::NoneError, Interpolation::Linear);
+    assert_eq!(curve, vec![(1000, 10.0), (1500, 15.0), (2000, 20.0), (2500, 25.0), (3000, 30.0)]);
+    assert!(curve.windows(2).all(|pair| pair[1].0 - pair[0].0 == 500));
+    assert_eq!(RAMP.sample_uniform(1, Extrapolation::NoneError, Interpolation::Linear), vec![(1000, 10.0)]);
+    assert!(RAMP.sample_uniform(0, Extrapolation::NoneError, Interpolation::Linear).is_empty());
+    const FLOAT_RAMP: OneDLookup<f64, f64, 2> = create_1d_lookup!((0.1f64,0.7), (0f64,6.0));
+    let curve = FLOAT_RAMP.sample_uniform(7, Extrapolation::NoneError, Interpolation::Linear);
+    assert_eq!(curve.last(), Some(&(0.7, 6.0)));
+}
+
+#[test]
+fn lookup_rounded_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16, i32, 3> = create_1d_lookup!((0i16,4,8), (0i32,3,-3));
+    //3 * 2 / 4 = 1.5 truncates to 1
+    assert_eq!(LOOKUP_TABLE.lookup(&2i16, Extrapolation::NoneError, Interpolation::Linear).unwrap(), 1);
+    assert_eq!(LOOKUP_TABLE.lookup_rounded(&2i16, Extrapolation::NoneError).unwrap(), 2);
+    assert_eq!(LOOKUP_TABLE.lookup_rounded(&3i16, Extrapolation::NoneError).unwrap(), 2);
+    assert_eq!(LOOKUP_TABLE.lookup_rounded(&4i16, Extrapolation::NoneError).unwrap(), 3);
+    //falling from 3 to -3, 3 - 6 * 3 / 4 = -1.5
+    assert_eq!(LOOKUP_TABLE.lookup(&7i16, Extrapolation::NoneError, Interpolation::Linear).unwrap(), -1);
+    assert_eq!(LOOKUP_TABLE.lookup_rounded(&7i16, Extrapolation::NoneError).unwrap(), -2);
+    assert!(LOOKUP_TABLE.lookup_rounded(&9i16, Extrapolation::NoneError).is_err());
+    assert_eq!(7i32.div_round(-2), -4);
+    assert_eq!(u8::MAX.div_round(2), 128);
+    assert_eq!(u8::MAX.div_round(3), 85);
+    assert_eq!(i8::MAX.div_round(2), 64);
+    assert_eq!(i8::MIN.div_round(3), -43);
+}
+
+#[test]
+fn table_meta_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16, f32, 4> = create_1d_lookup!((0i16,500,4500,5000), (0f32,0.0,500.0,500.0));
+    let annotated = LOOKUP_TABLE.with_meta(TableMeta::new("rail pressure", "mV", "bar"));
+    assert_eq!(annotated.meta(), &TableMeta { name: "rail pressure".to_string(), x_unit: "mV".to_string(), y_unit: "bar".to_string() });
+    assert_eq!(annotated.table().lookup(&2500i16, Extrapolation::NoneError, Interpolation::Linear).unwrap(), 250.0);
+    let text = annotated.to_string();
+    assert!(text.starts_with("rail pressure: 0 mV -> 0 bar"));
+    assert!(text.ends_with("5000 mV -> 500 bar"));
+    let (table, meta) = annotated.into_parts();
+    assert_eq!(table.to_points(), LOOKUP_TABLE.to_points());
+    assert_eq!(meta.y_unit, "bar");
+}
+
+#[test]
+fn redundant_knots_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16, f32, 6> = create_1d_lookup!((0i16,500,1500,2500,3500,5000), (0f32,0.0,100.0,250.0,400.0,500.0));
+    assert_eq!(LOOKUP_TABLE.redundant_knots(0.01), vec![3]);
+    assert_eq!(LOOKUP_TABLE.redundant_knots(30.0), vec![2, 3]);
+    assert!(LOOKUP_TABLE.redundant_knots(0.0).is_empty());
+    const SHORT_TABLE: OneDLookup<i16, f32, 2> = create_1d_lookup!((0i16,5000), (0f32,500.0));
+    assert!(SHORT_TABLE.redundant_knots(1.0).is_empty());
+}
+
+#[test]
+fn create_rle_1d() {
+    const EXPLICIT: OneDLookup<i16, f32, 4> = create_1d_lookup!((0i16,500,4500,5000), (0f32,0.0,500.0,500.0));
+    const RLE: OneDLookup<i16, f32, 4> = create_1d_lookup_rle!((0i16,500) => 0f32, (4500,5000) => 500.0);
+    assert_eq!(RLE.to_points(), EXPLICIT.to_points());
+    assert_eq!(RLE.lookup(&2500i16, Extrapolation::NoneError, Interpolation::Linear).unwrap(), 250.0);
+    const SINGLE_KNOTS: OneDLookup<i16, f32, 3> = create_1d_lookup_rle!((0i16) => 1f32, (10,20) => 2.0,);
+    assert_eq!(SINGLE_KNOTS.to_points(), [(0.0, 1.0), (10.0, 2.0), (20.0, 2.0)]);
+}
+
+#[test]
+fn weighted_centroid_1d() {
+    const DISTRIBUTION: OneDLookup<i16, f32, 5> = create_1d_lookup!((0i16,250,500,750,1000), (0f32,1.0,4.0,1.0,0.0));
+    assert_eq!(DISTRIBUTION.weighted_centroid(), Ok(500.0));
+    const SKEWED: OneDLookup<i16, f32, 3> = create_1d_lookup!((0i16,100,200), (3f32,1.0,0.0));
+    assert_eq!(SKEWED.weighted_centroid(), Ok(25.0));
+    const EMPTY: OneDLookup<i16, f32, 3> = create_1d_lookup!((0i16,100,200), (0f32,0.0,0.0));
+    assert_eq!(EMPTY.weighted_centroid(), Err(ZeroWeight));
+}
+
+#[test]
+fn boxed_large_1d() {
+    const COUNT: usize = 100_000;
+    let breakpoints: Vec<f64> = (0..COUNT).map(|i| i as f64).collect();
+    let values: Vec<f64> = (0..COUNT).map(|i| (i * 2) as f64).collect();
+    let lookup_table = BoxedOneDLookup::try_new(breakpoints, values).unwrap();
+    assert_eq!(lookup_table.breakpoints().len(), COUNT);
+    assert_eq!(lookup_table.lookup(&0.0f64, Extrapolation::NoneError, Interpolation::Linear).unwrap(), 0.0);
+    assert_eq!(lookup_table.lookup(&12345.5f64, Extrapolation::NoneError, Interpolation::Linear).unwrap(), 24691.0);
+    assert_eq!(lookup_table.lookup(&99999.0f64, Extrapolation::NoneError, Interpolation::Linear).unwrap(), 199998.0);
+    assert_eq!(lookup_table.lookup(&12345.4f64, Extrapolation::NoneError, Interpolation::NoneFloor).unwrap(), 24690.0);
+    assert_eq!(lookup_table.lookup(&100000.0f64, Extrapolation::Linear, Interpolation::Linear).unwrap(), 200000.0);
+    assert!(lookup_table.lookup(&-1.0f64, Extrapolation::NoneError, Interpolation::Linear).is_err());
+    assert!(BoxedOneDLookup::try_new(vec![0i16, 10, 5], vec![0.0f32, 1.0, 2.0]).is_err());
+}
+
+#[test]
+fn blend_1d() {
+    const RISING: OneDLookup<i16, f32, 3> = create_1d_lookup!((0i16,50,100), (0f32,50.0,100.0));
+    const FALLING: OneDLookup<i16, f32, 3> = create_1d_lookup!((0i16,50,100), (100f32,50.0,0.0));
+    const SHIFTED: OneDLookup<i16, f32, 3> = create_1d_lookup!((0i16,60,100), (100f32,50.0,0.0));
+    assert!(RISING.axes_match(&FALLING));
+    assert!(!RISING.axes_match(&SHIFTED));
+    let flat = RISING.blend(&FALLING, 0.5).unwrap();
+    assert_eq!(flat.to_points(), vec![(0.0, 50.0), (50.0, 50.0), (100.0, 50.0)]);
+    assert_eq!(flat.lookup(&120i16, Extrapolation::Linear, Interpolation::Linear).unwrap(), 50.0);
+    assert_eq!(RISING.blend(&FALLING, 0.0).unwrap().to_points(), RISING.to_points());
+    assert_eq!(RISING.blend(&FALLING, 1.0).unwrap().to_points(), FALLING.to_points());
+    assert!(RISING.blend(&SHIFTED, 0.5).is_none());
+    //unsigned values mix towards lower values without wrapping
+    let rising_u8: OneDLookup<u8, u8, 3> = OneDLookup::try_new([0, 50, 100], [0, 50, 100]).unwrap();
+    let falling_u8: OneDLookup<u8, u8, 3> = OneDLookup::try_new([0, 50, 100], [100, 50, 0]).unwrap();
+    assert_eq!(falling_u8.blend(&rising_u8, 1).unwrap().to_points(), rising_u8.to_points());
+    assert_eq!(rising_u8.blend(&falling_u8, 1).unwrap().to_points(), falling_u8.to_points());
+}
+
+#[test]
+fn none_floor_ceiling_boundaries_1d() {
+    const LOOKUP_TABLE: OneDLookup<f32, f32, 3> = create_1d_lookup!((1.0f32,2.0,3.0), (10.0f32,20.0,30.0));
+    let above_first = f32::from_bits(1.0f32.to_bits() + 1);
+    let below_last = f32::from_bits(3.0f32.to_bits() - 1);
+    for interpolation in [Interpolation::NoneFloor, Interpolation::NoneCeiling] {
+        //exactly on the first and last knots
+        assert_eq!(LOOKUP_TABLE.lookup(&1.0f32, Extrapolation::NoneError, interpolation).unwrap(), 10.0);
+        assert_eq!(LOOKUP_TABLE.lookup(&3.0f32, Extrapolation::NoneError, interpolation).unwrap(), 30.0);
+        assert_eq!(LOOKUP_TABLE.lookup_checked(&1.0f32, Extrapolation::NoneError, interpolation), Ok(10.0));
+        assert_eq!(LOOKUP_TABLE.lookup_checked(&3.0f32, Extrapolation::NoneError, interpolation), Ok(30.0));
+        //just outside the first and last knots
+        assert!(LOOKUP_TABLE.lookup(&f32::from_bits(1.0f32.to_bits() - 1), Extrapolation::NoneError, interpolation).is_err());
+        assert_eq!(LOOKUP_TABLE.lookup(&f32::from_bits(1.0f32.to_bits() - 1), Extrapolation::NoneHoldExtreme, interpolation).unwrap(), 10.0);
+        assert_eq!(LOOKUP_TABLE.lookup(&f32::from_bits(3.0f32.to_bits() + 1), Extrapolation::NoneHoldExtreme, interpolation).unwrap(), 30.0);
+    }
+    //just inside the first and last segments
+    assert_eq!(LOOKUP_TABLE.lookup(&above_first, Extrapolation::NoneError, Interpolation::NoneFloor).unwrap(), 10.0);
+    assert_eq!(LOOKUP_TABLE.lookup(&above_first, Extrapolation::NoneError, Interpolation::NoneCeiling).unwrap(), 20.0);
+    assert_eq!(LOOKUP_TABLE.lookup(&below_last, Extrapolation::NoneError, Interpolation::NoneFloor).unwrap(), 20.0);
+    assert_eq!(LOOKUP_TABLE.lookup(&below_last, Extrapolation::NoneError, Interpolation::NoneCeiling).unwrap(), 30.0);
+    assert_eq!(LOOKUP_TABLE.lookup_checked(&above_first, Extrapolation::NoneError, Interpolation::NoneFloor), Ok(10.0));
+    assert_eq!(LOOKUP_TABLE.lookup_checked(&below_last, Extrapolation::NoneError, Interpolation::NoneCeiling), Ok(30.0));
+    let dynamic = DynamicOneDLookup::try_new(vec![1.0f32, 2.0, 3.0], vec![10.0f32, 20.0, 30.0]).unwrap();
+    let boxed = BoxedOneDLookup::try_new(vec![1.0f32, 2.0, 3.0], vec![10.0f32, 20.0, 30.0]).unwrap();
+    assert_eq!(dynamic.lookup(&1.0f32, Extrapolation::NoneError, Interpolation::NoneFloor).unwrap(), 10.0);
+    assert_eq!(boxed.lookup(&1.0f32, Extrapolation::NoneError, Interpolation::NoneFloor).unwrap(), 10.0);
+    assert_eq!(dynamic.lookup(&above_first, Extrapolation::NoneError, Interpolation::NoneFloor).unwrap(), 10.0);
+    assert_eq!(boxed.lookup(&below_last, Extrapolation::NoneError, Interpolation::NoneCeiling).unwrap(), 30.0);
+}
+
+#[test]
+fn lookup_clamped_output_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16, f32, 3> = create_1d_lookup!((0i16,50,100), (0f32,40.0,80.0));
+    //extrapolated beyond the limits
+    assert_eq!(LOOKUP_TABLE.lookup_clamped_output(&150i16, Extrapolation::Linear, Interpolation::Linear, 5.0, 95.0).unwrap(), 95.0);
+    assert_eq!(LOOKUP_TABLE.lookup_clamped_output(&-50i16, Extrapolation::Linear, Interpolation::Linear, 5.0, 95.0).unwrap(), 5.0);
+    //in range results pass through
+    assert_eq!(LOOKUP_TABLE.lookup_clamped_output(&25i16, Extrapolation::Linear, Interpolation::Linear, 5.0, 95.0).unwrap(), 20.0);
+    assert_eq!(LOOKUP_TABLE.lookup_clamped_output(&110i16, Extrapolation::Linear, Interpolation::Linear, 5.0, 95.0).unwrap(), 88.0);
+    //an interpolated value below the limit is clamped as well
+    assert_eq!(LOOKUP_TABLE.lookup_clamped_output(&0i16, Extrapolation::NoneError, Interpolation::Linear, 5.0, 95.0).unwrap(), 5.0);
+    assert!(LOOKUP_TABLE.lookup_clamped_output(&150i16, Extrapolation::NoneError, Interpolation::Linear, 5.0, 95.0).is_err());
+}
+
+#[test]
+#[should_panic]
+fn lookup_clamped_output_inverted_limits_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16, f32, 2> = create_1d_lookup!((0i16,100), (0f32,80.0));
+    let _ = LOOKUP_TABLE.lookup_clamped_output(&50i16, Extrapolation::Linear, Interpolation::Linear, 95.0, 5.0);
+}
+
+#[test]
+fn cumulative_1d() {
+    const CONSTANT_RATE: OneDLookup<i16, i32, 5> = create_1d_lookup!((0i16,10,20,30,40), (3i32,3,3,3,3));
+    let cumulative = CONSTANT_RATE.cumulative();
+    assert_eq!(cumulative.to_points(), vec![(0.0, 3.0), (10.0, 6.0), (20.0, 9.0), (30.0, 12.0), (40.0, 15.0)]);
+    //the cached deltas follow the new values
+    assert_eq!(cumulative.lookup(&50i16, Extrapolation::Linear, Interpolation::Linear).unwrap(), 18);
+    assert_eq!(cumulative.lookup(&-10i16, Extrapolation::Linear, Interpolation::Linear).unwrap(), 0);
+    assert_eq!(cumulative.lookup(&15i16, Extrapolation::NoneError, Interpolation::Linear).unwrap(), 7);
+}
+
+#[cfg(feature = "checked_construction")]
+#[test]
+fn checked_construction_overflow_1d() {
+    let breakpoints_overflow: Result<OneDLookup<i8, i16, 3>, _> = OneDLookup::try_new([-100i8, 50, 100], [0i16, 1, 2]);
+    assert_eq!(breakpoints_overflow.err(), Some(LookupBuildError::DiffOverflow { index: 1 }));
+    let values_overflow: Result<OneDLookup<i8, i8, 3>, _> = OneDLookup::try_new([0i8, 1, 2], [0i8, 1, -128]);
+    assert_eq!(values_overflow.err(), Some(LookupBuildError::DiffOverflow { index: 2 }));
+    let fits: Result<OneDLookup<i8, i16, 3>, _> = OneDLookup::try_new([-100i8, 0, 27], [0i16, 1, 2]);
+    assert!(fits.is_ok());
+    //a falling edge is checked in its own direction, unsigned values may fall
+    let falling_signed: Result<OneDLookup<i8, i8, 2>, _> = OneDLookup::try_new([0i8, 1], [100i8, -100]);
+    assert_eq!(falling_signed.err(), Some(LookupBuildError::DiffOverflow { index: 1 }));
+    let falling_unsigned: Result<OneDLookup<u8, u8, 3>, _> = OneDLookup::try_new([0u8, 1, 2], [250u8, 0, 10]);
+    assert!(falling_unsigned.is_ok());
+}
+
+#[test]
+fn lookup_high_res_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16, i32, 3> = create_1d_lookup!((0i16,10,20), (0i32,100,300));
+    let query = 2.7f64;
+    //lookup needs the breakpoint type, so the fraction of the query is truncated away
+    assert_eq!(LOOKUP_TABLE.lookup(&(query as i16), Extrapolation::NoneError, Interpolation::Linear).unwrap(), 20);
+    assert_eq!(LOOKUP_TABLE.lookup_high_res(query, Extrapolation::NoneError, Interpolation::Linear).unwrap(), 27.0);
+    assert_eq!(LOOKUP_TABLE.lookup_high_res(15.5, Extrapolation::NoneError, Interpolation::Linear).unwrap(), 210.0);
+    assert_eq!(LOOKUP_TABLE.lookup_high_res(10.0, Extrapolation::NoneError, Interpolation::Linear).unwrap(), 100.0);
+    assert_eq!(LOOKUP_TABLE.lookup_high_res(9.5, Extrapolation::NoneError, Interpolation::NoneFloor).unwrap(), 0.0);
+    assert_eq!(LOOKUP_TABLE.lookup_high_res(9.5, Extrapolation::NoneError, Interpolation::NoneClosest).unwrap(), 100.0);
+    assert_eq!(LOOKUP_TABLE.lookup_high_res(-0.5, Extrapolation::Linear, Interpolation::Linear).unwrap(), -5.0);
+    assert_eq!(LOOKUP_TABLE.lookup_high_res(20.5, Extrapolation::LinearSlope { low: 1, high: 2 }, Interpolation::Linear).unwrap(), 301.0);
+    assert!(LOOKUP_TABLE.lookup_high_res(20.5, Extrapolation::NoneError, Interpolation::Linear).is_err());
+}
+
+#[test]
+fn argmax_argmin_1d() {
+    const INJECTOR_TIMES: OneDLookup<i16, f32, 4> = create_1d_lookup!((0i16,500,1000,1500), (3.0f32,4.2,5.5,6.5));
+    assert_eq!(INJECTOR_TIMES.argmax(), (1500, 6.5));
+    assert_eq!(INJECTOR_TIMES.argmin(), (0, 3.0));
+    const PEAKED: OneDLookup<i16, i32, 5> = create_1d_lookup!((0i16,10,20,30,40), (5i32,9,2,9,2));
+    assert_eq!(PEAKED.argmax(), (10, 9));
+    assert_eq!(PEAKED.argmin(), (20, 2));
+}
+
+#[test]
+fn interpolator_1d() {
+    fn sum_at<I: Interpolator<i16, f32>>(interpolator: &I, xs: &[i16]) -> Result<f32, ExtrapolationError> {
+        xs.iter().map(|x| interpolator.evaluate(*x)).sum()
+    }
+    const LOOKUP_TABLE: OneDLookup<i16, f32, 4> = create_1d_lookup!((0i16,500,4500,5000), (0f32,0.0,500.0,500.0));
+    //the table itself uses NoneError and Linear
+    assert_eq!(sum_at(&LOOKUP_TABLE, &[500, 2500, 4500]).unwrap(), 750.0);
+    assert!(sum_at(&LOOKUP_TABLE, &[-1]).is_err());
+    assert_eq!(LOOKUP_TABLE.evaluate(2500i16).unwrap(), 250.0);
+    //a configured table uses its own methods
+    let holding = LOOKUP_TABLE.interpolator(Extrapolation::NoneHoldExtreme, Interpolation::NoneFloor);
+    assert_eq!(sum_at(&holding, &[-1, 2500, 6000]).unwrap(), 500.0);
+}
+
+#[test]
+fn split_at_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16, f32, 4> = create_1d_lookup!((0i16,500,4500,5000), (0f32,0.0,500.0,500.0));
+    let (lower, upper) = LOOKUP_TABLE.split_at(2500, Interpolation::Linear);
+    assert_eq!(lower.breakpoints(), &[0, 500, 2500]);
+    assert_eq!(lower.values(), &[0.0, 0.0, 250.0]);
+    assert_eq!(upper.breakpoints(), &[2500, 4500, 5000]);
+    assert_eq!(upper.values(), &[250.0, 500.0, 500.0]);
+    for bp in (0i16..=5000).step_by(125) {
+        let original = LOOKUP_TABLE.lookup(&bp, Extrapolation::NoneError, Interpolation::Linear).unwrap();
+        let half = if bp <= 2500 { &lower } else { &upper };
+        assert_eq!(half.lookup(&bp, Extrapolation::NoneError, Interpolation::Linear).unwrap(), original);
+    }
+    assert!(lower.lookup(&2501i16, Extrapolation::NoneError, Interpolation::Linear).is_err());
+    assert!(upper.lookup(&2499i16, Extrapolation::NoneError, Interpolation::Linear).is_err());
+    //splitting on an existing knot doesn't duplicate it inside either half
+    let (lower, upper) = LOOKUP_TABLE.split_at(500, Interpolation::Linear);
+    assert_eq!(lower.breakpoints(), &[0, 500]);
+    assert_eq!(upper.breakpoints(), &[500, 4500, 5000]);
+}
+
+#[test]
+#[should_panic]
+fn split_at_last_breakpoint_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16, f32, 4> = create_1d_lookup!((0i16,500,4500,5000), (0f32,0.0,500.0,500.0));
+    let _ = LOOKUP_TABLE.split_at(5000, Interpolation::Linear);
+}
+
+#[test]
+fn lookup_interval_1d() {
+    const PEAKED: OneDLookup<i16, f32, 5> = create_1d_lookup!((0i16,100,200,300,400), (0f32,10.0,40.0,10.0,20.0));
+    //the interval spans the peak at 200, both ends are lower
+    let (min, max) = PEAKED.lookup_interval(&150i16, &250i16, Extrapolation::NoneError, Interpolation::Linear).unwrap();
+    assert_eq!((min, max), (25.0, 40.0));
+    //the interval spans the dip at 300
+    let (min, max) = PEAKED.lookup_interval(&250i16, &350i16, Extrapolation::NoneError, Interpolation::Linear).unwrap();
+    assert_eq!((min, max), (10.0, 25.0));
+    //without knots inside only the ends count
+    let (min, max) = PEAKED.lookup_interval(&210i16, &290i16, Extrapolation::NoneError, Interpolation::Linear).unwrap();
+    assert_eq!((min, max), (13.0, 37.0));
+    let (min, max) = PEAKED.lookup_interval(&120i16, &120i16, Extrapolation::NoneError, Interpolation::Linear).unwrap();
+    assert_eq!((min, max), (16.0, 16.0));
+    assert!(PEAKED.lookup_interval(&350i16, &450i16, Extrapolation::NoneError, Interpolation::Linear).is_err());
+    let (min, max) = PEAKED.lookup_interval(&350i16, &450i16, Extrapolation::NoneHoldExtreme, Interpolation::Linear).unwrap();
+    assert_eq!((min, max), (15.0, 20.0));
+}
+
+//tests/data/pressure_sensor.rs is the source a build script generates from tests/data/pressure_sensor.bin
+mod generated {
+    use go_lookup_tables::*;
+    include!("data/pressure_sensor.rs");
+}
+
+#[test]
+fn build_lookup_source_1d() {
+    const HAND_WRITTEN: OneDLookup<i16, f32, 4> = create_1d_lookup!((0i16,500,4500,5000), (0f32,0.0,500.0,500.0));
+    let source = build_1d_lookup_source::<i16, f32, 4>("PRESSURE_SENSOR", concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/pressure_sensor.bin")).unwrap();
+    assert_eq!(source, include_str!("data/pressure_sensor.rs"));
+    assert_eq!(generated::PRESSURE_SENSOR.to_points(), HAND_WRITTEN.to_points());
+    assert_eq!(generated::PRESSURE_SENSOR.lookup(&2500i16, Extrapolation::NoneError, Interpolation::Linear).unwrap(), 250.0);
+    //the length of the data file must match the table type
+    let wrong_length = build_1d_lookup_source::<i16, f32, 3>("PRESSURE_SENSOR", concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/pressure_sensor.bin"));
+    assert_eq!(wrong_length.unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+    assert!(build_1d_lookup_source::<i16, f32, 4>("MISSING", "tests/data/missing.bin").is_err());
+}
+
+//tests/data/const_source.rs is the source to_const_source generates for the tables of to_const_source_1d
+mod generated_const {
+    use go_lookup_tables::*;
+    include!("data/const_source.rs");
+}
+
+#[test]
+fn to_const_source_1d() {
+    //the value doesn't fit in an i32, the literals are suffixed so the macro checks it as a u64
+    const WIDE: OneDLookup<u32, u64, 2> = create_1d_lookup!((0u32,10), (0u64,5_000_000_000));
+    //create_1d_lookup! rejects the falling edge of unsigned values, so the table is written with OneDLookup::new
+    let falling: OneDLookup<u16, u16, 2> = OneDLookup::try_new([0, 10], [500, 100]).unwrap();
+    let source = WIDE.to_const_source("WIDE").unwrap() + &falling.to_const_source("FALLING").unwrap();
+    assert_eq!(source, include_str!("data/const_source.rs"));
+    assert_eq!(generated_const::WIDE.to_bytes(Endianness::Little), WIDE.to_bytes(Endianness::Little));
+    assert_eq!(generated_const::WIDE.lookup(&5u32, Extrapolation::NoneError, Interpolation::Linear).unwrap(), 2_500_000_000);
+    assert_eq!(generated_const::FALLING.to_bytes(Endianness::Little), falling.to_bytes(Endianness::Little));
+    assert_eq!(generated_const::FALLING.lookup(&12u16, Extrapolation::Linear, Interpolation::Linear).unwrap(), 20);
+    //NaN and infinite values have no literal, so the source would not compile
+    let nan_breakpoint: OneDLookup<f64, f64, 3> = OneDLookup::new([0.0, f64::NAN, 2.0], [0.0, 1.0, 2.0], 1.0, 1.0, 1.0, 1.0);
+    assert_eq!(nan_breakpoint.to_const_source("NAN"), Err(NonFiniteAt { index: 1 }));
+    let infinite_value: OneDLookup<i16, f32, 2> = OneDLookup::new([0, 10], [f32::NEG_INFINITY, 0.0], 10, 0.0, 10, 0.0);
+    assert_eq!(infinite_value.to_const_source("INFINITE"), Err(NonFiniteAt { index: 0 }));
+}
+
+#[test]
+fn linear_extrapolation_zero_edge_diff_1d() {
+    //new doesn't validate, so equal edge breakpoints reach the lookup with a zero cached delta
+    const FLOAT_TABLE: OneDLookup<f32, f32, 4> = OneDLookup::new([0.0, 0.0, 10.0, 10.0], [1.0, 2.0, 3.0, 4.0], 0.0, 1.0, 0.0, 1.0);
+    let low = FLOAT_TABLE.lookup(&-5.0f32, Extrapolation::Linear, Interpolation::Linear).unwrap();
+    let high = FLOAT_TABLE.lookup(&15.0f32, Extrapolation::Linear, Interpolation::Linear).unwrap();
+    assert_eq!((low, high), (1.0, 4.0));
+    assert_eq!(FLOAT_TABLE.lookup(&5.0f32, Extrapolation::Linear, Interpolation::Linear).unwrap(), 2.5);
+    let low_fma = FLOAT_TABLE.lookup_fma(&-5.0f32, Extrapolation::Linear, Interpolation::Linear).unwrap();
+    let high_fma = FLOAT_TABLE.lookup_fma(&15.0f32, Extrapolation::Linear, Interpolation::Linear).unwrap();
+    assert_eq!((low_fma, high_fma), (1.0, 4.0));
+    //integer division by zero would panic
+    const INT_TABLE: OneDLookup<i16, i32, 3> = OneDLookup::new([0, 0, 10], [1, 2, 12], 10, 10, 0, 1);
+    assert_eq!(INT_TABLE.lookup(&-5i16, Extrapolation::Linear, Interpolation::Linear).unwrap(), 1);
+    assert_eq!(INT_TABLE.lookup(&15i16, Extrapolation::Linear, Interpolation::Linear).unwrap(), 17);
+}
+
+#[test]
+fn contains_1d() {
+    const LOOKUP_TABLE: OneDLookup<f32, f32, 4> = create_1d_lookup!((0f32,500.0,4500.0,5000.0), (0f32,0.0,500.0,500.0));
+    assert!(LOOKUP_TABLE.contains(&0.0f32));
+    assert!(LOOKUP_TABLE.contains(&5000.0f32));
+    assert!(LOOKUP_TABLE.contains(&2500.0f32));
+    //the floats right next to the first and last breakpoints
+    assert!(!LOOKUP_TABLE.contains(&-f32::from_bits(1)));
+    assert!(!LOOKUP_TABLE.contains(&f32::from_bits(5000.0f32.to_bits() + 1)));
+    assert!(!LOOKUP_TABLE.contains(&f32::NAN));
+}
+
+#[test]
+fn smooth_1d() {
+    const SPIKED: OneDLookup<i16, f32, 9> = create_1d_lookup!((0i16,1,2,3,4,5,6,7,8), (2f32,2.0,2.0,2.0,11.0,2.0,2.0,2.0,2.0));
+    let smoothed = SPIKED.smooth(3);
+    let values: Vec<f64> = smoothed.to_points().iter().map(|(_, value)| *value).collect();
+    //the spike is spread over its neighbours
+    assert_eq!(values[4], 5.0);
+    assert_eq!((values[3], values[5]), (5.0, 5.0));
+    //flat regions, also at the shrunk edge windows, are unchanged
+    assert_eq!(values[..3], [2.0, 2.0, 2.0]);
+    assert_eq!(values[6..], [2.0, 2.0, 2.0]);
+    //even windows are centered like the next odd window, a window of 1 keeps the values
+    assert_eq!(SPIKED.smooth(2).to_points(), smoothed.to_points());
+    assert_eq!(SPIKED.smooth(1).to_points(), SPIKED.to_points());
+    assert_eq!(SPIKED.smooth(0).to_points(), SPIKED.to_points());
+    //the cached deltas follow the new values
+    assert_eq!(smoothed.lookup(&9i16, Extrapolation::Linear, Interpolation::Linear).unwrap(), 2.0);
+}
+
+/// A raw sensor reading that converts into `f64`, but can't be created from a breakpoint or negated.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+struct Reading(u16);
+
+impl From<Reading> for f64 {
+    fn from(reading: Reading) -> f64 {
+        reading.0 as f64
+    }
+}
+
+#[test]
+fn lookup_f64_1d() {
+    //new only stores the cached deltas, so the value type needs no arithmetic
+    let lookup_table: OneDLookup<i16, Reading, 3> = OneDLookup::new([0, 100, 200], [Reading(0), Reading(1000), Reading(1500)], 100, Reading(500), 100, Reading(1000));
+    assert_eq!(lookup_table.lookup_f64(&25i16, Extrapolation::NoneError, Interpolation::Linear).unwrap(), 250.0);
+    assert_eq!(lookup_table.lookup_f64(&150i16, Extrapolation::NoneError, Interpolation::Linear).unwrap(), 1250.0);
+    assert_eq!(lookup_table.lookup_f64(&150i16, Extrapolation::NoneError, Interpolation::NoneFloor).unwrap(), 1000.0);
+    assert_eq!(lookup_table.lookup_f64(&300i16, Extrapolation::Linear, Interpolation::Linear).unwrap(), 2000.0);
+    assert_eq!(lookup_table.lookup_f64(&-10i16, Extrapolation::LinearSlope { low: 2.0, high: 0.0 }, Interpolation::Linear).unwrap(), -20.0);
+    assert!(lookup_table.lookup_f64(&300i16, Extrapolation::NoneError, Interpolation::Linear).is_err());
+}
+
+#[test]
+fn lookup_percent_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16, f32, 4> = create_1d_lookup!((1000i16,1500,5500,6000), (0f32,0.0,500.0,500.0));
+    assert_eq!(LOOKUP_TABLE.lookup_percent(0.5, Interpolation::Linear), LOOKUP_TABLE.lookup(&3500i16, Extrapolation::NoneError, Interpolation::Linear).unwrap());
+    assert_eq!(LOOKUP_TABLE.lookup_percent(0.5, Interpolation::Linear), 250.0);
+    assert_eq!(LOOKUP_TABLE.lookup_percent(0.0, Interpolation::Linear), 0.0);
+    assert_eq!(LOOKUP_TABLE.lookup_percent(1.0, Interpolation::Linear), 500.0);
+    //outside of [0,1] the fraction is clamped
+    assert_eq!(LOOKUP_TABLE.lookup_percent(-0.5, Interpolation::Linear), 0.0);
+    assert_eq!(LOOKUP_TABLE.lookup_percent(1.5, Interpolation::Linear), 500.0);
+    assert_eq!(LOOKUP_TABLE.lookup_percent(f64::NAN, Interpolation::Linear), 0.0);
+    assert_eq!(LOOKUP_TABLE.lookup_percent(0.5, Interpolation::NoneCeiling), 500.0);
+}
+
+#[test]
+fn lookup_many_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16, f32, 4> = create_1d_lookup!((0i16,500,4500,5000), (0f32,0.0,500.0,500.0));
+    let pressures = LOOKUP_TABLE.lookup_many(&[-100i16, 0, 2000, 5000], Extrapolation::NoneHoldExtreme, Interpolation::Linear).unwrap();
+    assert_eq!(pressures, vec![0.0, 0.0, 187.5, 500.0]);
+    assert!(LOOKUP_TABLE.lookup_many(&[0i16, 6000], Extrapolation::NoneError, Interpolation::Linear).is_err());
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn lookup_many_par_1d() {
+    const LOOKUP_TABLE: OneDLookup<i32, f64, 4> = create_1d_lookup!((0i32,500,4500,5000), (0f64,0.0,500.0,500.0));
+    let breakpoints: Vec<i32> = (0..100_000).map(|i| i % 6000 - 500).collect();
+    let serial = LOOKUP_TABLE.lookup_many(&breakpoints, Extrapolation::Linear, Interpolation::Linear).unwrap();
+    let parallel = LOOKUP_TABLE.lookup_many_par(&breakpoints, Extrapolation::Linear, Interpolation::Linear).unwrap();
+    assert_eq!(serial, parallel);
+    assert!(LOOKUP_TABLE.lookup_many_par(&breakpoints, Extrapolation::NoneError, Interpolation::Linear).is_err());
+}
+
+#[test]
+fn monotonicity_defect_1d() {
+    const RISING: OneDLookup<i16, f32, 5> = create_1d_lookup!((0i16,500,1000,1500,2000), (0f32,100.0,97.0,200.0,199.5));
+    assert_eq!(RISING.monotonicity_defect(), 3.0);
+    const FALLING: OneDLookup<i16, i32, 4> = create_1d_lookup!((0i16,500,1000,1500), (100i32,50,52,0));
+    assert_eq!(FALLING.monotonicity_defect(), 2);
+    const MONOTONE: OneDLookup<i16, f32, 4> = create_1d_lookup!((0i16,500,4500,5000), (0f32,0.0,500.0,500.0));
+    assert_eq!(MONOTONE.monotonicity_defect(), 0.0);
+    //unsigned tables only subtract the backward steps
+    let rising_u8: OneDLookup<u8, u8, 4> = OneDLookup::try_new([0, 10, 20, 30], [0, 100, 90, 250]).unwrap();
+    assert_eq!(rising_u8.monotonicity_defect(), 10);
+    const RISING_U16: OneDLookup<u16, u16, 3> = create_1d_lookup!((0u16,10,20), (0u16,1000,60000));
+    assert_eq!(RISING_U16.monotonicity_defect(), 0);
+    let falling_u16: OneDLookup<u16, u16, 4> = OneDLookup::try_new([0, 10, 20, 30], [600, 1000, 0, 100]).unwrap();
+    assert_eq!(falling_u16.monotonicity_defect(), 400);
+}
+
+#[test]
+fn approximation_error_1d() {
+    use std::f64::consts::FRAC_PI_2;
+    //sin is most curved close to pi/2, so the last segment has the largest error
+    const SINE: OneDLookup<f64, f64, 4> = create_1d_lookup!((0.0, 0.5, 1.0, FRAC_PI_2), (0.0, 0.479425538604203, 0.8414709848078965, 1.0));
+    let errors = SINE.approximation_error(f64::sin, 50);
+    assert_eq!(errors.len(), 3);
+    assert!(errors[0] < errors[1] && errors[1] < errors[2], "errors {:?} don't grow with the curvature", errors);
+    assert!(errors.iter().all(|error| *error > 0.0 && *error < 0.05));
+    //with only the knots compared there is no error
+    assert_eq!(SINE.approximation_error(f64::sin, 0), vec![0.0; 3]);
+}
+
+#[test]
+fn lookup_angular_1d() {
+    //the shortest arc between 350 and 10 passes through 0, not 180
+    const HEADING: OneDLookup<i16, f32, 2> = create_1d_lookup!((0i16,10), (350f32,10.0));
+    assert_eq!(HEADING.lookup_angular(&5i16, Extrapolation::NoneError, 360.0).unwrap(), 0.0);
+    assert_eq!(HEADING.lookup_angular(&2i16, Extrapolation::NoneError, 360.0).unwrap(), 354.0);
+    assert_eq!(HEADING.lookup_angular(&8i16, Extrapolation::NoneError, 360.0).unwrap(), 6.0);
+    assert_eq!(HEADING.lookup(&5i16, Extrapolation::NoneError, Interpolation::Linear).unwrap(), 180.0);
+    //both directions with unsigned values, the middle segments wrap through 0 and fall so they are built at runtime
+    let forward: OneDLookup<u16, u16, 4> = OneDLookup::try_new([0, 10, 20, 30], [300, 350, 10, 40]).unwrap();
+    assert_eq!(forward.lookup_angular(&15u16, Extrapolation::NoneError, 360).unwrap(), 0);
+    assert_eq!(forward.lookup_angular(&12u16, Extrapolation::NoneError, 360).unwrap(), 354);
+    assert_eq!(forward.lookup_angular(&18u16, Extrapolation::NoneError, 360).unwrap(), 6);
+    let backward: OneDLookup<u16, u16, 4> = OneDLookup::try_new([0, 10, 20, 30], [10, 20, 340, 350]).unwrap();
+    assert_eq!(backward.lookup_angular(&15u16, Extrapolation::NoneError, 360).unwrap(), 0);
+    assert_eq!(backward.lookup_angular(&14u16, Extrapolation::NoneError, 360).unwrap(), 4);
+    assert_eq!(backward.lookup_angular(&16u16, Extrapolation::NoneError, 360).unwrap(), 356);
+    assert_eq!(backward.lookup_angular(&25u16, Extrapolation::NoneError, 360).unwrap(), 345);
+    //knots and out of range breakpoints behave like lookup
+    assert_eq!(backward.lookup_angular(&20u16, Extrapolation::NoneError, 360).unwrap(), 340);
+    assert_eq!(backward.lookup_angular(&40u16, Extrapolation::NoneHoldExtreme, 360).unwrap(), 350);
+    assert!(backward.lookup_angular(&40u16, Extrapolation::NoneError, 360).is_err());
+    //segments shorter than half a turn interpolate like lookup
+    const PHASE: OneDLookup<i16, f32, 2> = create_1d_lookup!((0i16,4), (10f32,50.0));
+    assert_eq!(PHASE.lookup_angular(&1i16, Extrapolation::NoneError, 360.0).unwrap(), PHASE.lookup(&1i16, Extrapolation::NoneError, Interpolation::Linear).unwrap());
+    //the arc is calculated in f64, so a value plus the period doesn't overflow a u8
+    let wide: OneDLookup<u8, u8, 2> = OneDLookup::try_new([0, 10], [250, 10]).unwrap();
+    assert_eq!(wide.lookup_angular(&5u8, Extrapolation::NoneError, 255).unwrap(), 2);
+    assert_eq!(wide.lookup_angular(&2u8, Extrapolation::NoneError, 255).unwrap(), 253);
+    //the interpolation method works with the lookups that calculate in f64, the others interpolate linearly
+    let angular = Interpolation::AngularLinear { period: 360.0 };
+    assert_eq!(HEADING.lookup_nonlinear(&5i16, Extrapolation::NoneError, angular).unwrap(), 0.0);
+    assert_eq!(HEADING.lookup_checked(&8i16, Extrapolation::NoneError, angular), Ok(6.0));
+    assert_eq!(backward.lookup_many(&[14u16, 16], Extrapolation::NoneError, angular).unwrap(), vec![
+        backward.lookup(&14u16, Extrapolation::NoneError, Interpolation::Linear).unwrap(),
+        backward.lookup(&16u16, Extrapolation::NoneError, Interpolation::Linear).unwrap()]);
+    assert_eq!("Angular_Linear:360".parse::<Interpolation>(), Ok(angular));
+    assert!("angular_linear:full".parse::<Interpolation>().is_err());
+}
+
+#[test]
+fn lookup_as_1d() {
+    const DAC_LEVEL: OneDLookup<i16, f32, 3> = create_1d_lookup!((0i16,500,1000), (-100f32,100.0,400.0));
+    let level: u8 = DAC_LEVEL.lookup_as(&500i16, Extrapolation::NoneError, Interpolation::Linear).unwrap();
+    assert_eq!(level, 100);
+    let level: u8 = DAC_LEVEL.lookup_as(&600i16, Extrapolation::NoneError, Interpolation::Linear).unwrap();
+    assert_eq!(level, 160);
+    //saturates at both bounds of u8
+    let level: u8 = DAC_LEVEL.lookup_as(&900i16, Extrapolation::NoneError, Interpolation::Linear).unwrap();
+    assert_eq!(level, 255);
+    let level: u8 = DAC_LEVEL.lookup_as(&2000i16, Extrapolation::Linear, Interpolation::Linear).unwrap();
+    assert_eq!(level, 255);
+    let level: u8 = DAC_LEVEL.lookup_as(&100i16, Extrapolation::NoneError, Interpolation::Linear).unwrap();
+    assert_eq!(level, 0);
+    //rounds towards zero
+    let level: u8 = DAC_LEVEL.lookup_as(&501i16, Extrapolation::NoneError, Interpolation::Linear).unwrap();
+    assert_eq!(level, 100);
+    assert!(DAC_LEVEL.lookup_as::<u8, i16>(&2000i16, Extrapolation::NoneError, Interpolation::Linear).is_err());
+    //integer results are narrowed without passing through f64, which can't hold 2^53 + 1
+    const COUNTS: OneDLookup<i64, i64, 2> = create_1d_lookup!((0i64,10), (-(1i64 << 53) - 1, (1i64 << 53) + 1));
+    let count: i64 = COUNTS.lookup_as(&10i64, Extrapolation::NoneError, Interpolation::Linear).unwrap();
+    assert_eq!(count, (1i64 << 53) + 1);
+    let count: u64 = COUNTS.lookup_as(&10i64, Extrapolation::NoneError, Interpolation::Linear).unwrap();
+    assert_eq!(count, (1u64 << 53) + 1);
+    let count: u32 = COUNTS.lookup_as(&10i64, Extrapolation::NoneError, Interpolation::Linear).unwrap();
+    assert_eq!(count, u32::MAX);
+    let count: u8 = COUNTS.lookup_as(&0i64, Extrapolation::NoneError, Interpolation::Linear).unwrap();
+    assert_eq!(count, 0);
+    let count: i16 = COUNTS.lookup_as(&0i64, Extrapolation::NoneError, Interpolation::Linear).unwrap();
+    assert_eq!(count, i16::MIN);
+}
@@ -0,0 +1,26 @@
+#![cfg(feature = "serde")]
+
+use go_lookup_tables::*;
+
+#[test]
+fn annotated_round_trip_1d() {
+    const LOOKUP_TABLE: OneDLookup<i16,f32,4> = create_1d_lookup!((0,500,4500,5000), (0.0,0.0,500.0,500.0));
+    let annotated = LOOKUP_TABLE.with_meta(TableMeta::new("pressure", "mV", "kPa"));
+    let json = serde_json::to_string(&annotated).unwrap();
+    assert_eq!(json, r#"{"table":{"breakpoints":[0,500,4500,5000],"values":[0.0,0.0,500.0,500.0]},"meta":{"name":"pressure","x_unit":"mV","y_unit":"kPa"}}"#);
+    let loaded: AnnotatedOneDLookup<i16,f32,4> = serde_json::from_str(&json).unwrap();
+    assert_eq!(loaded.meta(), annotated.meta());
+    assert_eq!(loaded.table().to_points(), LOOKUP_TABLE.to_points());
+    //the cached deltas are rebuilt, so extrapolation gives the same result
+    assert_eq!(loaded.table().lookup(&5500i16, Extrapolation::Linear, Interpolation::Linear).unwrap(), 500.0);
+}
+
+#[test]
+fn rejected_data_1d() {
+    let unsorted = serde_json::from_str::<OneDLookup<i16,f32,3>>(r#"{"breakpoints":[0,500,250],"values":[0.0,1.0,2.0]}"#);
+    assert!(unsorted.err().unwrap().to_string().starts_with(&LookupBuildError::NotAscending { index: 2 }.to_string()));
+    let short = serde_json::from_str::<OneDLookup<i16,f32,3>>(r#"{"breakpoints":[0,500],"values":[0.0,1.0]}"#);
+    assert!(short.err().unwrap().to_string().starts_with(&LookupBuildError::ShapeMismatch { expected: 3, found: 2 }.to_string()));
+    let long = serde_json::from_str::<OneDLookup<i16,f32,3>>(r#"{"breakpoints":[0,250,500,750],"values":[0.0,1.0,2.0]}"#);
+    assert!(long.err().unwrap().to_string().starts_with(&LookupBuildError::ShapeMismatch { expected: 3, found: 4 }.to_string()));
+}
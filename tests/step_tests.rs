@@ -0,0 +1,75 @@
+use go_lookup_tables::*;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum SensorState {
+    ShortToGround,
+    Ok,
+    ShortToSupply,
+}
+
+#[test]
+fn enum_values_step() {
+    const LOOKUP_TABLE: StepLookup1D<i16, SensorState, 3> = StepLookup1D::new([0, 250, 4750], [SensorState::ShortToGround, SensorState::Ok, SensorState::ShortToSupply]);
+    let result1 = LOOKUP_TABLE.lookup(&100i16, StepInterpolation::NoneFloor);
+    let result2 = LOOKUP_TABLE.lookup(&2000i16, StepInterpolation::NoneFloor);
+    let result3 = LOOKUP_TABLE.lookup(&4800i16, StepInterpolation::NoneFloor);
+    let result4 = LOOKUP_TABLE.lookup(&-5i16, StepInterpolation::NoneFloor);
+    let result5 = LOOKUP_TABLE.lookup(&100i16, StepInterpolation::NoneCeiling);
+    let result6 = LOOKUP_TABLE.lookup(&100i16, StepInterpolation::NoneClosest);
+    let result7 = LOOKUP_TABLE.lookup(&200i16, StepInterpolation::NoneClosest);
+    assert_eq!(result1, SensorState::ShortToGround);
+    assert_eq!(result2, SensorState::Ok);
+    assert_eq!(result3, SensorState::ShortToSupply);
+    assert_eq!(result4, SensorState::ShortToGround);
+    assert_eq!(result5, SensorState::Ok);
+    assert_eq!(result6, SensorState::ShortToGround);
+    assert_eq!(result7, SensorState::Ok);
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+enum Gear {
+    Reverse = -1,
+    Neutral,
+    First,
+    Second,
+    Third,
+    Fourth,
+    Fifth,
+}
+
+impl std::ops::Sub for Gear {
+    type Output = i8;
+    fn sub(self, rhs: Gear) -> i8 {
+        self as i8 - rhs as i8
+    }
+}
+
+#[test]
+fn enum_breakpoints_step() {
+    const TORQUE_LIMIT: StepLookup1D<Gear, u16, 4> = StepLookup1D::new([Gear::Reverse, Gear::First, Gear::Second, Gear::Fifth], [150, 400, 350, 250]);
+    let result1 = TORQUE_LIMIT.lookup(&Gear::Neutral, StepInterpolation::NoneClosest);
+    let result2 = TORQUE_LIMIT.lookup(&Gear::Third, StepInterpolation::NoneClosest);
+    let result3 = TORQUE_LIMIT.lookup(&Gear::Fourth, StepInterpolation::NoneClosest);
+    let result4 = TORQUE_LIMIT.lookup(&Gear::Second, StepInterpolation::NoneClosest);
+    let result5 = TORQUE_LIMIT.lookup(&Gear::Fourth, StepInterpolation::NoneFloor);
+    assert_eq!(result1, 400);
+    assert_eq!(result2, 350);
+    assert_eq!(result3, 250);
+    assert_eq!(result4, 350);
+    assert_eq!(result5, 350);
+    //lookup_floor and lookup_ceiling only compare the breakpoints, they don't need the Sub impl
+    let result6 = TORQUE_LIMIT.lookup_floor(&Gear::Neutral);
+    let result7 = TORQUE_LIMIT.lookup_ceiling(&Gear::Neutral);
+    let result8 = TORQUE_LIMIT.lookup_floor(&Gear::Fourth);
+    let result9 = TORQUE_LIMIT.lookup_ceiling(&Gear::Fourth);
+    let result10 = TORQUE_LIMIT.lookup_floor(&Gear::Second);
+    let result11 = TORQUE_LIMIT.lookup_ceiling(&Gear::Second);
+    let result12 = TORQUE_LIMIT.lookup_ceiling(&Gear::Third);
+    assert_eq!(result6, 150);
+    assert_eq!(result7, 400);
+    assert_eq!(result8, 350);
+    assert_eq!(result9, 250);
+    assert_eq!(result10, 350);
+    assert_eq!(result11, 350);
+    assert_eq!(result12, 250);
+}
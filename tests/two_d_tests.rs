@@ -126,3 +126,505 @@ fn no_interpolation_closest_2d() {
     assert_eq!(result8, 6.0f32, "2d lookup out of bounds hold failed when only the horizontal bp was above bounds");
     assert_eq!(result9, 4.2f32, "2d lookup out of bounds hold failed when only the horizontal bp was below bounds");
 }
+
+
+#[test]
+fn to_surface_2d() {
+    const LOOKUP_TABLE: TwoDLookup<i16,i8,f32,3,3> = create_2d_lookup!((0,500,1000),(0,3,6),(
+        3.0,    4.2,    5.5;
+        4.2,    5.0,    6.0;
+        5.0,    5.8,    6.5));
+    let (breakpoints_h, breakpoints_v, values) = LOOKUP_TABLE.to_surface();
+    assert_eq!(breakpoints_h, vec![0.0, 500.0, 1000.0]);
+    assert_eq!(breakpoints_v, vec![0.0, 3.0, 6.0]);
+    assert_eq!(values[1], vec![4.2f32 as f64, 5.0, 6.0]);
+}
+
+#[test]
+fn diagonal_2d() {
+    const LOOKUP_TABLE: TwoDLookup<i16,i16,f32,3,3> = create_2d_lookup!((0,500,1000),(0,3,6),(
+        3.0,    4.2,    5.5;
+        4.2,    5.0,    6.0;
+        5.0,    5.8,    6.5));
+    const NON_SQUARE_TABLE: TwoDLookup<i16,i16,f32,3,2> = create_2d_lookup!((0,500,1000),(0,3),(
+        3.0,    4.2,    5.5;
+        4.2,    5.0,    6.0));
+    let diagonal = LOOKUP_TABLE.diagonal().unwrap();
+    let result1 = diagonal.lookup(&500i16, Extrapolation::NoneError, Interpolation::Linear).unwrap();
+    let result2 = diagonal.lookup(&750i16, Extrapolation::NoneError, Interpolation::Linear).unwrap();
+    assert_eq!(result1, 5.0f32);
+    assert_eq!(result2, 5.75f32);
+    assert_eq!(NON_SQUARE_TABLE.diagonal().err(), Some(LookupBuildError::ShapeMismatch { expected: 3, found: 2 }));
+}
+
+#[test]
+fn from_uniform_2d() {
+    const LOOKUP_TABLE: TwoDLookup<i16,i8,f32,3,3> = create_2d_lookup!((0,500,1000),(0,3,6),(
+        3.0,    4.2,    5.5;
+        4.2,    5.0,    6.0;
+        5.0,    5.8,    6.5));
+    let uniform_table: TwoDLookup<i16,i8,f32,3,3> = TwoDLookup::from_uniform(0, 500, 0, 3, [
+        [3.0,   4.2,    5.5],
+        [4.2,   5.0,    6.0],
+        [5.0,   5.8,    6.5]]);
+    for (h, v) in [(750i16, 4i8), (1250, 7), (-250, 2), (500, 3), (100, 5)] {
+        assert_eq!(uniform_table.lookup(&h, &v, Interpolation::Linear).unwrap(), LOOKUP_TABLE.lookup(&h, &v, Interpolation::Linear).unwrap());
+        assert_eq!(uniform_table.lookup(&h, &v, Interpolation::NoneClosest).unwrap(), LOOKUP_TABLE.lookup(&h, &v, Interpolation::NoneClosest).unwrap());
+        assert_eq!(UNIFORM_TABLE.lookup(&h, &v, Interpolation::Linear).unwrap(), LOOKUP_TABLE.lookup(&h, &v, Interpolation::Linear).unwrap());
+    }
+}
+
+const UNIFORM_TABLE: TwoDLookup<i16,i8,f32,3,3> = create_uniform_2d_lookup!((0,500),(0,3),(
+    3.0,    4.2,    5.5;
+    4.2,    5.0,    6.0;
+    5.0,    5.8,    6.5));
+
+#[test]
+fn from_uniform_float_steps_2d() {
+    //0.1 can't be represented exactly, so the calculated index has to be corrected at the breakpoints
+    let values = [[0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0], [10.0, 11.0, 12.0, 13.0, 14.0, 15.0, 16.0, 17.0, 18.0, 19.0, 20.0]];
+    let uniform_table: TwoDLookup<f64,f64,f64,11,2> = TwoDLookup::from_uniform(0.0, 0.1, -1.0, 2.0, values);
+    let (breakpoints_h, breakpoints_v) = uniform_table.axes();
+    let searched_table: TwoDLookup<f64,f64,f64,11,2> = TwoDLookup::new(breakpoints_h, breakpoints_v, values);
+    for h in (-5..120).map(|h| h as f64 * 0.01).chain(breakpoints_h) {
+        for interpolation in [Interpolation::Linear, Interpolation::NoneCeiling, Interpolation::NoneFloor, Interpolation::NoneClosest] {
+            assert_eq!(uniform_table.lookup(&h, &0.5, interpolation).unwrap(), searched_table.lookup(&h, &0.5, interpolation).unwrap());
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn from_uniform_overflow_2d() {
+    let _: TwoDLookup<i8,i8,f32,3,2> = TwoDLookup::from_uniform(0i8, 100, 0, 1, [
+        [3.0,   4.2,    5.5],
+        [4.2,   5.0,    6.0]]);
+}
+
+#[test]
+fn to_macro_source_2d() {
+    const LOOKUP_TABLE: TwoDLookup<i16,i8,f32,3,3> = create_2d_lookup!((0,500,1000),(0,3,6),(
+        3.0,    4.2,    5.5;
+        4.2,    5.0,    6.0;
+        5.0,    5.8,    6.5));
+    //the generated source pasted back into the macro
+    const GENERATED_TABLE: TwoDLookup<i16,i8,f32,3,3> = create_2d_lookup!((0, 500, 1000), (0, 3, 6), (3.0, 4.2, 5.5; 4.2, 5.0, 6.0; 5.0, 5.8, 6.5));
+    assert_eq!(LOOKUP_TABLE.to_macro_source(), "create_2d_lookup!((0, 500, 1000), (0, 3, 6), (3.0, 4.2, 5.5; 4.2, 5.0, 6.0; 5.0, 5.8, 6.5))");
+    assert_eq!(GENERATED_TABLE.to_surface(), LOOKUP_TABLE.to_surface());
+}
+
+#[test]
+fn prepared_row_2d() {
+    const LOOKUP_TABLE: TwoDLookup<i16,i8,f32,3,3> = create_2d_lookup!((0,500,1000),(0,3,6),(
+        3.0,    4.2,    5.5;
+        4.2,    5.0,    6.0;
+        5.0,    5.8,    6.5));
+    for breakpoint_v in [-1i8, 0, 2, 4, 6, 7] {
+        let linear_row = LOOKUP_TABLE.prepared_at_v(&breakpoint_v, Interpolation::Linear);
+        let closest_row = LOOKUP_TABLE.prepared_at_v(&breakpoint_v, Interpolation::NoneClosest);
+        let smooth_row = LOOKUP_TABLE.prepared_at_v_nonlinear(&breakpoint_v, Interpolation::Smoothstep);
+        for breakpoint_h in [-250i16, 0, 250, 500, 750, 1000, 1250] {
+            assert_eq!(linear_row.lookup_h(&breakpoint_h).unwrap(), LOOKUP_TABLE.lookup(&breakpoint_h, &breakpoint_v, Interpolation::Linear).unwrap());
+            assert_eq!(closest_row.lookup_h(&breakpoint_h).unwrap(), LOOKUP_TABLE.lookup(&breakpoint_h, &breakpoint_v, Interpolation::NoneClosest).unwrap());
+            assert_eq!(smooth_row.lookup_h(&breakpoint_h).unwrap(), LOOKUP_TABLE.lookup_nonlinear(&breakpoint_h, &breakpoint_v, Interpolation::Smoothstep).unwrap());
+            //prepared_at_v interpolates linearly like lookup
+            assert_eq!(LOOKUP_TABLE.prepared_at_v(&breakpoint_v, Interpolation::Smoothstep).lookup_h(&breakpoint_h).unwrap(), LOOKUP_TABLE.lookup(&breakpoint_h, &breakpoint_v, Interpolation::Smoothstep).unwrap());
+        }
+    }
+    //the pre-eased vertical fraction rounds like a full lookup for integer values too
+    const INTEGER_TABLE: TwoDLookup<i16,i16,i32,2,2> = create_2d_lookup!((0,100),(0,30),(
+        0,      1000;
+        300,    -700));
+    let row = INTEGER_TABLE.prepared_at_v_nonlinear(&7i16, Interpolation::Smoothstep);
+    for breakpoint_h in [0i16, 13, 50, 99] {
+        assert_eq!(row.lookup_h(&breakpoint_h).unwrap(), INTEGER_TABLE.lookup_nonlinear(&breakpoint_h, &7i16, Interpolation::Smoothstep).unwrap());
+    }
+}
+
+#[test]
+fn checked_lookup_2d() {
+    const LOOKUP_TABLE: TwoDLookup<i16,i8,f32,3,3> = create_2d_lookup!((0,500,1000),(0,3,6),(
+        3.0,    4.2,    5.5;
+        4.2,    5.0,    6.0;
+        5.0,    5.8,    6.5));
+    const OVERFLOW_TABLE: TwoDLookup<i16,i16,i16,2,2> = create_2d_lookup!((0,1000),(0,10),(
+        0,      1000;
+        0,      1000));
+    for (h, v) in [(750i16, 4i8), (1250, 7), (-250, 2), (500, 3), (100, 5)] {
+        assert_eq!(LOOKUP_TABLE.lookup_checked(&h, &v, Interpolation::Linear).unwrap(), LOOKUP_TABLE.lookup(&h, &v, Interpolation::Linear).unwrap());
+        assert_eq!(LOOKUP_TABLE.lookup_checked(&h, &v, Interpolation::NoneClosest).unwrap(), LOOKUP_TABLE.lookup(&h, &v, Interpolation::NoneClosest).unwrap());
+    }
+    //500 * 1000 overflows the multiplication
+    assert_eq!(OVERFLOW_TABLE.lookup_checked(&500i16, &5i16, Interpolation::Linear), Err(LookupError::ArithmeticOverflow));
+    assert_eq!(OVERFLOW_TABLE.lookup_checked(&500i16, &5i16, Interpolation::NoneFloor), Ok(0));
+}
+
+#[test]
+fn try_from_rows_2d() {
+    const LOOKUP_TABLE: TwoDLookup<i16,i8,f32,3,3> = create_2d_lookup!((0,500,1000),(0,3,6),(
+        3.0,    4.2,    5.5;
+        4.2,    5.0,    6.0;
+        5.0,    5.8,    6.5));
+    let rows = vec![vec![3.0, 4.2, 5.5], vec![4.2, 5.0, 6.0], vec![5.0, 5.8, 6.5]];
+    let lookup_table: TwoDLookup<i16,i8,f32,3,3> = TwoDLookup::try_from_rows([0,500,1000], [0,3,6], &rows).unwrap();
+    assert_eq!(lookup_table.to_surface(), LOOKUP_TABLE.to_surface());
+
+    let jagged = vec![vec![3.0, 4.2, 5.5], vec![4.2, 5.0, 6.0, 7.0], vec![5.0, 5.8, 6.5]];
+    let too_few_rows = vec![vec![3.0, 4.2, 5.5], vec![4.2, 5.0, 6.0]];
+    let result1: Result<TwoDLookup<i16,i8,f32,3,3>, _> = TwoDLookup::try_from_rows([0,500,1000], [0,3,6], &jagged);
+    let result2: Result<TwoDLookup<i16,i8,f32,3,3>, _> = TwoDLookup::try_from_rows([0,500,1000], [0,3,6], &too_few_rows);
+    let result3: Result<TwoDLookup<i16,i8,f32,3,3>, _> = TwoDLookup::try_from_rows([0,1000,500], [0,3,6], &rows);
+    assert_eq!(result1.err(), Some(LookupBuildError::ShapeMismatch { expected: 3, found: 4 }));
+    assert_eq!(result2.err(), Some(LookupBuildError::ShapeMismatch { expected: 3, found: 2 }));
+    assert_eq!(result3.err(), Some(LookupBuildError::NotAscending { index: 2 }));
+}
+
+#[test]
+fn try_from_flat_2d() {
+    let values = [3.0, 4.2, 5.5, 4.2, 5.0, 6.0, 5.0, 5.8, 6.5];
+    let lookup_table: TwoDLookup<i16,i8,f32,3,3> = TwoDLookup::try_from_flat([0,500,1000], [0,3,6], &values).unwrap();
+    assert_eq!(lookup_table.lookup(&750i16, &4i8, Interpolation::Linear).unwrap(), 5.7166667f32);
+
+    let result1: Result<TwoDLookup<i16,i8,f32,3,3>, _> = TwoDLookup::try_from_flat([0,500,1000], [0,3,6], &values[..7]);
+    let result2: Result<TwoDLookup<i16,i8,f32,3,3>, _> = TwoDLookup::try_from_flat([0,500,1000], [0,3,6], &values[..6]);
+    assert_eq!(result1.err(), Some(LookupBuildError::ShapeMismatch { expected: 3, found: 1 }));
+    assert_eq!(result2.err(), Some(LookupBuildError::ShapeMismatch { expected: 3, found: 2 }));
+}
+
+#[test]
+fn from_rows_2d() {
+    const LOOKUP_TABLE: TwoDLookup<i16,i8,f32,3,3> = create_2d_lookup!((0,500,1000),(0,3,6),(
+        3.0,    4.2,    5.5;
+        4.2,    5.0,    6.0;
+        5.0,    5.8,    6.5));
+    const ROW_0: OneDLookup<i16,f32,3> = create_1d_lookup!((0i16,500,1000), (3.0f32,4.2,5.5));
+    const ROW_3: OneDLookup<i16,f32,3> = create_1d_lookup!((0i16,500,1000), (4.2f32,5.0,6.0));
+    const ROW_6: OneDLookup<i16,f32,3> = create_1d_lookup!((0i16,500,1000), (5.0f32,5.8,6.5));
+    const SHIFTED_ROW: OneDLookup<i16,f32,3> = create_1d_lookup!((0i16,600,1000), (5.0f32,5.8,6.5));
+    let lookup_table: TwoDLookup<i16,i8,f32,3,3> = TwoDLookup::from_rows([0,3,6], [ROW_0, ROW_3, ROW_6]).unwrap();
+    for (h, v) in [(0i16, 0i8), (250, 1), (750, 4), (1000, 6), (-100, 9)] {
+        assert_eq!(lookup_table.lookup(&h, &v, Interpolation::Linear).unwrap(), LOOKUP_TABLE.lookup(&h, &v, Interpolation::Linear).unwrap());
+    }
+
+    let result1: Result<TwoDLookup<i16,i8,f32,3,3>, _> = TwoDLookup::from_rows([0,3,6], [ROW_0, ROW_3, SHIFTED_ROW]);
+    let result2: Result<TwoDLookup<i16,i8,f32,3,3>, _> = TwoDLookup::from_rows([0,6,3], [ROW_0, ROW_3, ROW_6]);
+    assert_eq!(result1.err(), Some(LookupBuildError::BreakpointMismatch { row: 2 }));
+    assert_eq!(result2.err(), Some(LookupBuildError::NotAscending { index: 2 }));
+}
+
+#[test]
+fn no_interpolation_closest_is_euclidean_nearest_2d() {
+    const LOOKUP_TABLE: TwoDLookup<f64,f64,f64,4,3> = create_2d_lookup!((0.0,100.0,150.0,1000.0),(0.0,2.0,10.0),(
+        0.0,    1.0,    2.0,    3.0;
+        4.0,    5.0,    6.0,    7.0;
+        8.0,    9.0,    10.0,   11.0));
+    let breakpoints_h = [0.0, 100.0, 150.0, 1000.0];
+    let breakpoints_v = [0.0, 2.0, 10.0];
+    for (h, v) in [(124.0, 1.1), (126.0, 0.9), (51.0, 5.9), (149.0, 6.1), (560.0, 1.2), (99.0, 9.9)] {
+        let mut nearest = (f64::MAX, 0.0);
+        for (index_v, bp_v) in breakpoints_v.iter().enumerate() {
+            for (index_h, bp_h) in breakpoints_h.iter().enumerate() {
+                let distance = (h - bp_h) * (h - bp_h) + (v - bp_v) * (v - bp_v);
+                if distance < nearest.0 {
+                    nearest = (distance, (index_v * 4 + index_h) as f64);
+                }
+            }
+        }
+        assert_eq!(LOOKUP_TABLE.lookup(&h, &v, Interpolation::NoneClosest).unwrap(), nearest.1);
+    }
+}
+
+#[test]
+fn lookup_with_corners_2d() {
+    const LOOKUP_TABLE: TwoDLookup<i16,i8,f32,3,3> = create_2d_lookup!((0,500,1000),(0,2,6),(
+        3.0,    4.2,    5.5;
+        4.2,    5.0,    6.0;
+        5.0,    5.8,    6.5));
+    let (result1, corners1) = LOOKUP_TABLE.lookup_with_corners(&750i16, &4i8);
+    assert_eq!(result1, LOOKUP_TABLE.lookup(&750i16, &4i8, Interpolation::Linear).unwrap());
+    assert_eq!(corners1, [(1, 1, 0.25), (2, 1, 0.25), (1, 2, 0.25), (2, 2, 0.25)]);
+
+    let (result2, corners2) = LOOKUP_TABLE.lookup_with_corners(&500i16, &6i8);
+    assert_eq!(result2, 5.8);
+    assert_eq!(corners2.iter().map(|corner| corner.2).sum::<f64>(), 1.0);
+    assert_eq!(corners2[0], (1, 2, 1.0));
+}
+
+#[test]
+fn lookup_typed_2d() {
+    const LOOKUP_TABLE: TwoDLookup<i16,i16,f32,3,3> = create_2d_lookup!((0,500,1000),(0,3,6),(
+        3.0,    4.2,    5.5;
+        4.2,    5.0,    6.0;
+        5.0,    5.8,    6.5));
+    let rpm = Horizontal(750i16);
+    let throttle_pos = Vertical(4i16);
+    assert_eq!(LOOKUP_TABLE.lookup_typed(rpm, throttle_pos, Interpolation::Linear).unwrap(), LOOKUP_TABLE.lookup(&750i16, &4i16, Interpolation::Linear).unwrap());
+}
+
+/// A Q8 fixed-point number, which can't be converted to `f64`.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+struct Fixed(i32);
+
+impl From<i8> for Fixed {
+    fn from(value: i8) -> Fixed { Fixed(i32::from(value) << 8) }
+}
+
+impl std::ops::Add for Fixed {
+    type Output = Fixed;
+    fn add(self, other: Fixed) -> Fixed { Fixed(self.0 + other.0) }
+}
+
+impl std::ops::Sub for Fixed {
+    type Output = Fixed;
+    fn sub(self, other: Fixed) -> Fixed { Fixed(self.0 - other.0) }
+}
+
+impl std::ops::Mul for Fixed {
+    type Output = Fixed;
+    fn mul(self, other: Fixed) -> Fixed { Fixed((self.0 * other.0) >> 8) }
+}
+
+impl std::ops::Div for Fixed {
+    type Output = Fixed;
+    fn div(self, other: Fixed) -> Fixed { Fixed((self.0 << 8) / other.0) }
+}
+
+impl std::ops::Neg for Fixed {
+    type Output = Fixed;
+    fn neg(self) -> Fixed { Fixed(-self.0) }
+}
+
+impl EdgeDelta for Fixed {}
+
+#[test]
+fn fixed_point_2d() {
+    let lookup_table: TwoDLookup<Fixed,Fixed,Fixed,2,2> = TwoDLookup::try_new([Fixed::from(0), Fixed::from(10)], [Fixed::from(0), Fixed::from(10)], [
+        [Fixed::from(0), Fixed::from(100)],
+        [Fixed::from(100), Fixed::from(120)]]).unwrap();
+    assert_eq!(lookup_table.lookup(&Fixed::from(5), &Fixed::from(5), Interpolation::Linear).unwrap(), Fixed(80 << 8));
+    assert_eq!(lookup_table.prepared_at_v(&Fixed::from(5), Interpolation::Linear).lookup_h(&Fixed::from(5)).unwrap(), Fixed(80 << 8));
+    //the methods that are calculated in f64 interpolate linearly
+    assert_eq!(lookup_table.lookup(&Fixed::from(5), &Fixed::from(0), Interpolation::Smoothstep).unwrap(), Fixed(50 << 8));
+}
+
+#[test]
+fn smoothstep_2d() {
+    const LOOKUP_TABLE: TwoDLookup<i16,i8,f32,2,2> = create_2d_lookup!((0,400),(0,4),(
+        0.0,    100.0;
+        100.0,  200.0));
+    assert_eq!(LOOKUP_TABLE.lookup_nonlinear(&200i16, &2i8, Interpolation::Smoothstep).unwrap(), 100.0);
+    assert_eq!(LOOKUP_TABLE.lookup_nonlinear(&100i16, &0i8, Interpolation::Smoothstep).unwrap(), 15.625);
+    assert_eq!(LOOKUP_TABLE.lookup_nonlinear(&100i16, &1i8, Interpolation::Smoothstep).unwrap(), 31.25);
+    //lookup doesn't calculate in f64, so it interpolates linearly
+    assert_eq!(LOOKUP_TABLE.lookup(&100i16, &0i8, Interpolation::Smoothstep).unwrap(), 25.0);
+    assert_eq!(LOOKUP_TABLE.lookup_checked(&100i16, &1i8, Interpolation::Smoothstep), Ok(31.25));
+}
+
+#[test]
+fn angular_linear_2d() {
+    const HEADING: TwoDLookup<i16,i16,f32,2,2> = create_2d_lookup!((0,10),(0,10),(
+        350.0,  10.0;
+        10.0,   30.0));
+    let angular = Interpolation::AngularLinear { period: 360.0 };
+    assert_eq!(HEADING.lookup_nonlinear(&5i16, &0i16, angular).unwrap(), 0.0);
+    assert_eq!(HEADING.lookup_nonlinear(&0i16, &5i16, angular).unwrap(), 0.0);
+    assert_eq!(HEADING.lookup_nonlinear(&5i16, &10i16, angular).unwrap(), 20.0);
+    assert_eq!(HEADING.lookup_nonlinear(&5i16, &5i16, angular).unwrap(), 10.0);
+    assert_eq!(HEADING.lookup_checked(&5i16, &5i16, angular), Ok(10.0));
+}
+
+#[test]
+fn try_create_2d() {
+    let breakpoints_v = [0i8, 6, 3];
+    let values = [
+        [3.0f32, 4.2, 5.5],
+        [4.2, 5.0, 6.0],
+        [5.0, 5.8, 6.5]];
+    let result: Result<TwoDLookup<i16,i8,f32,3,3>, LookupBuildError> = try_create_2d_lookup!([0, 500, 1000], breakpoints_v, values);
+    assert_eq!(result.err(), Some(LookupBuildError::NotAscending { index: 2 }));
+    let lookup_table: TwoDLookup<i16,i8,f32,3,3> = try_create_2d_lookup!((0,500,1000),(0,3,6),(
+        3.0,    4.2,    5.5;
+        4.2,    5.0,    6.0;
+        5.0,    5.8,    6.5)).unwrap();
+    assert_eq!(lookup_table.lookup(&500i16, &3i8, Interpolation::Linear).unwrap(), 5.0);
+}
+
+#[test]
+fn lookup_nearest_four_2d() {
+    const LOOKUP_TABLE: TwoDLookup<i16,i8,f64,3,3> = create_2d_lookup!((0,500,1000),(0,4,8),(
+        3.0,    4.2,    5.5;
+        4.2,    5.0,    6.0;
+        5.0,    5.8,    6.5));
+    //at the center of a cell every corner is equally far away
+    assert_eq!(LOOKUP_TABLE.lookup_nearest_four(&250i16, &2i8), LOOKUP_TABLE.lookup(&250i16, &2i8, Interpolation::Linear).unwrap());
+    assert_eq!(LOOKUP_TABLE.lookup_nearest_four(&750i16, &6i8), (5.0 + 6.0 + 5.8 + 6.5) / 4.0);
+    //off center the weights aren't separable anymore
+    let off_center = LOOKUP_TABLE.lookup_nearest_four(&125i16, &1i8);
+    assert!((off_center - LOOKUP_TABLE.lookup(&125i16, &1i8, Interpolation::Linear).unwrap()).abs() > 1e-3);
+    assert!(off_center > 3.0 && off_center < 5.0);
+    //knots and gridlines
+    assert_eq!(LOOKUP_TABLE.lookup_nearest_four(&500i16, &4i8), 5.0);
+    assert_eq!(LOOKUP_TABLE.lookup_nearest_four(&500i16, &2i8), (4.2 + 5.0) / 2.0);
+    assert_eq!(LOOKUP_TABLE.lookup_nearest_four(&-100i16, &8i8), 5.0);
+    assert!(!LOOKUP_TABLE.lookup_nearest_four(&1200i16, &3i8).is_nan());
+}
+
+#[test]
+fn transpose_2d() {
+    const LOOKUP_TABLE: TwoDLookup<i16,i8,f64,3,2> = create_2d_lookup!((0,500,1000),(0,8),(
+        3.0,    4.2,    5.5;
+        5.0,    5.8,    6.5));
+    let transposed: TwoDLookup<i8,i16,f64,2,3> = LOOKUP_TABLE.transpose();
+    for (h, v) in [(0i16, 0i8), (250, 2), (500, 8), (750, 5), (1000, 3), (-100, 9), (1200, -1)] {
+        for interpolation in [Interpolation::Linear, Interpolation::NoneFloor, Interpolation::NoneCeiling, Interpolation::NoneClosest] {
+            assert_eq!(transposed.lookup(&v, &h, interpolation).unwrap(), LOOKUP_TABLE.lookup(&h, &v, interpolation).unwrap());
+        }
+    }
+    assert_eq!(transposed.transpose().to_surface(), LOOKUP_TABLE.to_surface());
+}
+
+#[test]
+fn none_floor_ceiling_boundaries_2d() {
+    const LOOKUP_TABLE: TwoDLookup<i16,i8,f32,3,2> = create_2d_lookup!((0,500,1000),(0,8),(
+        3.0,    4.2,    5.5;
+        5.0,    5.8,    6.5));
+    for interpolation in [Interpolation::NoneFloor, Interpolation::NoneCeiling] {
+        //exactly on the corner knots
+        assert_eq!(LOOKUP_TABLE.lookup(&0i16, &0i8, interpolation).unwrap(), 3.0);
+        assert_eq!(LOOKUP_TABLE.lookup(&1000i16, &8i8, interpolation).unwrap(), 6.5);
+        assert_eq!(LOOKUP_TABLE.lookup(&0i16, &8i8, interpolation).unwrap(), 5.0);
+        assert_eq!(LOOKUP_TABLE.lookup(&1000i16, &0i8, interpolation).unwrap(), 5.5);
+        //just outside the first and last knots, both axes hold their extremes
+        assert_eq!(LOOKUP_TABLE.lookup(&-1i16, &-1i8, interpolation).unwrap(), 3.0);
+        assert_eq!(LOOKUP_TABLE.lookup(&1001i16, &9i8, interpolation).unwrap(), 6.5);
+    }
+    //just inside the first and last cells
+    assert_eq!(LOOKUP_TABLE.lookup(&1i16, &1i8, Interpolation::NoneFloor).unwrap(), 3.0);
+    assert_eq!(LOOKUP_TABLE.lookup(&1i16, &1i8, Interpolation::NoneCeiling).unwrap(), 5.8);
+    assert_eq!(LOOKUP_TABLE.lookup(&999i16, &7i8, Interpolation::NoneFloor).unwrap(), 4.2);
+    assert_eq!(LOOKUP_TABLE.lookup(&999i16, &7i8, Interpolation::NoneCeiling).unwrap(), 6.5);
+}
+
+#[test]
+fn contains_2d() {
+    const LOOKUP_TABLE: TwoDLookup<i16,i8,f32,3,2> = create_2d_lookup!((0,500,1000),(0,8),(
+        3.0,    4.2,    5.5;
+        5.0,    5.8,    6.5));
+    assert!(LOOKUP_TABLE.contains(&0i16, &0i8));
+    assert!(LOOKUP_TABLE.contains(&1000i16, &8i8));
+    assert!(LOOKUP_TABLE.contains(&0i16, &8i8));
+    assert!(LOOKUP_TABLE.contains(&250i16, &4i8));
+    assert!(!LOOKUP_TABLE.contains(&-1i16, &4i8));
+    assert!(!LOOKUP_TABLE.contains(&1001i16, &4i8));
+    assert!(!LOOKUP_TABLE.contains(&250i16, &-1i8));
+    assert!(!LOOKUP_TABLE.contains(&250i16, &9i8));
+}
+
+#[test]
+fn lookup_table_trait_1d_2d() {
+    fn cells<L: LookupTable>(table: &L) -> usize {
+        table.axis_lengths().iter().product()
+    }
+    const ONE_D: OneDLookup<i16, f32, 4> = create_1d_lookup!((0i16,500,4500,5000), (0f32,0.0,500.0,500.0));
+    const TWO_D: TwoDLookup<i16,i8,f32,3,2> = create_2d_lookup!((0,500,1000),(0,8),(
+        3.0,    4.2,    5.5;
+        5.0,    5.8,    6.5));
+    assert_eq!(cells(&ONE_D), 4);
+    assert_eq!(cells(&TWO_D), 6);
+    assert_eq!((ONE_D.dimensions(), TWO_D.dimensions()), (1, 2));
+    assert_eq!(ONE_D.axes(), [0, 500, 4500, 5000]);
+    assert_eq!(TWO_D.axes(), ([0, 500, 1000], [0, 8]));
+    assert_eq!(ONE_D.knots().len(), cells(&ONE_D));
+    assert_eq!(TWO_D.knots()[..4], [((0, 0), 3.0), ((500, 0), 4.2), ((1000, 0), 5.5), ((0, 8), 5.0)]);
+}
+
+#[test]
+fn no_interpolation_closest_midpoints_2d() {
+    //spans of odd and even length, the value encodes the index of the knot as vertical * 10 + horizontal
+    const LOOKUP_TABLE: TwoDLookup<i16,i8,f32,3,3> = create_2d_lookup!((0,4,9),(0,3,5),(
+        0.0,    1.0,    2.0;
+        10.0,   11.0,   12.0;
+        20.0,   21.0,   22.0));
+    //the nearest knot with the exact midpoint rounding up, and the earlier rounding by comparing with the halved span
+    fn closest(breakpoints: &[i16], breakpoint: i16) -> (usize, usize) {
+        let index = breakpoints.iter().position(|bp| *bp >= breakpoint).unwrap();
+        if index == 0 || breakpoints[index] == breakpoint {
+            return (index, index)
+        }
+        let diff = breakpoint - breakpoints[index-1];
+        let span = breakpoints[index] - breakpoints[index-1];
+        let exact = if 2 * diff >= span { index } else { index - 1 };
+        let halved = if span - diff > span / 2 { index - 1 } else { index };
+        (exact, halved)
+    }
+    for h in 0i16..=9 {
+        for v in 0i8..=5 {
+            let (exact_h, halved_h) = closest(&[0, 4, 9], h);
+            let (exact_v, halved_v) = closest(&[0, 3, 5], v as i16);
+            assert_eq!((exact_h, exact_v), (halved_h, halved_v), "integer rounding differs at ({}, {})", h, v);
+            let result = LOOKUP_TABLE.lookup(&h, &v, Interpolation::NoneClosest).unwrap();
+            assert_eq!(result, (exact_v * 10 + exact_h) as f32, "2d lookup closest picked the wrong knot at ({}, {})", h, v);
+        }
+    }
+}
+
+#[test]
+fn dynamic_2d() {
+    //the first line holds the horizontal breakpoints, every other line a vertical breakpoint and its row of values
+    let mut lines = include_str!("data/injector_map.csv").lines();
+    let breakpoints_h: Vec<i16> = lines.next().unwrap().split(',').skip(1).map(|bp| bp.parse().unwrap()).collect();
+    let mut breakpoints_v: Vec<i8> = Vec::new();
+    let mut values: Vec<f32> = Vec::new();
+    for line in lines {
+        let mut fields = line.split(',');
+        breakpoints_v.push(fields.next().unwrap().parse().unwrap());
+        values.extend(fields.map(|value| value.parse::<f32>().unwrap()));
+    }
+    let dynamic = DynamicTwoDLookup::try_new(breakpoints_h, breakpoints_v, values).unwrap();
+    const FIXED: TwoDLookup<i16,i8,f32,3,3> = create_2d_lookup!((0,500,1000),(0,3,6),(
+        3.0,    4.2,    5.5;
+        4.2,    5.0,    6.0;
+        5.0,    5.8,    6.5));
+    assert_eq!((dynamic.breakpoints_h(), dynamic.breakpoints_v()), (&[0i16,500,1000][..], &[0i8,3,6][..]));
+    let interpolations = [Interpolation::Linear, Interpolation::NoneCeiling, Interpolation::NoneFloor, Interpolation::NoneClosest,
+        Interpolation::Smoothstep, Interpolation::Smootherstep, Interpolation::Cubic];
+    for interpolation in interpolations {
+        for h in (-250i16..=1250).step_by(125) {
+            for v in -1i8..=7 {
+                assert_eq!(dynamic.lookup(&h, &v, interpolation).unwrap(), FIXED.lookup(&h, &v, interpolation).unwrap(),
+                    "dynamic 2d lookup differs at ({}, {}) with {:?}", h, v, interpolation);
+                assert_eq!(dynamic.lookup_nonlinear(&h, &v, interpolation).unwrap(), FIXED.lookup_nonlinear(&h, &v, interpolation).unwrap(),
+                    "dynamic 2d lookup_nonlinear differs at ({}, {}) with {:?}", h, v, interpolation);
+            }
+        }
+    }
+
+    assert_eq!(DynamicTwoDLookup::<i16,i8,f32>::try_new(vec![0,500], vec![0,3], vec![3.0,4.2,4.2]).err(), Some(LookupBuildError::ShapeMismatch { expected: 4, found: 3 }));
+    assert_eq!(DynamicTwoDLookup::<i16,i8,f32>::try_new(vec![], vec![0,3], vec![]).err(), Some(LookupBuildError::EmptyAxis));
+    assert_eq!(DynamicTwoDLookup::<i16,i8,f32>::try_new(vec![0,500], vec![3,0], vec![3.0,4.2,4.2,5.0]).err(), Some(LookupBuildError::NotAscending { index: 1 }));
+}
+
+#[test]
+fn brackets_2d() {
+    const LOOKUP_TABLE: TwoDLookup<i16,i16,f64,3,3> = create_2d_lookup!((0,500,1000),(0,40,100),(
+        3.0,    4.2,    5.5;
+        4.2,    5.0,    6.0;
+        5.0,    5.8,    6.5));
+    //between two vertical breakpoints
+    assert_eq!(LOOKUP_TABLE.v_bracket(&55i16), (2, Some(1), 0.25));
+    assert_eq!(LOOKUP_TABLE.v_bracket(&10i16), (1, Some(0), 0.25));
+    //on a breakpoint and out of range nothing is blended
+    assert_eq!(LOOKUP_TABLE.v_bracket(&40i16), (1, None, 1.0));
+    assert_eq!(LOOKUP_TABLE.v_bracket(&-10i16), (0, None, 1.0));
+    assert_eq!(LOOKUP_TABLE.v_bracket(&110i16), (2, None, 1.0));
+    assert_eq!(LOOKUP_TABLE.h_bracket(&100i16), (1, Some(0), 0.2));
+
+    //the separable blend of the brackets gives the same result as the lookup
+    let (h, h_low, h_fraction) = LOOKUP_TABLE.h_bracket(&100i16);
+    let (v, v_low, v_fraction) = LOOKUP_TABLE.v_bracket(&55i16);
+    let values = [[3.0, 4.2, 5.5], [4.2, 5.0, 6.0], [5.0, 5.8, 6.5]];
+    let row = |index_v: usize| values[index_v][h_low.unwrap()] * (1.0 - h_fraction) + values[index_v][h] * h_fraction;
+    let blended = row(v_low.unwrap()) * (1.0 - v_fraction) + row(v) * v_fraction;
+    let result = LOOKUP_TABLE.lookup(&100i16, &55i16, Interpolation::Linear).unwrap();
+    assert!((blended - result).abs() < 1e-12, "{} != {}", blended, result);
+}
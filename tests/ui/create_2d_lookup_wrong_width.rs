@@ -0,0 +1,9 @@
+use go_lookup_tables::*;
+
+fn main() {
+    //there are 3 horizontal breakpoints but every row only has 2 values
+    let lookup_table = create_2d_lookup!((0i16,500,1000),(0i8,3),(
+        3.0f32,4.2;
+        4.2,5.0));
+    let _ = lookup_table.lookup(&750i16, &2i8, Interpolation::Linear);
+}
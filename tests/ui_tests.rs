@@ -0,0 +1,5 @@
+#[test]
+fn create_2d_lookup_ui() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+}